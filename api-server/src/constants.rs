@@ -3,3 +3,13 @@ pub const BET_EXPIRATION: u64 = 5 * 60;
 
 // 5 min in seconds.
 pub const BET_EXPIRATION_THRESHOLD: u64 = 5 * 60;
+
+// 5 min in seconds.
+pub const BATTLELOG_INGESTION_INTERVAL: u64 = 5 * 60;
+
+// 4 min in seconds.
+// Note: shorter than BET_EXPIRATION_THRESHOLD so the bets are settled within the threshold.
+pub const BET_SETTLEMENT_INTERVAL: u64 = 4 * 60;
+
+// 15 min in seconds.
+pub const TAG_VERIFICATION_EXPIRATION: u64 = 15 * 60;
@@ -6,12 +6,14 @@ use commons::clients::games::GameClients;
 use commons::clients::solana::SolanaClient;
 use commons::config::InitServiceConfig;
 use commons::database::DBInfo;
+use commons::games::GameVerifiers;
 
 pub struct AppContext {
     pub db_info: Arc<DBInfo>,
     pub http_client: Arc<reqwest::Client>,
     pub solana_client: Arc<SolanaClient>,
     pub game_clients: GameClients,
+    pub game_verifiers: GameVerifiers,
     inner: Arc<Mutex<Inner>>,
 }
 
@@ -28,10 +30,12 @@ impl AppContext {
         shutdown_signal: oneshot::Sender<()>,
     ) -> Self {
         let http_client = Arc::new(reqwest::Client::new());
+        let game_clients = GameClients::new(config, &http_client);
         AppContext {
             db_info,
             solana_client: Arc::new(SolanaClient::new(config)),
-            game_clients: GameClients::new(config, &http_client),
+            game_verifiers: GameVerifiers::new(&game_clients),
+            game_clients,
             http_client,
             inner: Arc::new(Mutex::new(Inner { shutdown_signal })),
         }
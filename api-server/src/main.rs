@@ -16,6 +16,7 @@ use commons::utils::init_logger;
 use crate::context::AppContext;
 use crate::error::ServerResult;
 use crate::routes::build_routes;
+use crate::workers::start_workers;
 
 mod constants;
 mod context;
@@ -25,6 +26,7 @@ mod routes;
 mod services;
 #[cfg(test)]
 mod tests;
+mod workers;
 
 #[tokio::main]
 async fn main() {
@@ -47,6 +49,9 @@ async fn application() -> ServerResult<()> {
     // Free locks.
     release_all_mutex_of_current_microservice().await;
 
+    // Background tasks.
+    start_workers(&context, &config);
+
     // Init server.
     let db_config = config.db_config().await;
     let (_, server) = warp::serve(routes).bind_with_graceful_shutdown(
@@ -0,0 +1,121 @@
+use arcstr::ArcStr;
+use serde::{Deserialize, Serialize};
+
+use commons::clients::games::{ClashRoyaleBattlelogResponse, ClashRoyalePlayerInfoResponse};
+use commons::data::games::clash_royale_tag_validator;
+use commons::database::documents::APIChallengeMilestone;
+use commons::database::types::{Address, DateTime};
+use commons::error::{AppError, AppResult, INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeEvaluateRequestBody {
+    /// The milestones to evaluate.
+    /// Missing: those of `challenge` are evaluated.
+    #[serde(default)]
+    pub milestones: Option<Vec<APIChallengeMilestone>>,
+
+    /// The challenge whose milestones are evaluated.
+    /// Missing: `milestones` are evaluated.
+    #[serde(default)]
+    pub challenge: Option<Address>,
+
+    /// The Clash Royale player to load the data from the API.
+    /// Missing: the data must be in `battlelog` and `playerInfo`.
+    #[serde(default)]
+    pub clash_royale_tag: Option<ArcStr>,
+
+    /// The Clash Royale matches to evaluate.
+    /// Missing: loaded from the API.
+    #[serde(default)]
+    pub battlelog: Option<Vec<ClashRoyaleBattlelogResponse>>,
+
+    /// The Clash Royale player info to evaluate the achievements.
+    /// Missing: loaded from the API.
+    #[serde(default)]
+    pub player_info: Option<ClashRoyalePlayerInfoResponse>,
+
+    /// The Clash Royale player info to compare incremental achievements with.
+    /// Missing: incremental achievements are never completed.
+    #[serde(default)]
+    pub snapshot: Option<ClashRoyalePlayerInfoResponse>,
+
+    /// Only the matches played after this date are evaluated.
+    /// Missing: all matches are evaluated.
+    #[serde(default)]
+    pub since: Option<DateTime>,
+
+    /// The expiration of the evaluated bet. Only the matches played before it are evaluated
+    /// and, once it is past, the milestones that wait for the expiration, e.g. statistics,
+    /// are completed or failed.
+    /// Missing: the evaluation never expires.
+    #[serde(default)]
+    pub expires_at: Option<DateTime>,
+}
+
+impl ChallengeEvaluateRequestBody {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self) -> AppResult<()> {
+        match (&self.milestones, &self.challenge) {
+            (Some(milestones), None) => {
+                if milestones.is_empty() {
+                    return Err(AppError::new_with_status(
+                        warp::http::StatusCode::BAD_REQUEST,
+                        INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE,
+                    )
+                    .message(arcstr::literal!("Empty milestones list"))
+                    .param(arcstr::literal!("milestones")));
+                }
+
+                for milestone in milestones {
+                    if let Some(error) = milestone.validate() {
+                        return Err(AppError::new_with_status(
+                            warp::http::StatusCode::BAD_REQUEST,
+                            INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE,
+                        )
+                        .message(error)
+                        .param(arcstr::literal!("milestones")));
+                    }
+                }
+            }
+            (None, Some(_)) => {}
+            _ => {
+                return Err(AppError::new_with_status(
+                    warp::http::StatusCode::BAD_REQUEST,
+                    INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE,
+                )
+                .message(arcstr::literal!(
+                    "Either the milestones or the challenge must be present"
+                ))
+                .param(arcstr::literal!("milestones")));
+            }
+        }
+
+        if let (Some(since), Some(expires_at)) = (&self.since, &self.expires_at) {
+            if since.0 >= expires_at.0 {
+                return Err(AppError::new_with_status(
+                    warp::http::StatusCode::BAD_REQUEST,
+                    INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE,
+                )
+                .message(arcstr::literal!(
+                    "The expiration must be after the starting date"
+                ))
+                .param(arcstr::literal!("expiresAt")));
+            }
+        }
+
+        if let Some(tag) = &self.clash_royale_tag {
+            if !clash_royale_tag_validator(tag.as_str()) {
+                return Err(AppError::new_with_status(
+                    warp::http::StatusCode::BAD_REQUEST,
+                    INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE,
+                )
+                .message(arcstr::literal!("The Clash Royale tag is incorrect"))
+                .param(arcstr::literal!("clashRoyaleTag")));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,11 @@
 pub use create::*;
 pub use create_config::*;
+pub use evaluate::*;
 pub use get::*;
 pub use list::*;
 
 mod create;
 mod create_config;
+mod evaluate;
 mod get;
 mod list;
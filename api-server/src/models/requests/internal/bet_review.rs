@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalBetReviewRequestBody {
+    /// Whether the bet is won, paying the reward, or lost because of collusion.
+    pub approved: bool,
+}
@@ -0,0 +1,3 @@
+pub use bet_review::*;
+
+mod bet_review;
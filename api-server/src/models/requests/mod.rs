@@ -1,5 +1,6 @@
 pub mod bet;
 pub mod challenge;
+pub mod internal;
 pub mod mail_list;
 pub mod participant;
 pub mod signature;
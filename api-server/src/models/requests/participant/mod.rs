@@ -1,7 +1,9 @@
 pub use get::*;
 pub use login::*;
 pub use update::*;
+pub use verification::*;
 
 mod get;
 mod login;
 mod update;
+mod verification;
@@ -0,0 +1,21 @@
+use commons::data::games::brawl_stars_tag_validator;
+use commons::database::documents::APIParticipantBrawlStarsGameData;
+use commons::database::NullableOption;
+use commons::error::{AppError, AppResult, INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE};
+
+pub fn validate_participant_brawl_stars_games_data(
+    data: &APIParticipantBrawlStarsGameData,
+) -> AppResult<()> {
+    if let NullableOption::Value(tag) = &data.tag {
+        if !brawl_stars_tag_validator(tag.as_str()) {
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE,
+            )
+            .message(arcstr::literal!("The Brawl Stars tag is incorrect"))
+            .param(arcstr::literal!("brawlStarsUserTag")));
+        }
+    }
+
+    Ok(())
+}
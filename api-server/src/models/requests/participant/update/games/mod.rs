@@ -1,8 +1,10 @@
+pub use brawl_stars::*;
 pub use clash_royale::*;
 use commons::database::documents::APIParticipantGamesData;
 use commons::database::NullableOption;
 use commons::error::AppResult;
 
+mod brawl_stars;
 mod clash_royale;
 
 pub fn validate_participant_games_data(data: &APIParticipantGamesData) -> AppResult<()> {
@@ -10,5 +12,9 @@ pub fn validate_participant_games_data(data: &APIParticipantGamesData) -> AppRes
         validate_participant_clash_royale_games_data(clash_royale)?;
     }
 
+    if let NullableOption::Value(brawl_stars) = &data.brawl_stars {
+        validate_participant_brawl_stars_games_data(brawl_stars)?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use commons::database::documents::ClashRoyaleTagVerificationKind;
+use commons::error::AppResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantVerificationCreateRequestBody {
+    pub kind: ClashRoyaleTagVerificationKind,
+}
+
+impl ParticipantVerificationCreateRequestBody {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self) -> AppResult<()> {
+        Ok(())
+    }
+}
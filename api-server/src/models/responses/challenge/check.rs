@@ -1,16 +1,42 @@
 use serde::{Deserialize, Serialize};
 
+use commons::games::{MilestoneProgress, MilestoneStatus};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChallengeCheckResponse {
     pub status: ChallengeCheckResponseStatus,
+
+    /// The progress of the participant in the milestones of the challenge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<MilestoneProgress>,
+
+    /// The seconds remaining before the bet expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_time: Option<u64>,
 }
 
 impl ChallengeCheckResponse {
     // CONSTRUCTORS -----------------------------------------------------------
 
     pub fn new(status: ChallengeCheckResponseStatus) -> Self {
-        ChallengeCheckResponse { status }
+        ChallengeCheckResponse {
+            status,
+            progress: None,
+            remaining_time: None,
+        }
+    }
+
+    pub fn new_with_progress(
+        status: ChallengeCheckResponseStatus,
+        progress: Option<MilestoneProgress>,
+        remaining_time: u64,
+    ) -> Self {
+        ChallengeCheckResponse {
+            status,
+            progress,
+            remaining_time: Some(remaining_time),
+        }
     }
 }
 
@@ -26,4 +52,17 @@ pub enum ChallengeCheckResponseStatus {
     NotInitiated,
     Initiated,
     Expired,
+    /// Won but with signs of collusion, so the reward is not paid until it is reviewed.
+    UnderReview,
+}
+
+impl From<&MilestoneStatus> for ChallengeCheckResponseStatus {
+    fn from(status: &MilestoneStatus) -> Self {
+        match status {
+            MilestoneStatus::NotInitiated => ChallengeCheckResponseStatus::NotInitiated,
+            MilestoneStatus::Initiated => ChallengeCheckResponseStatus::Initiated,
+            MilestoneStatus::Completed(_) => ChallengeCheckResponseStatus::Won,
+            MilestoneStatus::Failed => ChallengeCheckResponseStatus::Lost,
+        }
+    }
 }
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use commons::games::MilestoneProgress;
+
+use crate::models::responses::challenge::ChallengeCheckResponseStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeEvaluateResponse {
+    /// The status a bet would have with the evaluated data.
+    pub status: ChallengeCheckResponseStatus,
+
+    /// The evaluation of the milestones.
+    pub progress: MilestoneProgress,
+}
+
+impl ChallengeEvaluateResponse {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(progress: MilestoneProgress) -> Self {
+        ChallengeEvaluateResponse {
+            status: ChallengeCheckResponseStatus::from(&progress.status),
+            progress,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 pub use bet::*;
 pub use check::*;
+pub use evaluate::*;
 pub use get::*;
 
 mod bet;
 mod check;
+mod evaluate;
 mod get;
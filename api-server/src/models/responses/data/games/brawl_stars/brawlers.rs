@@ -0,0 +1,22 @@
+use arcstr::ArcStr;
+use serde::{Deserialize, Serialize};
+
+use commons::data::games::BrawlStarsBrawler;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsBrawlerGameDataResponse {
+    id: u64,
+    name: ArcStr,
+}
+
+impl BrawlStarsBrawlerGameDataResponse {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(brawler: BrawlStarsBrawler) -> Self {
+        BrawlStarsBrawlerGameDataResponse {
+            id: brawler as u64,
+            name: brawler.name().into(),
+        }
+    }
+}
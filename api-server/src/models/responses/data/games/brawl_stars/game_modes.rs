@@ -0,0 +1,22 @@
+use arcstr::ArcStr;
+use serde::{Deserialize, Serialize};
+
+use commons::data::games::BrawlStarsGameMode;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsGameModeGameDataResponse {
+    id: BrawlStarsGameMode,
+    name: ArcStr,
+}
+
+impl BrawlStarsGameModeGameDataResponse {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(game_mode: BrawlStarsGameMode) -> Self {
+        BrawlStarsGameModeGameDataResponse {
+            id: game_mode,
+            name: game_mode.name().into(),
+        }
+    }
+}
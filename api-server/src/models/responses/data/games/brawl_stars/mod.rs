@@ -0,0 +1,2 @@
+pub mod brawlers;
+pub mod game_modes;
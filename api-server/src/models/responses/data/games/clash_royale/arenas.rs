@@ -1,28 +1,35 @@
 use arcstr::ArcStr;
 use serde::{Deserialize, Serialize};
 
-use commons::data::games::ClashRoyaleArena;
+use commons::data::games::ClashRoyaleArenaInfo;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleArenaGameDataResponse {
     id: u64,
+    key: ArcStr,
     order: u64,
     title: ArcStr,
     subtitle: ArcStr,
+    min_trophies: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_trophies: Option<u64>,
     icon_url: ArcStr,
 }
 
 impl ClashRoyaleArenaGameDataResponse {
     // CONSTRUCTORS -----------------------------------------------------------
 
-    pub fn new(arena: ClashRoyaleArena) -> Self {
+    pub fn new(arena: &ClashRoyaleArenaInfo) -> Self {
         ClashRoyaleArenaGameDataResponse {
-            id: arena as u64,
-            order: arena.order(),
-            title: arena.title().into(),
-            subtitle: arena.subtitle().into(),
-            icon_url: arena.icon_url().into(),
+            id: arena.id,
+            key: arena.key.clone(),
+            order: arena.order,
+            title: arena.title.clone(),
+            subtitle: arena.subtitle.clone(),
+            min_trophies: arena.min_trophies,
+            max_trophies: arena.max_trophies,
+            icon_url: arena.icon_url.clone(),
         }
     }
 }
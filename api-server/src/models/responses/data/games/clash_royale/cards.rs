@@ -1,13 +1,20 @@
 use arcstr::ArcStr;
 use serde::{Deserialize, Serialize};
 
-use commons::data::games::ClashRoyaleCard;
+use commons::data::games::{ClashRoyaleCardInfo, ClashRoyaleCardRarity, ClashRoyaleCardType};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleCardGameDataResponse {
     id: u64,
+    key: ArcStr,
     name: ArcStr,
+    rarity: ClashRoyaleCardRarity,
+    #[serde(rename = "type")]
+    kind: ClashRoyaleCardType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    elixir: Option<u8>,
+    win_condition: bool,
     icon_url: ArcStr,
     max_level: u64,
 }
@@ -15,12 +22,17 @@ pub struct ClashRoyaleCardGameDataResponse {
 impl ClashRoyaleCardGameDataResponse {
     // CONSTRUCTORS -----------------------------------------------------------
 
-    pub fn new(card: ClashRoyaleCard) -> Self {
+    pub fn new(card: &ClashRoyaleCardInfo) -> Self {
         ClashRoyaleCardGameDataResponse {
-            id: card as u64,
-            name: card.name().into(),
-            icon_url: card.icon_url().into(),
-            max_level: card.max_level(),
+            id: card.id,
+            key: card.key.clone(),
+            name: card.name.clone(),
+            rarity: card.rarity,
+            kind: card.kind,
+            elixir: card.elixir,
+            win_condition: card.win_condition,
+            icon_url: card.icon_url.clone(),
+            max_level: card.max_level,
         }
     }
 }
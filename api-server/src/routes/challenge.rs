@@ -9,8 +9,8 @@ use commons::server::{limit_requests_by_ip, validate_api_token, with_body, with_
 
 use crate::context::AppContext;
 use crate::models::requests::challenge::{
-    ChallengeCreateConfigRequestBody, ChallengeCreateRequestBody, ChallengeGetRequestBody,
-    ChallengeListRequestBody,
+    ChallengeCreateConfigRequestBody, ChallengeCreateRequestBody, ChallengeEvaluateRequestBody,
+    ChallengeGetRequestBody, ChallengeListRequestBody,
 };
 use crate::routes::{with_context, RequestContext};
 
@@ -22,8 +22,10 @@ pub fn build_challenge_routes(
         get(context, config),
         info(context, config),
         list(context, config),
+        schema(context, config),
         create_config(context, config),
         create(context, config),
+        evaluate(context, config),
         bet(context, config),
     )
 }
@@ -134,6 +136,39 @@ fn list(
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+fn schema(
+    context: &Arc<AppContext>,
+    config: &Arc<InitServiceConfig>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    // Path, params and queries.
+    let route = warp::path!("challenge" / "schema");
+
+    // Method and other validations.
+    let route = route.and(warp::get());
+
+    // Body.
+
+    // (Optional) Request limits.
+    let route = route.and(limit_requests_by_ip(15 * 60 /* 15 min */, 75));
+
+    // (Optional) Make the request object to combine all data.
+
+    // Add application context and config.
+    let route = route.and(with_context(context)).and(with_config(config));
+
+    // Make the request context.
+    let route = route.map(RequestContext::new_empty);
+
+    // (Optional) With context validations.
+
+    // Service.
+    route.and_then(crate::services::challenge::schema_service)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 fn create_config(
     context: &Arc<AppContext>,
     config: &Arc<InitServiceConfig>,
@@ -204,6 +239,41 @@ fn create(
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+fn evaluate(
+    context: &Arc<AppContext>,
+    config: &Arc<InitServiceConfig>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    // Path, params and queries.
+    let route = warp::path!("challenge" / "evaluate");
+
+    // Method and other validations.
+    let route = route.and(warp::post());
+
+    // (Optional) Request limits.
+    let route = route.and(limit_requests_by_ip(15 * 60 /* 15 min */, 75));
+
+    // Body.
+    let route = route.and(with_body::<ChallengeEvaluateRequestBody>());
+
+    // (Optional) Make the request object to combine all data.
+
+    // Add application context and config.
+    let route = route.and(with_context(context)).and(with_config(config));
+
+    // Make the request context.
+    let route = route.map(RequestContext::new);
+
+    // (Optional) With context validations.
+    let route = validate_api_token(route);
+
+    // Service.
+    route.and_then(crate::services::challenge::evaluate_service)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 fn bet(
     context: &Arc<AppContext>,
     config: &Arc<InitServiceConfig>,
@@ -2,11 +2,13 @@ use std::sync::Arc;
 
 use warp::Filter;
 
+use brawl_stars::build_brawl_stars_game_data_routes;
 use clash_royale::build_clash_royale_game_data_routes;
 use commons::config::InitServiceConfig;
 
 use crate::context::AppContext;
 
+mod brawl_stars;
 mod clash_royale;
 
 pub fn build_games_data_routes(
@@ -15,5 +17,6 @@ pub fn build_games_data_routes(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path("games").and(commons::balanced_or_tree!(
         build_clash_royale_game_data_routes(context, config),
+        build_brawl_stars_game_data_routes(context, config),
     ))
 }
@@ -3,17 +3,19 @@ use std::sync::Arc;
 use warp::Filter;
 
 use commons::config::InitServiceConfig;
-use commons::database::types::{DBUuid, DBUuidType};
-use commons::server::{validate_specific_token, with_config};
+use commons::database::types::{Address, DBUuid, DBUuidType};
+use commons::server::requests::RequestWithParam;
+use commons::server::{validate_specific_token, with_body, with_config};
 
 use crate::context::AppContext;
+use crate::models::requests::internal::InternalBetReviewRequestBody;
 use crate::routes::{with_context, RequestContext};
 
 pub fn build_internal_routes(
     context: &Arc<AppContext>,
     config: &Arc<InitServiceConfig>,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
-    commons::balanced_or_tree!(auth_check(context, config),)
+    commons::balanced_or_tree!(auth_check(context, config), bet_review(context, config),)
 }
 
 // ----------------------------------------------------------------------------
@@ -48,3 +50,38 @@ fn auth_check(
     // Service.
     route.and_then(crate::services::internal::auth_check_service)
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn bet_review(
+    context: &Arc<AppContext>,
+    config: &Arc<InitServiceConfig>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    // Path, params and queries.
+    let route = warp::path!("internal" / "bet" / Address / "review");
+
+    // Method and other validations.
+    let route = route.and(warp::post());
+
+    // Body.
+    let route = route.and(with_body::<InternalBetReviewRequestBody>());
+    let route = route.map(RequestWithParam::new);
+
+    // (Optional) Request limits.
+
+    // (Optional) Make the request object to combine all data.
+
+    // Add application context and config.
+    let route = route.and(with_context(context)).and(with_config(config));
+
+    // Make the request context.
+    let route = route.map(RequestContext::new);
+
+    // (Optional) With context validations.
+    let route = validate_specific_token(route, DBUuidType::InternalToken);
+
+    // Service.
+    route.and_then(crate::services::internal::bet_review_service)
+}
@@ -9,6 +9,7 @@ use commons::server::{limit_requests_by_ip, with_body, with_config};
 use crate::context::AppContext;
 use crate::models::requests::participant::{
     ParticipantGetRequestBody, ParticipantLoginRequestBody, ParticipantUpdateRequestBody,
+    ParticipantVerificationCreateRequestBody,
 };
 use crate::routes::{with_context, RequestContext};
 
@@ -21,6 +22,8 @@ pub fn build_participant_routes(
         logout(context, config),
         get(context, config),
         update(context, config),
+        verification_create(context, config),
+        verification_check(context, config),
     )
 }
 
@@ -161,3 +164,72 @@ fn update(
     // Service.
     route.and_then(crate::services::participant::update_service)
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn verification_create(
+    context: &Arc<AppContext>,
+    config: &Arc<InitServiceConfig>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    // Path, params and queries.
+    let route = warp::path!("participant" / "verification" / "create");
+
+    // Method and other validations.
+    let route = route.and(warp::post());
+
+    // (Optional) Request limits.
+    let route = route.and(limit_requests_by_ip(15 * 60 /* 15 min */, 15));
+
+    // Body.
+    let route = route.and(with_body::<ParticipantVerificationCreateRequestBody>());
+
+    // (Optional) Make the request object to combine all data.
+
+    // Add application context and config.
+    let route = route.and(with_context(context)).and(with_config(config));
+
+    // Make the request context.
+    let route = route.map(RequestContext::new);
+
+    // (Optional) With context validations.
+    let route = validate_api_token(route);
+
+    // Service.
+    route.and_then(crate::services::participant::verification_create_service)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn verification_check(
+    context: &Arc<AppContext>,
+    config: &Arc<InitServiceConfig>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    // Path, params and queries.
+    let route = warp::path!("participant" / "verification" / "check");
+
+    // Method and other validations.
+    let route = route.and(warp::post());
+
+    // Body.
+
+    // (Optional) Request limits.
+    let route = route.and(limit_requests_by_ip(15 * 60 /* 15 min */, 30));
+
+    // (Optional) Make the request object to combine all data.
+
+    // Add application context and config.
+    let route = route.and(with_context(context)).and(with_config(config));
+
+    // Make the request context.
+    let route = route.map(RequestContext::new_empty);
+
+    // (Optional) With context validations.
+    let route = validate_api_token(route);
+
+    // Service.
+    route.and_then(crate::services::participant::verification_check_service)
+}
@@ -1,19 +1,23 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 
 use chrono::{TimeZone, Utc};
 
-use commons::clients::games::ClashRoyaleBattlelogResponse;
-use commons::database::collections::{BetCollection, ChallengeCollection};
-use commons::database::documents::{BetDBDocument, ChallengeDBDocument};
-use commons::database::documents::{BetDBState, ChallengeMilestone};
-use commons::database::types::game::{ClashRoyaleMilestone, GameMilestone};
-use commons::database::types::{Address, DateTime};
-use commons::database::{DBDocument, NullableOption};
+use commons::constants::REMOTE_MUTEX_TIMEOUT;
+use commons::data::RemoteMutexGuard;
+use commons::database::collections::{BetCollection, ChallengeCollection, ParticipantCollection};
+use commons::database::documents::{BattlelogDBDocument, BetDBDocument, ChallengeDBDocument};
+use commons::database::documents::{BetDBState, ChallengeMilestone, FraudConfig};
+use commons::database::types::game::GameMilestone;
+use commons::database::types::{Address, DateTime, GameType};
+use commons::database::{DBCollection, DBDocument, NullableOption};
 use commons::error::{
     AppError, AppResult, INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
-    INPUT_VALIDATION_UNDEFINED_BET_ERROR_CODE,
+    INPUT_VALIDATION_LOCKED_ELEMENT_ERROR_CODE, INPUT_VALIDATION_UNDEFINED_BET_ERROR_CODE,
 };
+use commons::games::{FraudSignal, MilestoneProgress, MilestoneStatus, MilestoneVerifier};
 use commons::programs::gmi_bet::state::{Bet, BetState};
+use commons::solana_sdk::pubkey::Pubkey;
 
 use crate::constants::BET_EXPIRATION_THRESHOLD;
 use crate::context::AppContext;
@@ -24,6 +28,7 @@ pub async fn check_service(
     request_context: RequestContext<Address>,
 ) -> Result<impl warp::Reply, warp::Rejection> {
     let context = request_context.app_context;
+    let db_config = request_context.config.db_config().await;
 
     // Find bet in DB and blockchain.
     let bet_address = request_context.request;
@@ -31,7 +36,46 @@ pub async fn check_service(
     let bet_pubkey = bet_address.into();
     let bet_program_client = context.solana_client.bet_program_client();
 
-    let bet_db_document = BetCollection::get_by_key_or_reject(&bet_key, None).await?;
+    // Lock the bet to prevent settling it twice, e.g. by the settlement worker.
+    let (bet_db_document, _mutex) = match RemoteMutexGuard::<BetDBDocument>::acquire_with_timeout(
+        &bet_key,
+        REMOTE_MUTEX_TIMEOUT,
+        None,
+    )
+    .await?
+    {
+        Some(v) => v,
+        None => {
+            // The lock is not acquired either because the bet does not exist or because it
+            // is being checked by another request or the settlement worker.
+            if BetCollection::instance().exists_by_key(&bet_key).await? {
+                return Err(AppError::new_with_status(
+                    warp::http::StatusCode::CONFLICT,
+                    INPUT_VALIDATION_LOCKED_ELEMENT_ERROR_CODE,
+                )
+                .message(arcstr::literal!(
+                    "The bet is being processed, please try again later"
+                ))
+                .param(arcstr::literal!("address"))
+                .into());
+            }
+
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                INPUT_VALIDATION_UNDEFINED_BET_ERROR_CODE,
+            )
+            .message(arcstr::literal!("Undefined bet"))
+            .param(arcstr::literal!("address"))
+            .into());
+        }
+    };
+
+    // Bets under review are resolved manually.
+    if let NullableOption::Value(BetDBState::UnderReview) = &bet_db_document.state {
+        let response = ChallengeCheckResponse::new(ChallengeCheckResponseStatus::UnderReview);
+        return Ok(warp::reply::json(&response));
+    }
+
     let bet = match bet_program_client.get_account(bet_pubkey).await {
         Ok(v) => v,
         Err(_) => {
@@ -66,32 +110,115 @@ pub async fn check_service(
         .into());
     }
 
-    // Find challenge in DB and blockchain.
-    let challenge_address = Address::from(bet_info.receiver_account);
-    let challenge_key = challenge_address.to_uuid();
-    let challenge_pubkey = challenge_address.into();
-    let challenge_program_client = context.solana_client.challenge_program_client();
+    let (result_status, progress) = settle_bet(
+        &context,
+        &db_config.fraud,
+        &bet_db_document,
+        &bet_pubkey,
+        bet_info,
+    )
+    .await?;
+
+    let bet_expiration = DateTime::new(Utc.timestamp(bet_info.expires_at, 0));
+    let remaining_time = (bet_expiration.0 - Utc::now()).num_seconds().max(0) as u64;
+    let response =
+        ChallengeCheckResponse::new_with_progress(result_status, progress, remaining_time);
+    Ok(warp::reply::json(&response))
+}
 
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Verifies an applied bet and, if it is won, lost or expired, settles it in the blockchain
+/// and in the DB. The bet must be locked by the caller.
+pub async fn settle_bet(
+    context: &Arc<AppContext>,
+    fraud_config: &FraudConfig,
+    bet_db_document: &BetDBDocument,
+    bet_pubkey: &Pubkey,
+    bet_info: &Bet,
+) -> AppResult<(ChallengeCheckResponseStatus, Option<MilestoneProgress>)> {
+    // Find challenge in DB.
+    let challenge_key = Address::from(bet_info.receiver_account).to_uuid();
     let challenge_db_document =
         ChallengeCollection::get_by_key_or_reject(&challenge_key, None).await?;
-    let challenge = challenge_program_client
-        .get_account(challenge_pubkey)
-        .await?;
-    let challenge_info = challenge.load_data().unwrap();
 
     // Check expiration.
     let bet_expiration = DateTime::new(Utc.timestamp(bet_info.expires_at, 0));
 
     // Verify the challenge.
-    // If the time is around after a threshold, the challenge is also verified.
-    let result_status = if bet_expiration.is_expired_with_threshold(BET_EXPIRATION_THRESHOLD) {
-        ChallengeCheckResponseStatus::Expired
+    // After the threshold, a final verification is done before expiring the bet because some
+    // milestones, e.g. statistics, are only completed once the bet expires.
+    let is_expired = bet_expiration.is_expired_with_threshold(BET_EXPIRATION_THRESHOLD);
+    let (status, progress, fraud_signals) = verify_challenge(
+        context,
+        fraud_config,
+        &challenge_db_document,
+        bet_db_document,
+        bet_info,
+    )
+    .await?;
+    let result_status = match status {
+        ChallengeCheckResponseStatus::Won => ChallengeCheckResponseStatus::Won,
+        _ if is_expired => ChallengeCheckResponseStatus::Expired,
+        other => other,
+    };
+    let progress = Some(progress);
+
+    // Won bets with signs of collusion are not paid until they are reviewed.
+    let result_status = if fraud_signals.is_empty() {
+        result_status
     } else {
-        verify_challenge(&context, &challenge_db_document, &bet_db_document, bet_info).await?
+        remote_warn!(
+            "[challenge::validate] Bet {} under review. Fraud signals: {}",
+            bet_pubkey,
+            fraud_signals.len()
+        );
+        ChallengeCheckResponseStatus::UnderReview
     };
 
+    resolve_bet(
+        context,
+        bet_db_document,
+        bet_pubkey,
+        bet_info,
+        result_status,
+        progress.as_ref(),
+        fraud_signals,
+    )
+    .await?;
+
+    Ok((result_status, progress))
+}
+
+/// Applies the result of a verified bet in the blockchain and in the DB.
+/// The bet must be locked by the caller.
+pub async fn resolve_bet(
+    context: &Arc<AppContext>,
+    bet_db_document: &BetDBDocument,
+    bet_pubkey: &Pubkey,
+    bet_info: &Bet,
+    result_status: ChallengeCheckResponseStatus,
+    progress: Option<&MilestoneProgress>,
+    fraud_signals: Vec<FraudSignal>,
+) -> AppResult<()> {
+    // Find challenge in blockchain.
+    let challenge_address = Address::from(bet_info.receiver_account);
+    let challenge_pubkey = challenge_address.into();
+    let challenge_program_client = context.solana_client.challenge_program_client();
+
+    let challenge = challenge_program_client
+        .get_account(challenge_pubkey)
+        .await?;
+    let challenge_info = challenge.load_data().unwrap();
+
     let mut updated_document = BetDBDocument::default();
 
+    if let Some(progress) = progress {
+        updated_document.evaluation = NullableOption::Value(progress.clone());
+    }
+
     match result_status {
         ChallengeCheckResponseStatus::Won => {
             // Send call to validate the bet in the blockchain.
@@ -99,7 +226,7 @@ pub async fn check_service(
                 .call_validate_bet_and_send_reward(
                     &challenge_pubkey,
                     challenge_info,
-                    &bet_pubkey,
+                    bet_pubkey,
                     bet_info,
                 )
                 .await?
@@ -118,7 +245,7 @@ pub async fn check_service(
                         .call_validate_bet(
                             &challenge_pubkey,
                             challenge_info,
-                            &bet_pubkey,
+                            bet_pubkey,
                             bet_info,
                             true,
                         )
@@ -129,6 +256,7 @@ pub async fn check_service(
             };
 
             updated_document.state = NullableOption::Value(BetDBState::Won);
+            updated_document.won_at = NullableOption::Value(DateTime::now());
         }
         ChallengeCheckResponseStatus::Lost => {
             // Send call to validate the bet in the blockchain.
@@ -136,7 +264,7 @@ pub async fn check_service(
                 .call_validate_bet(
                     &challenge_pubkey,
                     challenge_info,
-                    &bet_pubkey,
+                    bet_pubkey,
                     bet_info,
                     false,
                 )
@@ -148,7 +276,7 @@ pub async fn check_service(
         ChallengeCheckResponseStatus::Expired => {
             // Send call to check the bet in the blockchain.
             updated_document.state = match challenge_program_client
-                .call_check_bet(&challenge_pubkey, challenge_info, &bet_pubkey, bet_info)
+                .call_check_bet(&challenge_pubkey, challenge_info, bet_pubkey, bet_info)
                 .await
             {
                 Ok(signature) => {
@@ -158,6 +286,10 @@ pub async fn check_service(
                 Err(_) => NullableOption::Value(BetDBState::ExpiredNotInBlockchain),
             };
         }
+        ChallengeCheckResponseStatus::UnderReview => {
+            updated_document.state = NullableOption::Value(BetDBState::UnderReview);
+            updated_document.fraud_signals = NullableOption::Value(fraud_signals);
+        }
         ChallengeCheckResponseStatus::NotInitiated => {}
         ChallengeCheckResponseStatus::Initiated => {}
     };
@@ -168,95 +300,168 @@ pub async fn check_service(
         updated_document.update(true).await?;
     }
 
-    let response = ChallengeCheckResponse::new(result_status);
-    Ok(warp::reply::json(&response))
+    Ok(())
 }
 
-// ----------------------------------------------------------------------------
-// ----------------------------------------------------------------------------
-// ----------------------------------------------------------------------------
-
 async fn verify_challenge(
     context: &Arc<AppContext>,
+    fraud_config: &FraudConfig,
     challenge: &ChallengeDBDocument,
     bet: &BetDBDocument,
     bet_info: &Bet,
-) -> AppResult<ChallengeCheckResponseStatus> {
+) -> AppResult<(
+    ChallengeCheckResponseStatus,
+    MilestoneProgress,
+    Vec<FraudSignal>,
+)> {
     let milestones = challenge.milestones.unwrap_as_ref();
-    let bet_start_date = bet.created_at.unwrap_as_ref();
-    let bet_info = DateTime::new(Utc::timestamp(&Utc, bet_info.expires_at, 0));
-    let games_data = bet
+    let bet_expiration = DateTime::new(Utc::timestamp(&Utc, bet_info.expires_at, 0));
+    let challenge_creation = challenge.created_at.unwrap_as_ref();
+    let participant_key = bet.participant.unwrap_as_ref().key();
+
+    // Group the game milestones by game and load the prerequisite challenges.
+    let mut game_milestones: HashMap<GameType, Vec<&GameMilestone>> = HashMap::new();
+    let mut completed_challenges = HashSet::new();
+
+    for milestone in milestones.iter().flat_map(|v| v.leaves()) {
+        match milestone {
+            ChallengeMilestone::GameMilestone(milestone) => {
+                game_milestones
+                    .entry(milestone.milestone.game_type())
+                    .or_default()
+                    .push(&milestone.milestone);
+            }
+            ChallengeMilestone::OtherChallenge(milestone) => {
+                let challenge_key = milestone.challenge.key();
+                let is_completed = BetCollection::exists_won_by_participant_and_challenge(
+                    &participant_key,
+                    &challenge_key,
+                    challenge_creation,
+                    &bet_expiration,
+                )
+                .await?;
+
+                if is_completed {
+                    completed_challenges.insert(challenge_key);
+                }
+            }
+            ChallengeMilestone::Composite(_) => unreachable!("Composite milestones are not leaves"),
+        }
+    }
+
+    // Load the progress of the participant in each game. The milestones of the games the
+    // participant has not linked are failed.
+    let games_data = match &bet
         .participant
         .unwrap_as_ref()
         .unwrap_document_as_ref()
         .games_data
-        .unwrap_as_ref();
+    {
+        NullableOption::Value(v) => Some(v),
+        _ => None,
+    };
+    let mut progresses = HashMap::new();
 
-    for milestone in milestones {
-        match milestone {
-            ChallengeMilestone::GameMilestone(milestone) => match &milestone.milestone {
-                GameMilestone::ClashRoyale(milestone) => match milestone {
-                    ClashRoyaleMilestone::WinMatches(conditions) => {
-                        let client = context.game_clients.clash_royale.clone();
-                        let battlelogs = client
-                            .get_player_battlelog(
-                                games_data.clash_royale.unwrap_as_ref().tag.unwrap_as_ref(),
-                            )
-                            .await?;
-
-                        // Filter to get only those battlelogs that happened after the starting date.
-                        let battlelogs =
-                            get_battlelog_matches_after_date(bet_start_date, &battlelogs);
-
-                        // Initial checks.
-                        if battlelogs.is_empty() {
-                            return Ok(ChallengeCheckResponseStatus::NotInitiated);
-                        }
-
-                        if battlelogs.len() < conditions.len() {
-                            return Ok(ChallengeCheckResponseStatus::Initiated);
-                        }
-
-                        // Pick only the necessary ones.
-                        let battlelogs = &battlelogs[(battlelogs.len() - conditions.len())..];
-
-                        // Verify last match first.
-                        for (battlelog, condition) in battlelogs.iter().rev().zip(conditions.iter())
-                        {
-                            // When any of the matches are after the final date, the user has lost.
-                            let match_date = &battlelog.battle_time;
-                            if match_date.0 >= bet_info.0 {
-                                return Ok(ChallengeCheckResponseStatus::Lost);
-                            }
-
-                            // Verify the challenge.
-                            if !condition.verify_response(battlelog) {
-                                return Ok(ChallengeCheckResponseStatus::Lost);
-                            }
-                        }
-                    }
-                    ClashRoyaleMilestone::Achievement(_) => todo!(),
-                },
-            },
-            ChallengeMilestone::OtherChallenge(_) => todo!(),
+    for (game_type, milestones) in game_milestones {
+        let verifier = context.game_verifiers.get_for(milestones[0])?;
+
+        if games_data
+            .and_then(|v| verifier.participant_tag(v))
+            .is_none()
+        {
+            continue;
         }
+
+        let progress = verifier
+            .load_progress(bet, &bet_expiration, &milestones)
+            .await?;
+
+        progresses.insert(game_type, progress);
     }
 
-    Ok(ChallengeCheckResponseStatus::Won)
+    let verifier = MilestoneVerifier::new(progresses, completed_challenges);
+
+    let progress = verifier.verify_all_of(milestones, None);
+    let status = ChallengeCheckResponseStatus::from(&progress.status);
+
+    // Look for signs of collusion in the matches used to complete the challenge.
+    let fraud_signals = match &progress.status {
+        MilestoneStatus::Completed(_) if fraud_config.enabled => {
+            detect_fraud(context, fraud_config, bet, &verifier, milestones, &progress).await?
+        }
+        _ => Vec::new(),
+    };
+
+    Ok((status, progress, fraud_signals))
 }
 
-fn get_battlelog_matches_after_date<'a>(
-    start_date: &DateTime,
-    battlelogs: &'a [ClashRoyaleBattlelogResponse],
-) -> &'a [ClashRoyaleBattlelogResponse] {
-    for i in 0..battlelogs.len() {
-        let battlelog = &battlelogs[i];
-        let battle_time = &battlelog.battle_time;
+async fn detect_fraud(
+    context: &Arc<AppContext>,
+    config: &FraudConfig,
+    bet: &BetDBDocument,
+    verifier: &MilestoneVerifier,
+    milestones: &[ChallengeMilestone],
+    progress: &MilestoneProgress,
+) -> AppResult<Vec<FraudSignal>> {
+    let mut signals = verifier.fraud_signals(config, milestones, progress);
+
+    if !config.check_opponent_bets {
+        return Ok(signals);
+    }
+
+    let opponent_matches = verifier.opponent_matches(milestones, progress);
 
-        if battle_time.0 < start_date.0 {
-            return &battlelogs[0..i];
+    if opponent_matches.is_empty() {
+        return Ok(signals);
+    }
+
+    let opponent_tags: Vec<_> = opponent_matches
+        .iter()
+        .map(|(tag, _)| tag.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    // Look for the opponents that are other participants with a bet active during the match.
+    let participant_key = bet.participant.unwrap_as_ref().key();
+    let mut active_tags = BTreeSet::new();
+
+    for opponent in ParticipantCollection::get_all_by_game_tags(&opponent_tags).await? {
+        let opponent_key = opponent.db_key.as_ref().unwrap();
+
+        // Participants can have many active bets.
+        if *opponent_key == participant_key {
+            continue;
+        }
+
+        let games_data = match &opponent.games_data {
+            NullableOption::Value(v) => v,
+            _ => continue,
+        };
+
+        let tags: HashSet<_> = context
+            .game_verifiers
+            .all()
+            .filter_map(|v| v.participant_tag(games_data))
+            .map(|v| BattlelogDBDocument::normalize_tag(v).to_string())
+            .collect();
+
+        for (tag, battle_time) in &opponent_matches {
+            if active_tags.contains(tag) || !tags.contains(tag.as_str()) {
+                continue;
+            }
+
+            if BetCollection::exists_active_by_participant_at(opponent_key, battle_time).await? {
+                active_tags.insert(tag.clone());
+            }
         }
     }
 
-    battlelogs
+    signals.extend(
+        active_tags
+            .into_iter()
+            .map(|tag| FraudSignal::OpponentWithActiveBet { tag }),
+    );
+
+    Ok(signals)
 }
@@ -121,6 +121,13 @@ pub async fn send_service(
         .into());
     }
 
+    // Get challenge from blockchain.
+    let challenge_program_client = context.solana_client.challenge_program_client();
+    let challenge = challenge_program_client
+        .get_account(Address::try_from(challenge_key).unwrap().into())
+        .await?;
+    let challenge_info = challenge.load_data().unwrap();
+
     // Send transaction.
     let signature = context.solana_client.send_transaction(transaction).await?;
 
@@ -128,23 +135,17 @@ pub async fn send_service(
 
     // Update the bet in DB.
     let now = DateTime::now();
+    let timeout = now.after_seconds(challenge_info.bets_expiration_delay as u64);
     {
         bet_db_document.state = NullableOption::Value(BetDBState::Created);
         bet_db_document.transaction = NullableOption::Null;
         bet_db_document.db_expires_at = NullableOption::Null;
         bet_db_document.created_at = NullableOption::Value(now.clone());
+        bet_db_document.expires_at = NullableOption::Value(timeout.clone());
 
         bet_db_document.insert_or_update(true).await?;
     }
 
-    // Get challenge from blockchain.
-    let challenge_program_client = context.solana_client.challenge_program_client();
-    let challenge = challenge_program_client
-        .get_account(Address::try_from(challenge_key).unwrap().into())
-        .await?;
-    let challenge_info = challenge.load_data().unwrap();
-    let timeout = now.after_seconds(challenge_info.bets_expiration_delay as u64);
-
     // Return challenge info.
     let response = BetSendResponse::new(now, timeout);
 
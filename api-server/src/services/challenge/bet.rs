@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use arcstr::ArcStr;
 use spl_token::instruction::initialize_account;
 use spl_token::native_mint;
@@ -7,11 +9,11 @@ use spl_token::solana_program::pubkey::Pubkey;
 use commons::anchor_client::anchor_lang::Id;
 use commons::anchor_spl;
 use commons::clients::solana::SolanaClient;
-use commons::database::collections::{ChallengeCollection, ParticipantCollection};
+use commons::database::collections::{BetCollection, ChallengeCollection, ParticipantCollection};
 use commons::database::documents::{BetDBDocument, ParticipantDBDocument};
 use commons::database::documents::{BetDBState, ChallengeMilestone};
 use commons::database::types::game::GameMilestone;
-use commons::database::types::{Address, DateTime};
+use commons::database::types::{Address, DateTime, GameType};
 use commons::database::{DBDocument, DBReference, NullableOption};
 use commons::error::{AppError, INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE};
 use commons::solana_sdk::instruction::Instruction;
@@ -53,32 +55,57 @@ pub async fn bet_service(
     let challenge_info = challenge.load_data().unwrap();
 
     // Check participant contains all data to play the challenge.
-    for milestone in challenge_db_document.milestones.unwrap_as_ref() {
-        match milestone {
-            ChallengeMilestone::GameMilestone(milestone) => match &milestone.milestone {
-                GameMilestone::ClashRoyale(_) => {
-                    let data = &participant_db_document
-                        .games_data
-                        .unwrap_as_ref()
-                        .clash_royale;
-
-                    let is_ok = match data {
-                        NullableOption::Value(v) => matches!(&v.tag, NullableOption::Value(_)),
-                        _ => false,
-                    };
-
-                    if !is_ok {
-                        return Err(AppError::new_with_status(
-                            warp::http::StatusCode::BAD_REQUEST,
-                            INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
-                        )
-                            .message(arcstr::literal!("The participant does not contain a ClashRoyale tag, please add it first"))
-                            .param(arcstr::literal!("participant"))
-                            .into());
-                    }
-                }
-            },
-            ChallengeMilestone::OtherChallenge(_) => todo!(),
+    let milestones = challenge_db_document.milestones.unwrap_as_ref();
+    let games_data = participant_db_document.games_data.unwrap_as_ref();
+    let mut game_milestones: HashMap<GameType, Vec<&GameMilestone>> = HashMap::new();
+
+    for milestone in milestones.iter().flat_map(|v| v.leaves()) {
+        if let Some(milestone) = milestone.game_milestone() {
+            game_milestones
+                .entry(milestone.game_type())
+                .or_default()
+                .push(milestone);
+        }
+    }
+
+    for milestones in game_milestones.values() {
+        let verifier = context.game_verifiers.get_for(milestones[0])?;
+
+        if let Some(error) = verifier.validate_participant(games_data) {
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+            )
+            .message(error)
+            .param(arcstr::literal!("participant"))
+            .into());
+        }
+    }
+
+    // Check the participant has completed the required prerequisite challenges since this
+    // challenge was created.
+    let challenge_creation = challenge_db_document.created_at.unwrap_as_ref();
+    for milestone in milestones.iter().flat_map(|v| v.required_leaves()) {
+        if let ChallengeMilestone::OtherChallenge(milestone) = milestone {
+            let is_completed = BetCollection::exists_won_by_participant_and_challenge(
+                &participant_key,
+                &milestone.challenge.key(),
+                challenge_creation,
+                &DateTime::now(),
+            )
+            .await?;
+
+            if !is_completed {
+                return Err(AppError::new_with_status(
+                    warp::http::StatusCode::BAD_REQUEST,
+                    INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+                )
+                .message(arcstr::literal!(
+                    "The participant has not completed the prerequisite challenges yet"
+                ))
+                .param(arcstr::literal!("participant"))
+                .into());
+            }
         }
     }
 
@@ -162,7 +189,7 @@ pub async fn bet_service(
     let serialized_message: ArcStr = encode_to_base58(&message.serialize()).into();
 
     // Update the bet in DB.
-    let bet_db_document = BetDBDocument {
+    let mut bet_db_document = BetDBDocument {
         db_key: Some(Address::from(bet_account_pubkey).to_uuid()),
         state: NullableOption::Value(BetDBState::WaitingForCreating),
         participant: NullableOption::Value(DBReference::Document(Box::new(
@@ -182,6 +209,21 @@ pub async fn bet_service(
         ..Default::default()
     };
 
+    // Store the game data required to verify the milestones later.
+    let mut snapshots = Vec::new();
+
+    for milestones in game_milestones.values() {
+        let verifier = context.game_verifiers.get_for(milestones[0])?;
+
+        if let Some(snapshot) = verifier.take_snapshot(games_data, milestones).await? {
+            snapshots.push(snapshot);
+        }
+    }
+
+    if !snapshots.is_empty() {
+        bet_db_document.snapshots = NullableOption::Value(snapshots);
+    }
+
     bet_db_document.insert(false).await?;
 
     // Return challenge info.
@@ -1,5 +1,6 @@
 use crate::models::requests::challenge::ChallengeCreateConfigRequestBody;
 use crate::routes::RequestContextWithAuth;
+use crate::services::utils::validate_challenge_references;
 
 pub async fn create_config_service(
     request_context: RequestContextWithAuth<ChallengeCreateConfigRequestBody>,
@@ -8,6 +9,7 @@ pub async fn create_config_service(
 
     // Validate input.
     request.validate()?;
+    validate_challenge_references(&request.milestones).await?;
 
     // TODO Prepare transaction.
     // TODO Serialize transaction.
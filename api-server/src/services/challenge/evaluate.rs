@@ -0,0 +1,144 @@
+use std::collections::{HashMap, HashSet};
+
+use arcstr::ArcStr;
+
+use commons::database::collections::ChallengeCollection;
+use commons::database::documents::ChallengeMilestone;
+use commons::database::types::game::{ClashRoyaleMilestone, GameMilestone};
+use commons::database::types::{DateTime, GameType};
+use commons::database::NullableOption;
+use commons::error::{AppError, AppResult, INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE};
+use commons::games::{ClashRoyaleProgress, GameProgress, MilestoneVerifier};
+
+use crate::models::requests::challenge::ChallengeEvaluateRequestBody;
+use crate::models::responses::challenge::ChallengeEvaluateResponse;
+use crate::routes::RequestContextWithAuth;
+use crate::services::utils::validate_challenge_references;
+
+/// Evaluates some milestones against the data of a player without creating any bet.
+/// Milestones that depend on other challenges are never completed.
+pub async fn evaluate_service(
+    request_context: RequestContextWithAuth<ChallengeEvaluateRequestBody>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let context = request_context.app_context;
+    let request = request_context.request;
+
+    // Validate input.
+    request.validate()?;
+
+    // Get the milestones.
+    let milestones: Vec<ChallengeMilestone> = match &request.milestones {
+        Some(milestones) => {
+            validate_challenge_references(milestones).await?;
+
+            milestones
+                .iter()
+                .cloned()
+                .map(ChallengeMilestone::from)
+                .collect()
+        }
+        None => {
+            let challenge_key = request.challenge.as_ref().unwrap().to_uuid();
+            let challenge = ChallengeCollection::get_by_key_or_reject(&challenge_key, None).await?;
+
+            match challenge.milestones {
+                NullableOption::Value(v) => v,
+                _ => Vec::new(),
+            }
+        }
+    };
+
+    let mut has_matches = false;
+    let mut has_achievements = false;
+
+    for milestone in milestones.iter().flat_map(|v| v.leaves()) {
+        match milestone.game_milestone() {
+            Some(GameMilestone::ClashRoyale(ClashRoyaleMilestone::WinMatches(_)))
+            | Some(GameMilestone::ClashRoyale(ClashRoyaleMilestone::Statistics(_))) => {
+                has_matches = true
+            }
+            Some(GameMilestone::ClashRoyale(ClashRoyaleMilestone::Achievement(_))) => {
+                has_achievements = true
+            }
+            Some(_) => {
+                return Err(AppError::new_with_status(
+                    warp::http::StatusCode::BAD_REQUEST,
+                    INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE,
+                )
+                .message(arcstr::literal!(
+                    "Only Clash Royale milestones can be evaluated"
+                ))
+                .param(arcstr::literal!("milestones"))
+                .into());
+            }
+            None => {}
+        }
+    }
+
+    // Load the player data.
+    let client = &context.game_clients.clash_royale;
+    let mut battlelogs = match request.battlelog {
+        Some(v) => v,
+        None if has_matches => {
+            let tag = get_clash_royale_tag(&request.clash_royale_tag, "battlelog")?;
+            client.get_player_battlelog(tag).await?
+        }
+        None => Vec::new(),
+    };
+    let player_info = match request.player_info {
+        Some(v) => Some(v),
+        None if has_achievements => {
+            let tag = get_clash_royale_tag(&request.clash_royale_tag, "playerInfo")?;
+            Some(client.get_player_info(tag).await?)
+        }
+        None => None,
+    };
+
+    // Keep only those battlelogs that happened after the starting date, in chronological order.
+    if let Some(since) = &request.since {
+        battlelogs.retain(|v| v.battle_time.0 >= since.0);
+    }
+    battlelogs.sort_by(|a, b| a.battle_time.0.cmp(&b.battle_time.0));
+
+    // Evaluate the milestones.
+    let game_progress: Box<dyn GameProgress> = Box::new(ClashRoyaleProgress::new(
+        battlelogs,
+        player_info,
+        request.snapshot,
+        request.expires_at.unwrap_or_else(DateTime::max_datetime),
+    ));
+
+    let mut progresses = HashMap::new();
+    progresses.insert(GameType::ClashRoyale, game_progress);
+
+    let verifier = MilestoneVerifier::new(progresses, HashSet::new());
+    let progress = verifier.verify_all_of(&milestones, None);
+
+    let response = ChallengeEvaluateResponse::new(progress);
+    Ok(warp::reply::json(&response))
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn get_clash_royale_tag<'a>(
+    tag: &'a Option<ArcStr>,
+    data_param: &'static str,
+) -> AppResult<&'a str> {
+    match tag {
+        Some(v) => Ok(v.as_str()),
+        None => Err(AppError::new_with_status(
+            warp::http::StatusCode::BAD_REQUEST,
+            INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE,
+        )
+        .message(
+            format!(
+                "Either the Clash Royale tag or the {} must be present",
+                data_param
+            )
+            .into(),
+        )
+        .param(arcstr::literal!("clashRoyaleTag"))),
+    }
+}
@@ -1,13 +1,17 @@
 pub use bet::*;
 pub use create::*;
 pub use create_config::*;
+pub use evaluate::*;
 pub use get::*;
 pub use info::*;
 pub use list::*;
+pub use schema::*;
 
 mod bet;
 mod create;
 mod create_config;
+mod evaluate;
 mod get;
 mod info;
 mod list;
+mod schema;
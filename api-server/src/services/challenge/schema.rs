@@ -0,0 +1,20 @@
+use std::ops::Deref;
+
+use lazy_static::lazy_static;
+use serde_json::Value;
+
+use commons::database::documents::APIChallengeMilestone;
+use commons::database::types::schema::root_schema_for;
+
+use crate::routes::RequestContext;
+
+lazy_static! {
+    static ref MILESTONE_SCHEMA: Value = root_schema_for::<APIChallengeMilestone>();
+}
+
+pub async fn schema_service(
+    _request_context: RequestContext<()>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let schema = MILESTONE_SCHEMA.deref();
+    Ok(warp::reply::json(schema))
+}
@@ -0,0 +1,23 @@
+use std::ops::Deref;
+
+use lazy_static::lazy_static;
+
+use commons::data::games::BrawlStarsBrawler;
+
+use crate::models::responses::data::games::brawl_stars::brawlers::BrawlStarsBrawlerGameDataResponse;
+use crate::routes::RequestContext;
+
+lazy_static! {
+    static ref BRAWLERS_LIST: Vec<BrawlStarsBrawlerGameDataResponse> =
+        BrawlStarsBrawler::enum_list()
+            .iter()
+            .map(|v| { BrawlStarsBrawlerGameDataResponse::new(*v) })
+            .collect();
+}
+
+pub async fn brawlers_service(
+    _request_context: RequestContext<()>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let list = BRAWLERS_LIST.deref();
+    Ok(warp::reply::json(list))
+}
@@ -0,0 +1,23 @@
+use std::ops::Deref;
+
+use lazy_static::lazy_static;
+
+use commons::data::games::BrawlStarsGameMode;
+
+use crate::models::responses::data::games::brawl_stars::game_modes::BrawlStarsGameModeGameDataResponse;
+use crate::routes::RequestContext;
+
+lazy_static! {
+    static ref GAME_MODES_LIST: Vec<BrawlStarsGameModeGameDataResponse> =
+        BrawlStarsGameMode::enum_list()
+            .iter()
+            .map(|v| { BrawlStarsGameModeGameDataResponse::new(*v) })
+            .collect();
+}
+
+pub async fn game_modes_service(
+    _request_context: RequestContext<()>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let list = GAME_MODES_LIST.deref();
+    Ok(warp::reply::json(list))
+}
@@ -0,0 +1,5 @@
+pub use brawlers::*;
+pub use game_modes::*;
+
+mod brawlers;
+mod game_modes;
@@ -2,15 +2,16 @@ use std::ops::Deref;
 
 use lazy_static::lazy_static;
 
-use commons::data::games::ClashRoyaleArena;
+use commons::data::games::ClashRoyaleCatalog;
 
 use crate::models::responses::data::games::clash_royale::arenas::ClashRoyaleArenaGameDataResponse;
 use crate::routes::RequestContext;
 
 lazy_static! {
-    static ref ARENAS_LIST: Vec<ClashRoyaleArenaGameDataResponse> = ClashRoyaleArena::enum_list()
+    static ref ARENAS_LIST: Vec<ClashRoyaleArenaGameDataResponse> = ClashRoyaleCatalog::instance()
+        .arenas()
         .iter()
-        .map(|v| { ClashRoyaleArenaGameDataResponse::new(*v) })
+        .map(ClashRoyaleArenaGameDataResponse::new)
         .collect();
 }
 
@@ -2,15 +2,16 @@ use std::ops::Deref;
 
 use lazy_static::lazy_static;
 
-use commons::data::games::ClashRoyaleCard;
+use commons::data::games::ClashRoyaleCatalog;
 
 use crate::models::responses::data::games::clash_royale::cards::ClashRoyaleCardGameDataResponse;
 use crate::routes::RequestContext;
 
 lazy_static! {
-    static ref CARDS_LIST: Vec<ClashRoyaleCardGameDataResponse> = ClashRoyaleCard::enum_list()
+    static ref CARDS_LIST: Vec<ClashRoyaleCardGameDataResponse> = ClashRoyaleCatalog::instance()
+        .cards()
         .iter()
-        .map(|v| { ClashRoyaleCardGameDataResponse::new(*v) })
+        .map(ClashRoyaleCardGameDataResponse::new)
         .collect();
 }
 
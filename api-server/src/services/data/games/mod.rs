@@ -1 +1,2 @@
+pub mod brawl_stars;
 pub mod clash_royale;
@@ -0,0 +1,102 @@
+use commons::constants::REMOTE_MUTEX_TIMEOUT;
+use commons::data::RemoteMutexGuard;
+use commons::database::documents::{BetDBDocument, BetDBState};
+use commons::database::types::Address;
+use commons::database::NullableOption;
+use commons::error::{
+    AppError, INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+    INPUT_VALIDATION_UNDEFINED_BET_ERROR_CODE,
+};
+use commons::programs::gmi_bet::state::BetState;
+use commons::server::requests::RequestWithParam;
+
+use crate::models::requests::internal::InternalBetReviewRequestBody;
+use crate::models::responses::challenge::{ChallengeCheckResponse, ChallengeCheckResponseStatus};
+use crate::routes::RequestContextWithAuth;
+use crate::services::bet::resolve_bet;
+
+/// Resolves a bet kept under review because of signs of collusion.
+pub async fn bet_review_service(
+    request_context: RequestContextWithAuth<
+        RequestWithParam<Address, InternalBetReviewRequestBody>,
+    >,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let context = request_context.app_context;
+    let request = request_context.request.body;
+
+    // Find bet in DB and blockchain.
+    let bet_address = request_context.request.param;
+    let bet_key = bet_address.to_uuid();
+    let bet_pubkey = bet_address.into();
+    let bet_program_client = context.solana_client.bet_program_client();
+
+    let (bet_db_document, _mutex) = match RemoteMutexGuard::<BetDBDocument>::acquire_with_timeout(
+        &bet_key,
+        REMOTE_MUTEX_TIMEOUT,
+        None,
+    )
+    .await?
+    {
+        Some(v) => v,
+        None => {
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                INPUT_VALIDATION_UNDEFINED_BET_ERROR_CODE,
+            )
+            .message(arcstr::literal!("Undefined bet"))
+            .param(arcstr::literal!("address"))
+            .into());
+        }
+    };
+
+    // Check state.
+    if !matches!(
+        &bet_db_document.state,
+        NullableOption::Value(BetDBState::UnderReview)
+    ) {
+        return Err(AppError::new_with_status(
+            warp::http::StatusCode::BAD_REQUEST,
+            INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+        )
+        .message(arcstr::literal!("Only Bets under review can be reviewed"))
+        .param(arcstr::literal!("address"))
+        .into());
+    }
+
+    let bet = bet_program_client.get_account(bet_pubkey).await?;
+    let bet_info = match bet.load_data() {
+        Some(v) if v.state == BetState::Applied => v,
+        _ => {
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+            )
+            .message(arcstr::literal!(
+                "Only Bets in not yet validated state can be reviewed"
+            ))
+            .param(arcstr::literal!("address"))
+            .into());
+        }
+    };
+
+    // Settle the bet with the result of the review. The fraud signals are kept for the record.
+    let result_status = if request.approved {
+        ChallengeCheckResponseStatus::Won
+    } else {
+        ChallengeCheckResponseStatus::Lost
+    };
+
+    resolve_bet(
+        &context,
+        &bet_db_document,
+        &bet_pubkey,
+        bet_info,
+        result_status,
+        None,
+        Vec::new(),
+    )
+    .await?;
+
+    let response = ChallengeCheckResponse::new(result_status);
+    Ok(warp::reply::json(&response))
+}
@@ -1,3 +1,5 @@
 pub use auth_check::*;
+pub use bet_review::*;
 
 mod auth_check;
+mod bet_review;
@@ -2,8 +2,12 @@ pub use get::*;
 pub use login::*;
 pub use logout::*;
 pub use update::*;
+pub use verification_check::*;
+pub use verification_create::*;
 
 mod get;
 mod login;
 mod logout;
 mod update;
+mod verification_check;
+mod verification_create;
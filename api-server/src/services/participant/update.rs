@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
+use commons::database::collections::ParticipantCollection;
 use commons::database::documents::APIParticipantGamesData;
 use commons::database::documents::{ParticipantAPIDocument, ParticipantDBDocument};
 use commons::database::types::{DBUuid, DBUuidType};
-use commons::database::{DBDocument, NullableOption};
+use commons::database::{DBCollection, DBDocument, NullableOption};
 use commons::error::AppResult;
 
 use crate::context::AppContext;
@@ -14,7 +15,7 @@ pub async fn update_service(
     request_context: RequestContextWithAuth<ParticipantUpdateRequestBody>,
 ) -> Result<impl warp::Reply, warp::Rejection> {
     let context = request_context.app_context;
-    let request = request_context.request;
+    let mut request = request_context.request;
 
     // Validate input.
     request.validate()?;
@@ -28,6 +29,34 @@ pub async fn update_service(
     )
     .unwrap();
 
+    if let NullableOption::Value(clash_royale) = &mut request.games_data.clash_royale {
+        // The ownership of the tag is only set by the verification.
+        clash_royale.verified = NullableOption::Missing;
+        clash_royale.verification = NullableOption::Missing;
+
+        // A new tag must be verified again.
+        if !clash_royale.tag.is_missing() {
+            let collection = ParticipantCollection::instance();
+            let participant = collection.get_one_by_key(&participant_key, None).await?;
+            let current_tag = participant
+                .as_ref()
+                .and_then(|v| v.clash_royale_data())
+                .map(|v| &v.tag);
+
+            let is_same_tag = match (current_tag, &clash_royale.tag) {
+                (Some(NullableOption::Value(current)), NullableOption::Value(tag)) => {
+                    current == tag
+                }
+                _ => false,
+            };
+
+            if !is_same_tag {
+                clash_royale.verified = NullableOption::Null;
+                clash_royale.verification = NullableOption::Null;
+            }
+        }
+    }
+
     let participant = ParticipantDBDocument {
         db_key: Some(participant_key.clone()),
         games_data: NullableOption::Value(request.games_data.into()),
@@ -53,5 +82,12 @@ async fn validate_information(
         }
     }
 
+    if let NullableOption::Value(v) = &data.brawl_stars {
+        if let NullableOption::Value(v) = &v.tag {
+            let client = context.game_clients.brawl_stars.clone();
+            client.get_player_info(v.as_str()).await?;
+        }
+    }
+
     Ok(())
 }
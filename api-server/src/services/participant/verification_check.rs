@@ -0,0 +1,93 @@
+use commons::database::collections::ParticipantCollection;
+use commons::database::documents::{ParticipantAPIDocument, ParticipantClashRoyaleGameData};
+use commons::database::NullableOption;
+use commons::error::{
+    AppError, INPUT_VALIDATION_EXPIRED_VALUE_ERROR_CODE,
+    INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+};
+
+use crate::routes::RequestContextWithAuth;
+
+pub async fn verification_check_service(
+    request_context: RequestContextWithAuth<()>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let context = request_context.app_context;
+
+    // Find participant in DB.
+    let participant_address = request_context.claims.address;
+    let participant_key = participant_address.to_uuid();
+
+    let participant = ParticipantCollection::get_by_key_or_reject(&participant_key, None).await?;
+
+    // Find the pending verification of the current tag.
+    let (tag, verification) = match participant.clash_royale_data() {
+        Some(ParticipantClashRoyaleGameData {
+            tag: NullableOption::Value(tag),
+            verification: NullableOption::Value(verification),
+            ..
+        }) if verification.tag.is_value() && verification.tag.unwrap_as_ref() == tag => {
+            (tag, verification)
+        }
+        _ => {
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+            )
+            .message(arcstr::literal!(
+                "The participant has not requested to verify the ClashRoyale tag"
+            ))
+            .param(arcstr::literal!("participant"))
+            .into());
+        }
+    };
+
+    if verification.is_expired() {
+        return Err(AppError::new_with_status(
+            warp::http::StatusCode::BAD_REQUEST,
+            INPUT_VALIDATION_EXPIRED_VALUE_ERROR_CODE,
+        )
+        .message(arcstr::literal!(
+            "The verification has expired, please request a new one"
+        ))
+        .param(arcstr::literal!("verification"))
+        .into());
+    }
+
+    // Check the player has set the required cards.
+    let client = context.game_clients.clash_royale.clone();
+    let player = client.get_fresh_player_info(tag.as_str()).await?;
+
+    if !verification.verify(&player) {
+        return Err(AppError::new_with_status(
+            warp::http::StatusCode::BAD_REQUEST,
+            INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+        )
+        .message(arcstr::literal!(
+            "The required cards are not set in the game yet"
+        ))
+        .param(arcstr::literal!("verification"))
+        .into());
+    }
+
+    // Update participant data only if the tag has not changed during the verification.
+    let participant =
+        match ParticipantCollection::verify_clash_royale_tag(&participant_key, tag).await? {
+            Some(v) => v,
+            None => {
+                return Err(AppError::new_with_status(
+                    warp::http::StatusCode::BAD_REQUEST,
+                    INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+                )
+                .message(arcstr::literal!(
+                    "The ClashRoyale tag has changed during the verification"
+                ))
+                .param(arcstr::literal!("participant"))
+                .into());
+            }
+        };
+
+    let mut response: ParticipantAPIDocument = participant.into();
+    response.remove_sensible_info();
+
+    Ok(warp::reply::json(&response))
+}
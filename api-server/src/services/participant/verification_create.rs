@@ -0,0 +1,83 @@
+use commons::database::collections::ParticipantCollection;
+use commons::database::documents::{
+    ClashRoyaleTagVerification, ParticipantAPIDocument, ParticipantClashRoyaleGameData,
+    ParticipantDBDocument, ParticipantGamesData,
+};
+use commons::database::types::DateTime;
+use commons::database::{DBDocument, NullableOption};
+use commons::error::{AppError, INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE};
+
+use crate::constants::TAG_VERIFICATION_EXPIRATION;
+use crate::models::requests::participant::ParticipantVerificationCreateRequestBody;
+use crate::routes::RequestContextWithAuth;
+
+pub async fn verification_create_service(
+    request_context: RequestContextWithAuth<ParticipantVerificationCreateRequestBody>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let context = request_context.app_context;
+    let request = request_context.request;
+
+    // Validate input.
+    request.validate()?;
+
+    // Find participant in DB.
+    let participant_address = request_context.claims.address;
+    let participant_key = participant_address.to_uuid();
+
+    let participant = ParticipantCollection::get_by_key_or_reject(&participant_key, None).await?;
+    let tag = match participant.clash_royale_data().map(|v| &v.tag) {
+        Some(NullableOption::Value(v)) => v.clone(),
+        _ => {
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+            )
+            .message(arcstr::literal!(
+                "The participant does not contain a ClashRoyale tag, please add it first"
+            ))
+            .param(arcstr::literal!("participant"))
+            .into());
+        }
+    };
+
+    // Choose the cards among the ones of the player.
+    let client = context.game_clients.clash_royale.clone();
+    let player = client.get_player_info(tag.as_str()).await?;
+    let expires_at = DateTime::now().after_seconds(TAG_VERIFICATION_EXPIRATION);
+
+    let verification =
+        match ClashRoyaleTagVerification::new_random(tag, request.kind, &player, expires_at) {
+            Some(v) => v,
+            None => {
+                return Err(AppError::new_with_status(
+                    warp::http::StatusCode::BAD_REQUEST,
+                    INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+                )
+                .message(arcstr::literal!(
+                    "The player does not have enough cards to verify the ClashRoyale tag"
+                ))
+                .param(arcstr::literal!("kind"))
+                .into());
+            }
+        };
+
+    // Update participant data.
+    let participant = ParticipantDBDocument {
+        db_key: Some(participant_key),
+        games_data: NullableOption::Value(ParticipantGamesData {
+            clash_royale: NullableOption::Value(ParticipantClashRoyaleGameData {
+                verification: NullableOption::Value(verification),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let participant = participant.insert_or_update(true).await?;
+
+    let mut response: ParticipantAPIDocument = participant.into();
+    response.remove_sensible_info();
+
+    Ok(warp::reply::json(&response))
+}
@@ -1,10 +1,14 @@
+use std::collections::HashSet;
+
 use commons::clients::solana::SolanaClient;
-use commons::database::collections::SignatureCollection;
+use commons::database::collections::{ChallengeCollection, SignatureCollection};
+use commons::database::documents::{APIChallengeMilestone, ChallengeMilestone};
 use commons::database::documents::{SignatureAction, SignatureDBDocument};
 use commons::database::types::DBUuid;
-use commons::database::NullableOption;
+use commons::database::{DBCollection, NullableOption};
 use commons::error::{
     AppError, AppResult, INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE,
+    INPUT_VALIDATION_UNDEFINED_CHALLENGE_ERROR_CODE,
     INPUT_VALIDATION_UNDEFINED_SIGNATURE_ERROR_CODE, INTERNAL_INCOMPLETE_ERROR_CODE,
 };
 
@@ -82,3 +86,91 @@ pub async fn validate_signature(
 
     Ok(signature_doc)
 }
+
+/// Checks that all the challenges referenced by the milestones exist and that they do not
+/// contain cyclic references.
+pub async fn validate_challenge_references(milestones: &[APIChallengeMilestone]) -> AppResult<()> {
+    let roots: Vec<DBUuid> = milestones
+        .iter()
+        .flat_map(|v| v.leaves())
+        .filter_map(|milestone| match milestone {
+            APIChallengeMilestone::OtherChallenge(v) => Some(v.challenge.key()),
+            _ => None,
+        })
+        .collect();
+
+    // Depth-first search keeping the current path to detect cycles.
+    let mut explored = HashSet::new();
+    let mut path: Vec<(DBUuid, Vec<DBUuid>)> = Vec::new();
+
+    for root in roots {
+        if explored.contains(&root) {
+            continue;
+        }
+
+        let references = get_challenge_references(&root).await?;
+        path.push((root, references));
+
+        while let Some((_, references)) = path.last_mut() {
+            match references.pop() {
+                Some(reference) => {
+                    if path.iter().any(|(key, _)| key == &reference) {
+                        return Err(AppError::new_with_status(
+                            warp::http::StatusCode::BAD_REQUEST,
+                            INPUT_VALIDATION_INCORRECT_VALUE_ERROR_CODE,
+                        )
+                        .message(arcstr::literal!(
+                            "The referenced challenges contain a cyclic reference"
+                        ))
+                        .param(arcstr::literal!("milestones")));
+                    }
+
+                    if explored.contains(&reference) {
+                        continue;
+                    }
+
+                    let references = get_challenge_references(&reference).await?;
+                    path.push((reference, references));
+                }
+                None => {
+                    let (key, _) = path.pop().unwrap();
+                    explored.insert(key);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn get_challenge_references(challenge_key: &DBUuid) -> AppResult<Vec<DBUuid>> {
+    let challenge = ChallengeCollection::instance()
+        .get_one_by_key(challenge_key, None)
+        .await?;
+
+    let challenge = match challenge {
+        Some(v) => v,
+        None => {
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                INPUT_VALIDATION_UNDEFINED_CHALLENGE_ERROR_CODE,
+            )
+            .message(arcstr::literal!("Undefined referenced challenge"))
+            .param(arcstr::literal!("milestones")));
+        }
+    };
+
+    let references = match &challenge.milestones {
+        NullableOption::Value(milestones) => milestones
+            .iter()
+            .flat_map(|v| v.leaves())
+            .filter_map(|milestone| match milestone {
+                ChallengeMilestone::OtherChallenge(v) => Some(v.challenge.key()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Ok(references)
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use commons::clients::games::BrawlStarsClient;
+use commons::config::{GameAPIConfig, GamesConfig};
+use commons::test::brawl_stars::{start_brawl_stars_mock, BRAWL_STARS_FIXTURE_TAG};
+
+fn build_client() -> BrawlStarsClient {
+    let brawl_stars = start_brawl_stars_mock();
+    let games_config = GamesConfig {
+        clash_royale: GameAPIConfig {
+            token: arcstr::literal!(""),
+            url: arcstr::literal!(""),
+        },
+        brawl_stars,
+    };
+
+    BrawlStarsClient::new(Arc::new(games_config), Arc::new(reqwest::Client::new()))
+}
+
+#[tokio::test]
+async fn get_player_info() {
+    let client = build_client();
+    let player = client
+        .get_player_info(BRAWL_STARS_FIXTURE_TAG)
+        .await
+        .unwrap();
+
+    assert_eq!(player.tag.unwrap().as_str(), "#8Q9RJ2LC");
+}
+
+#[tokio::test]
+async fn get_player_battlelog() {
+    let client = build_client();
+    let battlelogs = client
+        .get_player_battlelog(BRAWL_STARS_FIXTURE_TAG)
+        .await
+        .unwrap();
+
+    assert_eq!(battlelogs.len(), 3);
+}
+
+#[tokio::test]
+async fn get_undefined_player() {
+    let client = build_client();
+    let error = client.get_player_info("2YQ0V8L9").await.unwrap_err();
+
+    assert_eq!(error.code.as_str(), "undefined_brawl_stars_player");
+}
@@ -1,29 +1,290 @@
-use crate::tests::common::run_db_test_parallel;
+use std::sync::Arc;
+
+use reqwest::StatusCode;
+
+use commons::clients::games::ClashRoyaleClient;
+use commons::config::{GameAPIConfig, GamesConfig};
+use commons::data::games::ClashRoyaleCard;
+use commons::test::clash_royale::{
+    ClashRoyaleMock, ClashRoyaleMockError, CLASH_ROYALE_CARDS_MOCK_KEY, CLASH_ROYALE_FIXTURE_TAG,
+};
+
+use crate::tests::common::{clash_royale_mock, run_db_test_parallel};
+
+fn build_client(mock: &ClashRoyaleMock) -> ClashRoyaleClient {
+    let games_config = GamesConfig {
+        clash_royale: mock.config(),
+        brawl_stars: GameAPIConfig {
+            token: arcstr::literal!(""),
+            url: arcstr::literal!(""),
+        },
+    };
+
+    ClashRoyaleClient::new(Arc::new(games_config), Arc::new(reqwest::Client::new()))
+}
+
+#[tokio::test]
+async fn get_player_info() {
+    let mock = ClashRoyaleMock::start();
+    let client = build_client(&mock);
+    let player = client
+        .get_player_info(CLASH_ROYALE_FIXTURE_TAG)
+        .await
+        .unwrap();
+
+    assert_eq!(player.tag.unwrap().as_str(), "#82L0C9YG");
+    assert_eq!(player.current_deck.unwrap().len(), 8);
+}
+
+#[tokio::test]
+async fn get_player_battlelog() {
+    let mock = ClashRoyaleMock::start();
+    mock.push_battle(
+        CLASH_ROYALE_FIXTURE_TAG,
+        serde_json::json!({ "type": "PvP", "battleTime": "20211116T193000.000Z" }),
+    );
+
+    let client = build_client(&mock);
+    let battlelogs = client
+        .get_player_battlelog(CLASH_ROYALE_FIXTURE_TAG)
+        .await
+        .unwrap();
+
+    assert_eq!(battlelogs.len(), 4);
+}
+
+#[tokio::test]
+async fn get_undefined_player() {
+    let mock = ClashRoyaleMock::start();
+    let client = build_client(&mock);
+    let error = client.get_player_info("2YQ0V8L9").await.unwrap_err();
+
+    assert_eq!(error.code.as_str(), "undefined_clash_royale_player");
+}
+
+#[tokio::test]
+async fn get_cached_player_info() {
+    let mock = ClashRoyaleMock::start();
+    let client = build_client(&mock);
+
+    client
+        .get_player_info(CLASH_ROYALE_FIXTURE_TAG)
+        .await
+        .unwrap();
+    client
+        .get_player_info(CLASH_ROYALE_FIXTURE_TAG)
+        .await
+        .unwrap();
+    assert_eq!(mock.request_count(CLASH_ROYALE_FIXTURE_TAG), 1);
+
+    // Fresh requests skip the cache.
+    mock.update_player(CLASH_ROYALE_FIXTURE_TAG, |player| {
+        player["trophies"] = serde_json::json!(6000);
+    });
+
+    let player = client
+        .get_fresh_player_info(CLASH_ROYALE_FIXTURE_TAG)
+        .await
+        .unwrap();
+    assert_eq!(player.trophies, Some(6000));
+    assert_eq!(mock.request_count(CLASH_ROYALE_FIXTURE_TAG), 2);
+}
+
+#[tokio::test]
+async fn retry_throttled_request() {
+    let mock = ClashRoyaleMock::start();
+    mock.push_error(
+        CLASH_ROYALE_FIXTURE_TAG,
+        ClashRoyaleMockError::throttled(Some(0)),
+    );
+    mock.push_error(
+        CLASH_ROYALE_FIXTURE_TAG,
+        ClashRoyaleMockError::new(StatusCode::SERVICE_UNAVAILABLE),
+    );
+
+    let client = build_client(&mock);
+    client
+        .get_player_info(CLASH_ROYALE_FIXTURE_TAG)
+        .await
+        .unwrap();
+
+    assert_eq!(mock.request_count(CLASH_ROYALE_FIXTURE_TAG), 3);
+}
+
+#[tokio::test]
+async fn get_throttled_error() {
+    let mock = ClashRoyaleMock::start();
+    for _ in 0..4 {
+        mock.push_error(
+            CLASH_ROYALE_FIXTURE_TAG,
+            ClashRoyaleMockError::throttled(Some(0)),
+        );
+    }
+
+    let client = build_client(&mock);
+    let error = client
+        .get_player_info(CLASH_ROYALE_FIXTURE_TAG)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.code.as_str(), "upstream_throttled");
+}
+
+#[tokio::test]
+async fn get_clan_info() {
+    let mock = ClashRoyaleMock::start();
+    mock.add_clan(
+        "9VQJ2Y0R",
+        serde_json::json!({ "name": "Clan", "type": "open", "members": 1 }),
+        vec![serde_json::json!({ "tag": "#82L0C9YG", "role": "leader", "lastSeen": null })],
+        Vec::new(),
+    );
+
+    let client = build_client(&mock);
+    let clan = client.get_clan_info("9VQJ2Y0R").await.unwrap();
+
+    assert_eq!(clan.tag.as_str(), "#9VQJ2Y0R");
+    assert_eq!(clan.kind.as_deref(), Some("open"));
+    assert_eq!(clan.members, Some(1));
+
+    let members = client.get_clan_members("9VQJ2Y0R").await.unwrap();
+
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].tag.as_str(), "#82L0C9YG");
+    assert!(members[0].last_seen.is_none());
+}
+
+#[tokio::test]
+async fn get_clan_war_log() {
+    let mock = ClashRoyaleMock::start();
+    mock.add_clan(
+        "9VQJ2Y0R",
+        serde_json::json!({ "name": "Clan" }),
+        Vec::new(),
+        vec![serde_json::json!({
+            "seasonId": 80,
+            "sectionIndex": 3,
+            "createdDate": "20211115T094047.000Z",
+            "standings": [{
+                "rank": 1,
+                "clan": {
+                    "tag": "#9VQJ2Y0R",
+                    "finishTime": "20211113T102154.000Z",
+                    "participants": [{ "tag": "#82L0C9YG", "fame": 1800, "decksUsed": 16 }]
+                }
+            }, {
+                "rank": 2,
+                "clan": { "tag": "#2PYQ8GC0", "finishTime": null }
+            }]
+        })],
+    );
+
+    let client = build_client(&mock);
+    let war_log = client.get_clan_war_log("9VQJ2Y0R").await.unwrap();
+
+    assert_eq!(war_log.len(), 1);
+
+    let race = &war_log[0];
+    assert!(race
+        .clan_standing("#9VQJ2Y0R")
+        .unwrap()
+        .clan
+        .finish_time
+        .is_some());
+    assert!(race
+        .clan_standing("#2PYQ8GC0")
+        .unwrap()
+        .clan
+        .finish_time
+        .is_none());
+    assert_eq!(race.participant("#82L0C9YG").unwrap().decks_used, Some(16));
+}
+
+#[tokio::test]
+async fn get_undefined_clan() {
+    let mock = ClashRoyaleMock::start();
+    let client = build_client(&mock);
+
+    let error = client.get_clan_info("9VQJ2Y0R").await.unwrap_err();
+    assert_eq!(error.code.as_str(), "undefined_clash_royale_clan");
+
+    let error = client.get_clan_members("9VQJ2Y0R").await.unwrap_err();
+    assert_eq!(error.code.as_str(), "undefined_clash_royale_clan");
+
+    let error = client.get_clan_war_log("9VQJ2Y0R").await.unwrap_err();
+    assert_eq!(error.code.as_str(), "undefined_clash_royale_clan");
+}
+
+#[tokio::test]
+async fn get_tournament_info() {
+    let mock = ClashRoyaleMock::start();
+    mock.add_tournament(
+        "2LJQ8PV0",
+        serde_json::json!({
+            "name": "Tournament",
+            "status": "ended",
+            "createdTime": "20211115T094047.000Z",
+            "startedTime": "20211115T100000.000Z",
+            "endedTime": null,
+            "membersList": [{ "tag": "#82L0C9YG", "score": 12, "rank": 1 }]
+        }),
+    );
+
+    let client = build_client(&mock);
+    let tournament = client.get_tournament_info("2LJQ8PV0").await.unwrap();
+
+    assert!(tournament.has_ended());
+    assert!(tournament.started_time.is_some());
+    assert!(tournament.ended_time.is_none());
+    assert_eq!(tournament.member("#82L0C9YG").unwrap().rank, Some(1));
+
+    let error = client.get_tournament_info("9VQJ2Y0R").await.unwrap_err();
+    assert_eq!(error.code.as_str(), "undefined_clash_royale_tournament");
+}
+
+#[tokio::test]
+async fn get_cards() {
+    let mock = ClashRoyaleMock::start();
+    let client = build_client(&mock);
+    let cards = client.get_cards().await.unwrap();
+
+    assert_eq!(cards.items.len(), ClashRoyaleCard::enum_list().len());
+    assert!(cards.unknown_cards().is_empty());
+    assert!(cards.removed_cards().is_empty());
+
+    // A card added to the game.
+    let mut items = cards
+        .items
+        .iter()
+        .map(|v| serde_json::to_value(v).unwrap())
+        .collect::<Vec<_>>();
+    items.push(serde_json::json!({ "id": 26999999, "name": "New card", "maxLevel": 14 }));
+    mock.set_cards(items);
+
+    let cards = client.get_cards().await.unwrap();
+    let unknown: Vec<_> = cards.unknown_cards().iter().map(|v| v.id).collect();
+
+    assert_eq!(unknown, vec![26999999]);
+    assert_eq!(mock.request_count(CLASH_ROYALE_CARDS_MOCK_KEY), 2);
+}
 
 #[test]
-fn get_player_status() {
-    run_db_test_parallel(|context, config, _uid_generator| async move {
-        let games = config.game_apis.as_ref().unwrap();
-        let response = context
-            .http_client
-            .get(format!("{}/players/%2382L0C9YG", games.clash_royale.url.as_str()).as_str())
-            .bearer_auth(games.clash_royale.token.as_str())
-            .send()
+fn context_uses_mock() {
+    run_db_test_parallel(|context, _config, _uid_generator| async move {
+        let tag = "9CQ2U8QJ";
+        clash_royale_mock().add_fixture_player(tag);
+
+        let battlelogs = context
+            .game_clients
+            .clash_royale
+            .get_player_battlelog(tag)
             .await
             .unwrap();
 
-        let text = response.text().await.unwrap();
-        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
-        let value = vec![
-            json["currentDeck"][0]["name"].clone(),
-            json["currentDeck"][1]["name"].clone(),
-            json["currentDeck"][2]["name"].clone(),
-            json["currentDeck"][3]["name"].clone(),
-            json["currentDeck"][4]["name"].clone(),
-            json["currentDeck"][5]["name"].clone(),
-            json["currentDeck"][6]["name"].clone(),
-            json["currentDeck"][7]["name"].clone(),
-        ];
-        println!("{:?}", value)
+        assert_eq!(battlelogs.len(), 3);
+        assert_eq!(
+            battlelogs[0].team.as_ref().unwrap()[0].tag.as_deref(),
+            Some("#9CQ2U8QJ")
+        );
     });
 }
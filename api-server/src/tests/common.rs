@@ -9,6 +9,7 @@ use tokio::sync::{oneshot, Mutex};
 
 use commons::config::{read_app_config, InitServiceConfig};
 use commons::constants::CONFIG_FILE;
+use commons::test::clash_royale::ClashRoyaleMock;
 use commons::test::commons::{
     acquire_test_reset_for_parallel, reset_db, set_test_reset_for_serial,
 };
@@ -32,6 +33,12 @@ lazy_static! {
         .enable_all()
         .build()
         .unwrap();
+    static ref CLASH_ROYALE_MOCK: ClashRoyaleMock = ClashRoyaleMock::start();
+}
+
+/// The mock of the Clash Royale API the game clients of the tests point at.
+pub fn clash_royale_mock() -> &'static ClashRoyaleMock {
+    &CLASH_ROYALE_MOCK
 }
 
 async fn setup(reset_config: bool) -> ServerResult<(Arc<AppContext>, Arc<InitServiceConfig>)> {
@@ -56,7 +63,14 @@ async fn setup(reset_config: bool) -> ServerResult<(Arc<AppContext>, Arc<InitSer
             let path = format!("{}/../deployment/{}", dir, CONFIG_FILE);
             std::env::set_var("CONFIG_PATH", &path);
 
-            let config = Arc::new(read_app_config()?);
+            let mut config = read_app_config()?;
+
+            // Game APIs
+            if let Some(game_apis) = &mut config.game_apis {
+                Arc::make_mut(game_apis).clash_royale = clash_royale_mock().config();
+            }
+
+            let config = Arc::new(config);
 
             let (shutdown_tx, _) = oneshot::channel();
             let context = setup_context(&config, shutdown_tx).await?;
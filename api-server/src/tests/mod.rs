@@ -1,2 +1,5 @@
+mod brawl_stars_api;
+mod clash_api;
 mod common;
 mod routes;
+mod workers;
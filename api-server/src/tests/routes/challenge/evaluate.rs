@@ -0,0 +1,205 @@
+use reqwest::StatusCode;
+use serde_json::json;
+
+use commons::database::documents::AuthenticationDBDocument;
+use commons::database::types::game::GameMilestone;
+use commons::database::types::{Address, DBUuid, DBUuidType};
+use commons::database::{DBDocument, NullableOption};
+use commons::server::AUTHENTICATION_BEARER;
+use commons::test::{assert_http_body, assert_http_response_status};
+
+use crate::models::responses::challenge::{
+    ChallengeCheckResponseStatus, ChallengeEvaluateResponse,
+};
+use crate::routes::build_routes;
+use crate::tests::common::{clash_royale_mock, run_db_test_parallel};
+
+#[test]
+fn evaluate_ok() {
+    run_db_test_parallel(|context, config, next_uid| async move {
+        let filter = Box::new(build_routes(&context, &config));
+        let token = insert_api_token(next_uid()).await;
+
+        let tag = "2PYLQGRJ";
+        clash_royale_mock().add_fixture_player(tag);
+
+        // Test request.
+        let milestone = GameMilestone::from_rule("win any 2 matches").unwrap();
+        let response = warp::test::request()
+            .path("/challenge/evaluate")
+            .method("POST")
+            .header(
+                "authorization",
+                format!("{}{}", AUTHENTICATION_BEARER, token),
+            )
+            .json(&json!({
+                "milestones": [{ "T": "gameMilestone", "V": { "milestone": milestone } }],
+                "clashRoyaleTag": tag,
+            }))
+            .reply(filter.as_ref())
+            .await;
+
+        // Check response.
+        assert_http_response_status(&response, StatusCode::OK, true);
+
+        let body: ChallengeEvaluateResponse = assert_http_body(&response, true);
+        assert_eq!(body.status, ChallengeCheckResponseStatus::Won);
+    });
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn evaluate_lost() {
+    run_db_test_parallel(|context, config, next_uid| async move {
+        let filter = Box::new(build_routes(&context, &config));
+        let token = insert_api_token(next_uid()).await;
+
+        let tag = "2PYLQGRU";
+        clash_royale_mock().add_fixture_player(tag);
+
+        // Test request.
+        let milestone = GameMilestone::from_rule("win 2 matches").unwrap();
+        let response = warp::test::request()
+            .path("/challenge/evaluate")
+            .method("POST")
+            .header(
+                "authorization",
+                format!("{}{}", AUTHENTICATION_BEARER, token),
+            )
+            .json(&json!({
+                "milestones": [{ "T": "gameMilestone", "V": { "milestone": milestone } }],
+                "clashRoyaleTag": tag,
+            }))
+            .reply(filter.as_ref())
+            .await;
+
+        // Check response.
+        assert_http_response_status(&response, StatusCode::OK, true);
+
+        let body: ChallengeEvaluateResponse = assert_http_body(&response, true);
+        assert_eq!(body.status, ChallengeCheckResponseStatus::Lost);
+    });
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn evaluate_without_player() {
+    run_db_test_parallel(|context, config, next_uid| async move {
+        let filter = Box::new(build_routes(&context, &config));
+        let token = insert_api_token(next_uid()).await;
+
+        // Test request.
+        let milestone = GameMilestone::from_rule("win any 2 matches").unwrap();
+        let response = warp::test::request()
+            .path("/challenge/evaluate")
+            .method("POST")
+            .header(
+                "authorization",
+                format!("{}{}", AUTHENTICATION_BEARER, token),
+            )
+            .json(&json!({
+                "milestones": [{ "T": "gameMilestone", "V": { "milestone": milestone } }],
+            }))
+            .reply(filter.as_ref())
+            .await;
+
+        // Check response.
+        assert_http_response_status(&response, StatusCode::BAD_REQUEST, true);
+    });
+}
+
+#[test]
+fn evaluate_statistics_expired() {
+    run_db_test_parallel(|context, config, next_uid| async move {
+        let filter = Box::new(build_routes(&context, &config));
+        let token = insert_api_token(next_uid()).await;
+
+        let battlelog: Vec<_> = (1..=3)
+            .map(|minute| {
+                json!({
+                    "type": "PvP",
+                    "battleTime": format!("20220101T10{:02}00.000Z", minute),
+                    "team": [{ "tag": "#A", "crowns": 1 }],
+                    "opponent": [{ "tag": "#B", "crowns": 0 }],
+                })
+            })
+            .collect();
+
+        // Test request.
+        let milestone =
+            GameMilestone::from_rule("statistics where wins >= 3 and winRate = 100").unwrap();
+        let request = json!({
+            "milestones": [{ "T": "gameMilestone", "V": { "milestone": milestone } }],
+            "battlelog": battlelog,
+        });
+
+        // Statistics are only completed once the evaluation expires.
+        let response = warp::test::request()
+            .path("/challenge/evaluate")
+            .method("POST")
+            .header(
+                "authorization",
+                format!("{}{}", AUTHENTICATION_BEARER, token),
+            )
+            .json(&request)
+            .reply(filter.as_ref())
+            .await;
+
+        assert_http_response_status(&response, StatusCode::OK, true);
+
+        let body: ChallengeEvaluateResponse = assert_http_body(&response, true);
+        assert_eq!(body.status, ChallengeCheckResponseStatus::Initiated);
+
+        // 2022-01-01T12:00:00Z
+        let mut request = request;
+        request["expiresAt"] = json!(1641038400000i64);
+
+        let response = warp::test::request()
+            .path("/challenge/evaluate")
+            .method("POST")
+            .header(
+                "authorization",
+                format!("{}{}", AUTHENTICATION_BEARER, token),
+            )
+            .json(&request)
+            .reply(filter.as_ref())
+            .await;
+
+        assert_http_response_status(&response, StatusCode::OK, true);
+
+        let body: ChallengeEvaluateResponse = assert_http_body(&response, true);
+        assert_eq!(body.status, ChallengeCheckResponseStatus::Won);
+    });
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+async fn insert_api_token(uid: usize) -> DBUuid {
+    let token = DBUuid::new_with_code_for_test(uid, DBUuidType::APIToken);
+    let authentication = AuthenticationDBDocument {
+        db_key: Some(token.clone()),
+        address: NullableOption::Value(
+            Address::parse_str("11111111111111111111111111111111").unwrap(),
+        ),
+        ..Default::default()
+    };
+
+    authentication
+        .insert(false)
+        .await
+        .expect("Cannot insert the API token");
+
+    token
+}
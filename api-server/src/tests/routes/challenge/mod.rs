@@ -0,0 +1 @@
+mod evaluate;
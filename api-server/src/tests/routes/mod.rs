@@ -1 +1,3 @@
+mod challenge;
 mod mail_list;
+mod participant;
@@ -0,0 +1 @@
+mod verification_check;
@@ -0,0 +1,142 @@
+use arcstr::ArcStr;
+use reqwest::StatusCode;
+
+use commons::data::games::ClashRoyaleCard;
+use commons::database::collections::ParticipantCollection;
+use commons::database::documents::{
+    AuthenticationDBDocument, ClashRoyaleTagVerification, ClashRoyaleTagVerificationKind,
+    ParticipantAPIDocument, ParticipantClashRoyaleGameData, ParticipantDBDocument,
+    ParticipantGamesData,
+};
+use commons::database::types::{Address, DBUuid, DBUuidType, DateTime};
+use commons::database::{DBCollection, DBDocument, NullableOption};
+use commons::server::AUTHENTICATION_BEARER;
+use commons::solana_sdk::pubkey::Pubkey;
+use commons::test::{assert_http_body, assert_http_response_status};
+
+use crate::routes::build_routes;
+use crate::tests::common::{clash_royale_mock, run_db_test_parallel};
+
+#[test]
+fn verification_check_ok() {
+    run_db_test_parallel(|context, config, next_uid| async move {
+        let filter = Box::new(build_routes(&context, &config));
+        let (token, participant_key) = insert_api_token(next_uid()).await;
+
+        // The favourite card of the fixture is the Knight.
+        let tag = arcstr::literal!("2PYLQGRV");
+        clash_royale_mock().add_fixture_player(tag.as_str());
+        insert_participant(&participant_key, &tag, &tag).await;
+
+        // Test request.
+        let response = warp::test::request()
+            .path("/participant/verification/check")
+            .method("POST")
+            .header(
+                "authorization",
+                format!("{}{}", AUTHENTICATION_BEARER, token),
+            )
+            .reply(filter.as_ref())
+            .await;
+
+        // Check response.
+        assert_http_response_status(&response, StatusCode::OK, true);
+
+        let body: ParticipantAPIDocument = assert_http_body(&response, true);
+        let games_data = body.games_data.unwrap();
+        let clash_royale = games_data.clash_royale.unwrap();
+        assert_eq!(clash_royale.verified, NullableOption::Value(true));
+        assert!(!clash_royale.verification.is_value());
+    });
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn verification_check_tag_changed() {
+    run_db_test_parallel(|_context, _config, next_uid| async move {
+        let (_, participant_key) = insert_api_token(next_uid()).await;
+
+        // The tag is changed after the verification of the previous one was loaded.
+        let verified_tag = arcstr::literal!("2PYLQGRW");
+        let new_tag = arcstr::literal!("2PYLQGRX");
+        insert_participant(&participant_key, &new_tag, &verified_tag).await;
+
+        let participant =
+            ParticipantCollection::verify_clash_royale_tag(&participant_key, &verified_tag)
+                .await
+                .expect("Cannot verify the tag");
+        assert!(participant.is_none());
+
+        // Check database.
+        let participant = ParticipantCollection::instance()
+            .get_one_by_key(&participant_key, None)
+            .await
+            .expect("Cannot get the participant from DB")
+            .unwrap();
+        let clash_royale = participant.clash_royale_data().unwrap();
+        assert_eq!(clash_royale.tag, NullableOption::Value(new_tag.clone()));
+        assert!(!clash_royale.is_verified());
+
+        // The current tag is verified.
+        let participant =
+            ParticipantCollection::verify_clash_royale_tag(&participant_key, &new_tag)
+                .await
+                .expect("Cannot verify the tag")
+                .unwrap();
+        let clash_royale = participant.clash_royale_data().unwrap();
+        assert_eq!(clash_royale.tag, NullableOption::Value(new_tag));
+        assert!(clash_royale.is_verified());
+        assert!(!clash_royale.verification.is_value());
+    });
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Inserts an API token of a new address so that each test has its own participant.
+async fn insert_api_token(uid: usize) -> (DBUuid, DBUuid) {
+    let token = DBUuid::new_with_code_for_test(uid, DBUuidType::APIToken);
+    let address = Address::from(Pubkey::new_unique());
+    let authentication = AuthenticationDBDocument {
+        db_key: Some(token.clone()),
+        address: NullableOption::Value(address.clone()),
+        ..Default::default()
+    };
+
+    authentication
+        .insert(false)
+        .await
+        .expect("Cannot insert the API token");
+
+    (token, address.to_uuid())
+}
+
+async fn insert_participant(participant_key: &DBUuid, tag: &ArcStr, verification_tag: &ArcStr) {
+    let verification = ClashRoyaleTagVerification {
+        tag: NullableOption::Value(verification_tag.clone()),
+        kind: NullableOption::Value(ClashRoyaleTagVerificationKind::FavouriteCard),
+        cards: NullableOption::Value(vec![ClashRoyaleCard::Knight]),
+        expires_at: NullableOption::Value(DateTime::max_datetime()),
+    };
+    let participant = ParticipantDBDocument {
+        db_key: Some(participant_key.clone()),
+        games_data: NullableOption::Value(ParticipantGamesData {
+            clash_royale: NullableOption::Value(ParticipantClashRoyaleGameData {
+                tag: NullableOption::Value(tag.clone()),
+                verified: NullableOption::Null,
+                verification: NullableOption::Value(verification),
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    participant
+        .insert(false)
+        .await
+        .expect("Cannot insert the participant");
+}
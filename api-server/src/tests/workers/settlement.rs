@@ -0,0 +1,101 @@
+use commons::data::RemoteMutexGuard;
+use commons::database::collections::BetCollection;
+use commons::database::documents::{BetDBDocument, BetDBState, FraudConfig};
+use commons::database::types::{Address, DBUuid};
+use commons::database::{DBCollection, DBDocument, NullableOption};
+use commons::solana_sdk::pubkey::Pubkey;
+
+use crate::tests::common::run_db_test_parallel;
+use crate::workers::settle_applied_bet;
+
+#[test]
+fn settlement_skips_locked_bet() {
+    run_db_test_parallel(|context, _config, _next_uid| async move {
+        let (bet_pubkey, bet_key) = insert_bet(BetDBState::Created).await;
+
+        // The bet is being checked by a request.
+        let (_, _mutex) = RemoteMutexGuard::<BetDBDocument>::try_acquire(&bet_key, None)
+            .await
+            .expect("Cannot lock the bet")
+            .unwrap();
+
+        let status = settle_applied_bet(&context, &FraudConfig::default(), &bet_pubkey)
+            .await
+            .expect("Cannot settle the bet");
+        assert!(status.is_none());
+
+        assert_state(&bet_key, BetDBState::Created).await;
+    });
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn settlement_skips_bet_under_review() {
+    run_db_test_parallel(|context, _config, _next_uid| async move {
+        let (bet_pubkey, bet_key) = insert_bet(BetDBState::UnderReview).await;
+
+        let status = settle_applied_bet(&context, &FraudConfig::default(), &bet_pubkey)
+            .await
+            .expect("Cannot settle the bet");
+        assert!(status.is_none());
+
+        assert_state(&bet_key, BetDBState::UnderReview).await;
+    });
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn settlement_skips_settled_bets() {
+    run_db_test_parallel(|context, _config, _next_uid| async move {
+        for state in [
+            BetDBState::Won,
+            BetDBState::Lost,
+            BetDBState::Expired,
+            BetDBState::ExpiredNotInBlockchain,
+        ] {
+            let (bet_pubkey, bet_key) = insert_bet(state).await;
+
+            let status = settle_applied_bet(&context, &FraudConfig::default(), &bet_pubkey)
+                .await
+                .expect("Cannot settle the bet");
+            assert!(status.is_none(), "The {:?} bet is not skipped", state);
+
+            assert_state(&bet_key, state).await;
+        }
+    });
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Inserts a bet of a new address so that each test has its own bet.
+async fn insert_bet(state: BetDBState) -> (Pubkey, DBUuid) {
+    let bet_pubkey = Pubkey::new_unique();
+    let bet_key = Address::from(bet_pubkey).to_uuid();
+    let bet = BetDBDocument {
+        db_key: Some(bet_key.clone()),
+        state: NullableOption::Value(state),
+        ..Default::default()
+    };
+
+    bet.insert(false).await.expect("Cannot insert the bet");
+
+    (bet_pubkey, bet_key)
+}
+
+async fn assert_state(bet_key: &DBUuid, state: BetDBState) {
+    let bet = BetCollection::instance()
+        .get_one_by_key(bet_key, None)
+        .await
+        .expect("Cannot get the bet from DB")
+        .unwrap();
+
+    assert_eq!(bet.state, NullableOption::Value(state));
+}
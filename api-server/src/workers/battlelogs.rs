@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use commons::database::collections::BetCollection;
+use commons::database::NullableOption;
+use commons::error::AppResult;
+
+use crate::constants::BATTLELOG_INGESTION_INTERVAL;
+use crate::context::AppContext;
+
+/// Periodically stores the latest matches of the participants with active bets, so that
+/// they can be verified even after they leave the window returned by the game APIs.
+pub async fn battlelog_ingester(context: Arc<AppContext>) {
+    let mut interval = tokio::time::interval(Duration::from_secs(BATTLELOG_INGESTION_INTERVAL));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = ingest_battlelogs(&context).await {
+            remote_error!("[workers::battlelogs] Cannot load the active bets: {}", e);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+async fn ingest_battlelogs(context: &Arc<AppContext>) -> AppResult<()> {
+    let bets = BetCollection::get_all_created().await?;
+    let mut ingested_participants = HashSet::new();
+
+    for bet in bets {
+        let participant = match &bet.participant {
+            NullableOption::Value(v) if v.is_document() => v,
+            _ => continue,
+        };
+
+        // Participants can have many active bets.
+        if !ingested_participants.insert(participant.key()) {
+            continue;
+        }
+
+        let games_data = match &participant.unwrap_document_as_ref().games_data {
+            NullableOption::Value(v) => v,
+            _ => continue,
+        };
+
+        for verifier in context.game_verifiers.all() {
+            if let Err(e) = verifier.ingest_matches(games_data).await {
+                remote_warn!(
+                    "[workers::battlelogs] Cannot ingest the {:?} matches of participant {}: {}",
+                    verifier.game_type(),
+                    participant.key(),
+                    e
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+pub use battlelogs::*;
+pub use settlement::*;
+
+use commons::config::InitServiceConfig;
+
+use crate::context::AppContext;
+
+mod battlelogs;
+mod settlement;
+
+/// Starts the background tasks of the server.
+pub fn start_workers(context: &Arc<AppContext>, config: &Arc<InitServiceConfig>) {
+    tokio::spawn(battlelog_ingester(context.clone()));
+    tokio::spawn(bet_settler(context.clone(), config.clone()));
+}
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use commons::config::InitServiceConfig;
+use commons::data::RemoteMutexGuard;
+use commons::database::documents::{BetDBDocument, BetDBState, FraudConfig};
+use commons::database::types::Address;
+use commons::database::NullableOption;
+use commons::error::AppResult;
+use commons::programs::gmi_bet::state::BetState;
+use commons::solana_sdk::pubkey::Pubkey;
+
+use crate::constants::BET_SETTLEMENT_INTERVAL;
+use crate::context::AppContext;
+use crate::models::responses::challenge::ChallengeCheckResponseStatus;
+use crate::services::bet::settle_bet;
+
+/// Periodically settles the applied bets, so that they are resolved even if their owners
+/// never check them.
+pub async fn bet_settler(context: Arc<AppContext>, config: Arc<InitServiceConfig>) {
+    let mut interval = tokio::time::interval(Duration::from_secs(BET_SETTLEMENT_INTERVAL));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = settle_bets(&context, &config).await {
+            remote_error!("[workers::settlement] Cannot load the applied bets: {}", e);
+        }
+    }
+}
+
+/// Settles a bet listed as applied in the blockchain. Returns `None` if the bet is skipped
+/// because it is being checked by a request or another node, it is already settled or under
+/// review in the DB, or it is no longer applied in the blockchain.
+pub(crate) async fn settle_applied_bet(
+    context: &Arc<AppContext>,
+    fraud_config: &FraudConfig,
+    bet_pubkey: &Pubkey,
+) -> AppResult<Option<ChallengeCheckResponseStatus>> {
+    let bet_key = Address::from(*bet_pubkey).to_uuid();
+    let (bet_db_document, _mutex) =
+        match RemoteMutexGuard::<BetDBDocument>::try_acquire(&bet_key, None).await? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+    // Bets under review are resolved manually and settled bets are skipped.
+    if !is_waiting_for_settlement(&bet_db_document) {
+        return Ok(None);
+    }
+
+    // The bet can be settled by a request between listing it and locking it.
+    let bet = context
+        .solana_client
+        .bet_program_client()
+        .get_account(*bet_pubkey)
+        .await?;
+    let bet_info = match bet.load_data() {
+        Some(v) if v.state == BetState::Applied => v,
+        _ => return Ok(None),
+    };
+
+    let (status, _) = settle_bet(
+        context,
+        fraud_config,
+        &bet_db_document,
+        bet_pubkey,
+        bet_info,
+    )
+    .await?;
+
+    Ok(Some(status))
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+async fn settle_bets(context: &Arc<AppContext>, config: &Arc<InitServiceConfig>) -> AppResult<()> {
+    let db_config = config.db_config().await;
+    let bets = context
+        .solana_client
+        .bet_program_client()
+        .find_by_state(BetState::Applied)
+        .await?;
+
+    for (bet_pubkey, _) in bets {
+        match settle_applied_bet(context, &db_config.fraud, &bet_pubkey).await {
+            Ok(None)
+            | Ok(Some(ChallengeCheckResponseStatus::NotInitiated))
+            | Ok(Some(ChallengeCheckResponseStatus::Initiated)) => {}
+            Ok(Some(status)) => {
+                remote_info!(
+                    "[workers::settlement] Bet {} settled as {:?}",
+                    bet_pubkey,
+                    status
+                );
+            }
+            Err(e) => {
+                remote_warn!(
+                    "[workers::settlement] Cannot settle the bet {}: {}",
+                    bet_pubkey,
+                    e
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_waiting_for_settlement(bet_db_document: &BetDBDocument) -> bool {
+    match &bet_db_document.state {
+        NullableOption::Value(state) => {
+            matches!(state, BetDBState::WaitingForCreating | BetDBState::Created)
+        }
+        _ => true,
+    }
+}
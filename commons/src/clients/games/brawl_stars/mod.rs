@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use reqwest::StatusCode;
+
+pub use responses::*;
+
+use crate::config::GamesConfig;
+use crate::data::games::brawl_stars_tag_validator;
+use crate::error::{AppError, AppResult};
+
+mod responses;
+
+pub struct BrawlStarsClient {
+    pub games_config: Arc<GamesConfig>,
+    pub http_client: Arc<reqwest::Client>,
+}
+
+impl BrawlStarsClient {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(games_config: Arc<GamesConfig>, http_client: Arc<reqwest::Client>) -> Self {
+        BrawlStarsClient {
+            games_config,
+            http_client,
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub async fn get_player_info(&self, tag: &str) -> AppResult<BrawlStarsPlayerInfoResponse> {
+        if !brawl_stars_tag_validator(tag) {
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                arcstr::literal!("incorrect_brawl_stars_tag"),
+            )
+            .message(arcstr::literal!("The Brawl Stars tag is incorrect")));
+        }
+
+        let config = &self.games_config.brawl_stars;
+        let response = self
+            .http_client
+            .get(format!("{}/players/%23{}", config.url, tag))
+            .bearer_auth(&config.token)
+            .send()
+            .await?;
+
+        if response.status() != StatusCode::OK {
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                arcstr::literal!("undefined_brawl_stars_player"),
+            )
+            .message(arcstr::literal!("Missing Brawl Stars player")));
+        }
+
+        let body: BrawlStarsPlayerInfoResponse = response.json().await?;
+
+        Ok(body)
+    }
+
+    pub async fn get_player_battlelog(
+        &self,
+        tag: &str,
+    ) -> AppResult<Vec<BrawlStarsBattlelogResponse>> {
+        if !brawl_stars_tag_validator(tag) {
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                arcstr::literal!("incorrect_brawl_stars_tag"),
+            )
+            .message(arcstr::literal!("The Brawl Stars tag is incorrect")));
+        }
+
+        let config = &self.games_config.brawl_stars;
+        let response = self
+            .http_client
+            .get(format!("{}/players/%23{}/battlelog", config.url, tag))
+            .bearer_auth(&config.token)
+            .send()
+            .await?;
+
+        if response.status() != StatusCode::OK {
+            return Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                arcstr::literal!("undefined_brawl_stars_player"),
+            )
+            .message(arcstr::literal!("Missing Brawl Stars player")));
+        }
+
+        // Unlike Clash Royale, the battles are wrapped in a paginated list.
+        let body: BrawlStarsBattlelogListResponse = response.json().await?;
+
+        Ok(body.items)
+    }
+}
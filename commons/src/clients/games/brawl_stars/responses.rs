@@ -0,0 +1,197 @@
+use arcstr::ArcStr;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::clients::games::deserialize_battle_time;
+use crate::database::traits::{DBNormalize, DBNormalizeResult};
+use crate::database::types::DateTime;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsPlayerInfoResponse {
+    #[serde(default)]
+    pub tag: Option<ArcStr>,
+
+    #[serde(default)]
+    pub name: Option<ArcStr>,
+
+    #[serde(default)]
+    pub exp_level: Option<u16>,
+
+    #[serde(default)]
+    pub trophies: Option<u64>,
+
+    #[serde(default)]
+    pub highest_trophies: Option<u64>,
+
+    #[serde(default)]
+    #[serde(rename = "3vs3Victories")]
+    pub three_vs_three_victories: Option<u64>,
+
+    #[serde(default)]
+    pub solo_victories: Option<u64>,
+
+    #[serde(default)]
+    pub duo_victories: Option<u64>,
+
+    #[serde(default)]
+    pub brawlers: Option<Vec<BrawlStarsBrawlerResponse>>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsBrawlerResponse {
+    pub id: u64,
+    // BrawlStarsBrawler
+    #[serde(default)]
+    pub power: Option<u16>,
+
+    #[serde(default)]
+    pub rank: Option<u16>,
+
+    #[serde(default)]
+    pub trophies: Option<u64>,
+
+    #[serde(default)]
+    pub highest_trophies: Option<u64>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsBattlelogListResponse {
+    #[serde(default)]
+    pub items: Vec<BrawlStarsBattlelogResponse>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsBattlelogResponse {
+    #[serde(deserialize_with = "deserialize_battle_time")]
+    pub battle_time: DateTime,
+
+    #[serde(default)]
+    pub event: Option<BrawlStarsEventResponse>,
+
+    pub battle: BrawlStarsBattleResponse,
+}
+
+impl DBNormalize for BrawlStarsBattlelogResponse {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsEventResponse {
+    #[serde(default)]
+    pub id: Option<u64>,
+
+    #[serde(default)]
+    pub mode: Option<ArcStr>,
+
+    #[serde(default)]
+    pub map: Option<ArcStr>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsBattleResponse {
+    #[serde(default)]
+    pub mode: Option<ArcStr>,
+
+    #[serde(default)]
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+
+    /// Only in team modes: victory, defeat or draw.
+    #[serde(default)]
+    pub result: Option<String>,
+
+    /// Only in showdown modes: the final position of the player.
+    #[serde(default)]
+    pub rank: Option<u8>,
+
+    #[serde(default)]
+    pub duration: Option<u64>,
+
+    #[serde(default)]
+    pub trophy_change: Option<i64>,
+
+    /// Only in team modes.
+    #[serde(default)]
+    pub teams: Option<Vec<Vec<BrawlStarsBattlelogPlayerResponse>>>,
+
+    /// Only in solo showdown.
+    #[serde(default)]
+    pub players: Option<Vec<BrawlStarsBattlelogPlayerResponse>>,
+}
+
+impl BrawlStarsBattleResponse {
+    // METHODS ----------------------------------------------------------------
+
+    /// Finds a player of the battle by its tag, with or without the leading '#'.
+    pub fn find_player(&self, tag: &str) -> Option<&BrawlStarsBattlelogPlayerResponse> {
+        let tag = tag.trim_start_matches('#');
+        let teams = self.teams.iter().flatten().flatten();
+        let players = self.players.iter().flatten();
+
+        teams.chain(players).find(|player| match &player.tag {
+            Some(v) => v.trim_start_matches('#') == tag,
+            None => false,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsBattlelogPlayerResponse {
+    #[serde(default)]
+    pub tag: Option<ArcStr>,
+
+    #[serde(default)]
+    pub name: Option<ArcStr>,
+
+    #[serde(default)]
+    pub brawler: Option<BrawlStarsBattlelogBrawlerResponse>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsBattlelogBrawlerResponse {
+    pub id: u64,
+    // BrawlStarsBrawler
+    #[serde(default)]
+    pub power: Option<u16>,
+
+    #[serde(default)]
+    pub trophies: Option<u64>,
+}
@@ -1,66 +1,235 @@
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use reqwest::header::RETRY_AFTER;
 use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
 
 pub use responses::*;
 
+use crate::clients::games::{retry_delay, ResponseCache, TokenBucket};
 use crate::config::GamesConfig;
+use crate::constants::{
+    GAME_API_BUCKET_CAPACITY, GAME_API_BUCKET_REFILL_PER_SECOND, GAME_API_CACHE_TTL,
+    GAME_API_MAX_RETRIES,
+};
 use crate::data::games::clash_royale_tag_validator;
-use crate::error::{AppError, AppResult};
+use crate::error::{
+    AppError, AppResult, EXTERNAL_THROTTLED_ERROR_CODE, EXTERNAL_UNAVAILABLE_ERROR_CODE,
+    INPUT_VALIDATION_UNDEFINED_CLASH_ROYALE_CLAN_ERROR_CODE,
+    INPUT_VALIDATION_UNDEFINED_CLASH_ROYALE_PLAYER_ERROR_CODE,
+    INPUT_VALIDATION_UNDEFINED_CLASH_ROYALE_TOURNAMENT_ERROR_CODE,
+};
 
 mod responses;
 
 pub struct ClashRoyaleClient {
     pub games_config: Arc<GamesConfig>,
     pub http_client: Arc<reqwest::Client>,
+    rate_limiter: TokenBucket,
+    player_info_cache: ResponseCache<ClashRoyalePlayerInfoResponse>,
+    battlelog_cache: ResponseCache<Vec<ClashRoyaleBattlelogResponse>>,
 }
 
 impl ClashRoyaleClient {
     // CONSTRUCTORS -----------------------------------------------------------
 
     pub fn new(games_config: Arc<GamesConfig>, http_client: Arc<reqwest::Client>) -> Self {
+        let cache_ttl = Duration::from_secs(GAME_API_CACHE_TTL);
+
         ClashRoyaleClient {
             games_config,
             http_client,
+            rate_limiter: TokenBucket::new(
+                GAME_API_BUCKET_CAPACITY,
+                GAME_API_BUCKET_REFILL_PER_SECOND,
+            ),
+            player_info_cache: ResponseCache::new(cache_ttl),
+            battlelog_cache: ResponseCache::new(cache_ttl),
         }
     }
 
     // METHODS ----------------------------------------------------------------
 
+    /// Gets the info of a player, reusing a recent response if any.
     pub async fn get_player_info(&self, tag: &str) -> AppResult<ClashRoyalePlayerInfoResponse> {
-        if !clash_royale_tag_validator(tag) {
-            return Err(AppError::new_with_status(
-                warp::http::StatusCode::BAD_REQUEST,
-                arcstr::literal!("incorrect_clash_royale_tag"),
-            )
-            .message(arcstr::literal!("The Clash Royale tag is incorrect")));
+        if let Some(body) = self.player_info_cache.get(tag) {
+            return Ok(body);
         }
 
-        let config = &self.games_config.clash_royale;
-        let response = self
-            .http_client
-            .get(format!("{}/players/%23{}", config.url, tag))
-            .bearer_auth(&config.token)
-            .send()
-            .await?;
-
-        if response.status() != StatusCode::OK {
-            return Err(AppError::new_with_status(
-                warp::http::StatusCode::BAD_REQUEST,
-                arcstr::literal!("undefined_clash_royale_player"),
-            )
-            .message(arcstr::literal!("Missing Clash Royale player")));
-        }
+        self.get_fresh_player_info(tag).await
+    }
 
-        let body: ClashRoyalePlayerInfoResponse = response.json().await?;
+    /// Gets the current info of a player, ignoring the cached responses.
+    pub async fn get_fresh_player_info(
+        &self,
+        tag: &str,
+    ) -> AppResult<ClashRoyalePlayerInfoResponse> {
+        Self::validate_tag(tag)?;
+
+        let body: ClashRoyalePlayerInfoResponse = self
+            .get(format!("players/%23{}", tag).as_str())
+            .await?
+            .ok_or_else(Self::undefined_player_error)?;
+        self.player_info_cache.insert(tag.to_string(), body.clone());
 
         Ok(body)
     }
 
+    /// Gets the battlelog of a player, reusing a recent response if any.
     pub async fn get_player_battlelog(
         &self,
         tag: &str,
     ) -> AppResult<Vec<ClashRoyaleBattlelogResponse>> {
+        if let Some(body) = self.battlelog_cache.get(tag) {
+            return Ok(body);
+        }
+
+        Self::validate_tag(tag)?;
+
+        let body: Vec<ClashRoyaleBattlelogResponse> = self
+            .get(format!("players/%23{}/battlelog", tag).as_str())
+            .await?
+            .ok_or_else(Self::undefined_player_error)?;
+        self.battlelog_cache.insert(tag.to_string(), body.clone());
+
+        Ok(body)
+    }
+
+    pub async fn get_clan_info(&self, tag: &str) -> AppResult<ClashRoyaleClanResponse> {
+        Self::validate_tag(tag)?;
+
+        self.get(format!("clans/%23{}", tag).as_str())
+            .await?
+            .ok_or_else(Self::undefined_clan_error)
+    }
+
+    pub async fn get_clan_members(
+        &self,
+        tag: &str,
+    ) -> AppResult<Vec<ClashRoyaleClanMemberResponse>> {
+        Self::validate_tag(tag)?;
+
+        let body: ClashRoyaleClanMemberListResponse = self
+            .get(format!("clans/%23{}/members", tag).as_str())
+            .await?
+            .ok_or_else(Self::undefined_clan_error)?;
+
+        Ok(body.items)
+    }
+
+    /// Gets the last finished wars, i.e. river races, of a clan.
+    pub async fn get_clan_war_log(
+        &self,
+        tag: &str,
+    ) -> AppResult<Vec<ClashRoyaleRiverRaceLogResponse>> {
+        Self::validate_tag(tag)?;
+
+        let body: ClashRoyaleRiverRaceLogListResponse = self
+            .get(format!("clans/%23{}/riverracelog", tag).as_str())
+            .await?
+            .ok_or_else(Self::undefined_clan_error)?;
+
+        Ok(body.items)
+    }
+
+    pub async fn get_tournament_info(&self, tag: &str) -> AppResult<ClashRoyaleTournamentResponse> {
+        Self::validate_tag(tag)?;
+
+        self.get(format!("tournaments/%23{}", tag).as_str())
+            .await?
+            .ok_or_else(|| {
+                AppError::new_with_status(
+                    warp::http::StatusCode::BAD_REQUEST,
+                    INPUT_VALIDATION_UNDEFINED_CLASH_ROYALE_TOURNAMENT_ERROR_CODE,
+                )
+                .message(arcstr::literal!("Missing Clash Royale tournament"))
+            })
+    }
+
+    /// Gets the list of all the cards of the game.
+    pub async fn get_cards(&self) -> AppResult<ClashRoyaleCardListResponse> {
+        self.get("cards").await?.ok_or_else(|| {
+            AppError::new_with_status(
+                warp::http::StatusCode::SERVICE_UNAVAILABLE,
+                EXTERNAL_UNAVAILABLE_ERROR_CODE,
+            )
+            .message(arcstr::literal!(
+                "The Clash Royale API is unavailable, please try again later"
+            ))
+        })
+    }
+
+    /// Sends a GET request to the API, retrying it with backoff when the API is
+    /// throttling us or temporarily unavailable. Returns `None` if the requested
+    /// element does not exist.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> AppResult<Option<T>> {
+        let config = &self.games_config.clash_royale;
+        let mut attempt = 0;
+
+        loop {
+            self.rate_limiter.acquire().await;
+
+            let response = self
+                .http_client
+                .get(format!("{}/{}", config.url, path))
+                .bearer_auth(&config.token)
+                .send()
+                .await?;
+
+            let status = response.status();
+            if status == StatusCode::OK {
+                let body: T = response.json().await?;
+                return Ok(Some(body));
+            }
+
+            if status == StatusCode::NOT_FOUND || status == StatusCode::BAD_REQUEST {
+                return Ok(None);
+            }
+
+            let throttled = status == StatusCode::TOO_MANY_REQUESTS;
+            if attempt >= GAME_API_MAX_RETRIES || !(throttled || status.is_server_error()) {
+                return Err(if throttled {
+                    AppError::new_with_status(
+                        warp::http::StatusCode::SERVICE_UNAVAILABLE,
+                        EXTERNAL_THROTTLED_ERROR_CODE,
+                    )
+                    .message(arcstr::literal!(
+                        "The Clash Royale API is throttling the requests, please try again later"
+                    ))
+                } else {
+                    AppError::new_with_status(
+                        warp::http::StatusCode::SERVICE_UNAVAILABLE,
+                        EXTERNAL_UNAVAILABLE_ERROR_CODE,
+                    )
+                    .message(arcstr::literal!(
+                        "The Clash Royale API is unavailable, please try again later"
+                    ))
+                });
+            }
+
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .map(Duration::from_secs);
+            let delay = retry_delay(attempt, retry_after);
+
+            if throttled {
+                // Stop every request of this client, not only this one.
+                self.rate_limiter.block_until(Instant::now() + delay);
+            } else {
+                tokio::time::sleep(delay).await;
+            }
+
+            attempt += 1;
+        }
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    fn validate_tag(tag: &str) -> AppResult<()> {
         if !clash_royale_tag_validator(tag) {
             return Err(AppError::new_with_status(
                 warp::http::StatusCode::BAD_REQUEST,
@@ -69,24 +238,22 @@ impl ClashRoyaleClient {
             .message(arcstr::literal!("The Clash Royale tag is incorrect")));
         }
 
-        let config = &self.games_config.clash_royale;
-        let response = self
-            .http_client
-            .get(format!("{}/players/%23{}/battlelog", config.url, tag))
-            .bearer_auth(&config.token)
-            .send()
-            .await?;
-
-        if response.status() != StatusCode::OK {
-            return Err(AppError::new_with_status(
-                warp::http::StatusCode::BAD_REQUEST,
-                arcstr::literal!("undefined_clash_royale_player"),
-            )
-            .message(arcstr::literal!("Missing Clash Royale player")));
-        }
+        Ok(())
+    }
 
-        let body: Vec<ClashRoyaleBattlelogResponse> = response.json().await?;
+    fn undefined_player_error() -> AppError {
+        AppError::new_with_status(
+            warp::http::StatusCode::BAD_REQUEST,
+            INPUT_VALIDATION_UNDEFINED_CLASH_ROYALE_PLAYER_ERROR_CODE,
+        )
+        .message(arcstr::literal!("Missing Clash Royale player"))
+    }
 
-        Ok(body)
+    fn undefined_clan_error() -> AppError {
+        AppError::new_with_status(
+            warp::http::StatusCode::BAD_REQUEST,
+            INPUT_VALIDATION_UNDEFINED_CLASH_ROYALE_CLAN_ERROR_CODE,
+        )
+        .message(arcstr::literal!("Missing Clash Royale clan"))
     }
 }
@@ -1,11 +1,17 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
 use arcstr::ArcStr;
-use chrono::{TimeZone, Utc};
+use serde::Deserialize;
 use serde::Serialize;
-use serde::{de, Deserialize, Deserializer};
 
+use crate::clients::games::{deserialize_battle_time, deserialize_optional_battle_time};
+use crate::data::games::ClashRoyaleCard;
+use crate::database::traits::{DBNormalize, DBNormalizeResult};
+use crate::database::types::game::ClashRoyaleBattleKind;
 use crate::database::types::DateTime;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyalePlayerInfoResponse {
     #[serde(default)]
@@ -75,11 +81,30 @@ pub struct ClashRoyalePlayerInfoResponse {
     pub current_favourite_card: Option<ClashRoyaleCardResponse>,
 }
 
+impl DBNormalize for ClashRoyalePlayerInfoResponse {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyalePlayerClanResponse {
+    pub tag: ArcStr,
+
+    #[serde(default)]
+    pub name: Option<ArcStr>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleArenaResponse {
     pub id: u64, // ClashRoyaleArena
@@ -89,7 +114,7 @@ pub struct ClashRoyaleArenaResponse {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleCardResponse {
     pub id: u64,
@@ -102,19 +127,22 @@ pub struct ClashRoyaleCardResponse {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleBattlelogResponse {
     #[serde(default)]
     #[serde(rename = "type")]
     pub kind: Option<String>,
 
-    #[serde(deserialize_with = "deserialize_clash_royale_battle_time")]
+    #[serde(deserialize_with = "deserialize_battle_time")]
     pub battle_time: DateTime,
 
     #[serde(default)]
     pub arena: Option<ClashRoyaleArenaResponse>,
 
+    #[serde(default)]
+    pub game_mode: Option<ClashRoyaleBattleGameModeResponse>,
+
     #[serde(default)]
     pub team: Option<Vec<ClashRoyaleBattlelogPlayerResponse>>,
 
@@ -122,11 +150,44 @@ pub struct ClashRoyaleBattlelogResponse {
     pub opponent: Option<Vec<ClashRoyaleBattlelogPlayerResponse>>,
 }
 
+impl ClashRoyaleBattlelogResponse {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn battle_kind(&self) -> ClashRoyaleBattleKind {
+        match &self.kind {
+            Some(kind) => ClashRoyaleBattleKind::parse(
+                kind,
+                self.game_mode.as_ref().and_then(|v| v.name.as_deref()),
+            ),
+            None => ClashRoyaleBattleKind::Unknown,
+        }
+    }
+}
+
+impl DBNormalize for ClashRoyaleBattlelogResponse {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleBattleGameModeResponse {
+    pub id: u64,
+
+    #[serde(default)]
+    pub name: Option<ArcStr>,
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleBattlelogPlayerResponse {
     pub tag: Option<ArcStr>,
@@ -139,49 +200,411 @@ pub struct ClashRoyaleBattlelogPlayerResponse {
 
     #[serde(default)]
     pub cards: Option<Vec<ClashRoyaleCardResponse>>,
+
+    /// Missing when the tower is destroyed.
+    #[serde(default)]
+    pub king_tower_hit_points: Option<u32>,
+
+    /// Only contains the towers that are not destroyed.
+    #[serde(default)]
+    pub princess_towers_hit_points: Option<Vec<u32>>,
+
+    /// Missing when the player is not in a clan.
+    #[serde(default)]
+    pub clan: Option<ClashRoyalePlayerClanResponse>,
 }
 
-// ----------------------------------------------------------------------------
-// Auxiliary methods ----------------------------------------------------------
-// ----------------------------------------------------------------------------
+impl ClashRoyaleBattlelogPlayerResponse {
+    // GETTERS ----------------------------------------------------------------
 
-fn deserialize_clash_royale_battle_time<'de, D>(deserializer: D) -> Result<DateTime, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    struct DateTimeVisitor;
+    /// The remaining hit points of the king tower, 0 if destroyed.
+    pub fn remaining_king_tower_hit_points(&self) -> u32 {
+        self.king_tower_hit_points.unwrap_or(0)
+    }
 
-    impl<'de> de::Visitor<'de> for DateTimeVisitor {
-        type Value = DateTime;
+    /// The remaining hit points of the princess tower with the most of them, 0 only if
+    /// both towers are destroyed.
+    pub fn max_princess_tower_hit_points(&self) -> u32 {
+        self.princess_towers_hit_points
+            .as_ref()
+            .and_then(|v| v.iter().max().copied())
+            .unwrap_or(0)
+    }
 
-        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-            formatter.write_str("a string containing json data")
+    /// The remaining hit points of the princess tower with the fewest of them, 0 if any
+    /// tower is destroyed.
+    ///
+    /// The API does not tell which tower is destroyed, so the towers are compared by
+    /// their hit points instead of by their side.
+    pub fn min_princess_tower_hit_points(&self) -> u32 {
+        match &self.princess_towers_hit_points {
+            Some(v) if v.len() >= 2 => v.iter().min().copied().unwrap_or(0),
+            _ => 0,
         }
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
 
-        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            // 20211014T123745.000Z
-            if v.len() != 20 {
-                return Err(E::custom("incorrect string"));
-            }
-
-            let year = (&v[0..4]).parse::<i32>().map_err(E::custom)?;
-            let month = (&v[4..6]).parse::<u32>().map_err(E::custom)?;
-            let day = (&v[6..8]).parse::<u32>().map_err(E::custom)?;
-
-            let hour = (&v[9..11]).parse::<u32>().map_err(E::custom)?;
-            let minute = (&v[11..13]).parse::<u32>().map_err(E::custom)?;
-            let second = (&v[13..15]).parse::<u32>().map_err(E::custom)?;
-            let millis = (&v[16..19]).parse::<u32>().map_err(E::custom)?;
-
-            Ok(DateTime::new(
-                Utc.ymd(year, month, day)
-                    .and_hms_milli(hour, minute, second, millis),
-            ))
+    /// The sum of the starting trophies of the players, missing if there are no players
+    /// or the trophies of any of them are unknown.
+    pub fn total_starting_trophies(players: &[Self]) -> Option<u64> {
+        if players.is_empty() {
+            return None;
         }
+
+        players.iter().map(|v| v.starting_trophies).sum()
     }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleClanResponse {
+    pub tag: ArcStr,
+
+    #[serde(default)]
+    pub name: Option<ArcStr>,
+
+    /// Either open, inviteOnly or closed.
+    #[serde(default)]
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+
+    #[serde(default)]
+    pub description: Option<ArcStr>,
+
+    #[serde(default)]
+    pub clan_score: Option<u64>,
+
+    #[serde(default)]
+    pub clan_war_trophies: Option<u64>,
 
-    deserializer.deserialize_string(DateTimeVisitor)
+    #[serde(default)]
+    pub required_trophies: Option<u64>,
+
+    #[serde(default)]
+    pub donations_per_week: Option<u64>,
+
+    /// The number of members.
+    #[serde(default)]
+    pub members: Option<u8>,
+
+    #[serde(default)]
+    pub member_list: Option<Vec<ClashRoyaleClanMemberResponse>>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleClanMemberListResponse {
+    #[serde(default)]
+    pub items: Vec<ClashRoyaleClanMemberResponse>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleClanMemberResponse {
+    pub tag: ArcStr,
+
+    #[serde(default)]
+    pub name: Option<ArcStr>,
+
+    /// Either member, elder, coLeader or leader.
+    #[serde(default)]
+    pub role: Option<String>,
+
+    #[serde(default)]
+    pub exp_level: Option<u16>,
+
+    #[serde(default)]
+    pub trophies: Option<u64>,
+
+    #[serde(default)]
+    pub arena: Option<ClashRoyaleArenaResponse>,
+
+    #[serde(default)]
+    pub clan_rank: Option<u8>,
+
+    #[serde(default)]
+    pub donations: Option<u64>,
+
+    #[serde(default)]
+    pub donations_received: Option<u64>,
+
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_optional_battle_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<DateTime>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleRiverRaceLogListResponse {
+    #[serde(default)]
+    pub items: Vec<ClashRoyaleRiverRaceLogResponse>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A finished clan war, i.e. a river race.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleRiverRaceLogResponse {
+    #[serde(default)]
+    pub season_id: Option<u64>,
+
+    #[serde(default)]
+    pub section_index: Option<u64>,
+
+    #[serde(deserialize_with = "deserialize_battle_time")]
+    pub created_date: DateTime,
+
+    #[serde(default)]
+    pub standings: Vec<ClashRoyaleRiverRaceStandingResponse>,
+}
+
+impl ClashRoyaleRiverRaceLogResponse {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The standing of a clan in the race.
+    pub fn clan_standing(&self, clan_tag: &str) -> Option<&ClashRoyaleRiverRaceStandingResponse> {
+        self.standings
+            .iter()
+            .find(|v| v.clan.tag.as_str() == clan_tag)
+    }
+
+    /// The participation of a player in the race, in any clan.
+    pub fn participant(&self, tag: &str) -> Option<&ClashRoyaleRiverRaceParticipantResponse> {
+        self.standings
+            .iter()
+            .flat_map(|v| v.clan.participants.iter())
+            .find(|v| v.tag.as_str() == tag)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleRiverRaceStandingResponse {
+    /// The final position of the clan, starting at 1.
+    pub rank: u8,
+
+    #[serde(default)]
+    pub trophy_change: Option<i64>,
+
+    pub clan: ClashRoyaleRiverRaceClanResponse,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleRiverRaceClanResponse {
+    pub tag: ArcStr,
+
+    #[serde(default)]
+    pub name: Option<ArcStr>,
+
+    #[serde(default)]
+    pub fame: Option<u64>,
+
+    #[serde(default)]
+    pub repair_points: Option<u64>,
+
+    /// Missing when the clan has not finished the race.
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_optional_battle_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finish_time: Option<DateTime>,
+
+    #[serde(default)]
+    pub participants: Vec<ClashRoyaleRiverRaceParticipantResponse>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleRiverRaceParticipantResponse {
+    pub tag: ArcStr,
+
+    #[serde(default)]
+    pub name: Option<ArcStr>,
+
+    #[serde(default)]
+    pub fame: Option<u64>,
+
+    #[serde(default)]
+    pub repair_points: Option<u64>,
+
+    #[serde(default)]
+    pub boat_attacks: Option<u64>,
+
+    #[serde(default)]
+    pub decks_used: Option<u64>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleTournamentResponse {
+    pub tag: ArcStr,
+
+    #[serde(default)]
+    pub name: Option<ArcStr>,
+
+    /// Either open or passwordProtected.
+    #[serde(default)]
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+
+    /// Either inPreparation, inProgress or ended.
+    #[serde(default)]
+    pub status: Option<String>,
+
+    #[serde(default)]
+    pub creator_tag: Option<ArcStr>,
+
+    #[serde(default)]
+    pub capacity: Option<u64>,
+
+    #[serde(default)]
+    pub max_capacity: Option<u64>,
+
+    #[serde(default)]
+    pub level_cap: Option<u16>,
+
+    #[serde(default)]
+    pub game_mode: Option<ClashRoyaleBattleGameModeResponse>,
+
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_optional_battle_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<DateTime>,
+
+    /// Missing while in preparation.
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_optional_battle_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_time: Option<DateTime>,
+
+    /// Missing until the tournament ends.
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_optional_battle_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ended_time: Option<DateTime>,
+
+    #[serde(default)]
+    pub members_list: Vec<ClashRoyaleTournamentMemberResponse>,
+}
+
+impl ClashRoyaleTournamentResponse {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn has_ended(&self) -> bool {
+        self.status.as_deref() == Some("ended")
+    }
+
+    pub fn member(&self, tag: &str) -> Option<&ClashRoyaleTournamentMemberResponse> {
+        self.members_list.iter().find(|v| v.tag.as_str() == tag)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleTournamentMemberResponse {
+    pub tag: ArcStr,
+
+    #[serde(default)]
+    pub name: Option<ArcStr>,
+
+    #[serde(default)]
+    pub score: Option<u64>,
+
+    /// The position in the tournament, starting at 1.
+    #[serde(default)]
+    pub rank: Option<u64>,
+
+    #[serde(default)]
+    pub clan: Option<ClashRoyalePlayerClanResponse>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleCardListResponse {
+    #[serde(default)]
+    pub items: Vec<ClashRoyaleCardInfoResponse>,
+}
+
+impl ClashRoyaleCardListResponse {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The cards of the game that are missing in `ClashRoyaleCard`.
+    pub fn unknown_cards(&self) -> Vec<&ClashRoyaleCardInfoResponse> {
+        self.items
+            .iter()
+            .filter(|v| ClashRoyaleCard::try_from(v.id).is_err())
+            .collect()
+    }
+
+    /// The cards of `ClashRoyaleCard` that are no longer in the game.
+    pub fn removed_cards(&self) -> Vec<ClashRoyaleCard> {
+        let ids: HashSet<u64> = self.items.iter().map(|v| v.id).collect();
+
+        ClashRoyaleCard::enum_list()
+            .iter()
+            .copied()
+            .filter(|v| !ids.contains(&(*v as u64)))
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleCardInfoResponse {
+    pub id: u64,
+
+    #[serde(default)]
+    pub name: Option<ArcStr>,
+
+    #[serde(default)]
+    pub max_level: Option<u16>,
 }
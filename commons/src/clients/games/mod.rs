@@ -1,13 +1,20 @@
 use std::sync::Arc;
 
+pub use brawl_stars::*;
 pub use clash_royale::*;
+pub use throttling::*;
+pub(crate) use utils::*;
 
 use crate::config::InitServiceConfig;
 
+mod brawl_stars;
 mod clash_royale;
+mod throttling;
+mod utils;
 
 pub struct GameClients {
     pub clash_royale: Arc<ClashRoyaleClient>,
+    pub brawl_stars: Arc<BrawlStarsClient>,
 }
 
 impl GameClients {
@@ -16,7 +23,11 @@ impl GameClients {
     pub fn new(config: &Arc<InitServiceConfig>, http_client: &Arc<reqwest::Client>) -> Self {
         let games_config = config.game_apis.clone().unwrap();
         GameClients {
-            clash_royale: Arc::new(ClashRoyaleClient::new(games_config, http_client.clone())),
+            clash_royale: Arc::new(ClashRoyaleClient::new(
+                games_config.clone(),
+                http_client.clone(),
+            )),
+            brawl_stars: Arc::new(BrawlStarsClient::new(games_config, http_client.clone())),
         }
     }
 }
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::constants::{GAME_API_MAX_RETRY_DELAY, GAME_API_RETRY_BASE_DELAY};
+
+/// A client-side token bucket to keep the requests sent to a game API under its quota.
+pub struct TokenBucket {
+    capacity: f64,
+    refill_per_second: f64,
+    state: Mutex<TokenBucketState>,
+}
+
+struct TokenBucketState {
+    tokens: f64,
+    last_refill: Instant,
+    blocked_until: Option<Instant>,
+}
+
+impl TokenBucket {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(capacity: u32, refill_per_second: u32) -> Self {
+        TokenBucket {
+            capacity: capacity as f64,
+            refill_per_second: refill_per_second as f64,
+            state: Mutex::new(TokenBucketState {
+                tokens: capacity as f64,
+                last_refill: Instant::now(),
+                blocked_until: None,
+            }),
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Waits until a token is available and takes it.
+    pub async fn acquire(&self) {
+        while let Err(delay) = self.try_acquire_at(Instant::now()) {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Takes a token at `now` or returns how long to wait for the next one.
+    pub fn try_acquire_at(&self, now: Instant) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(blocked_until) = state.blocked_until {
+            if now < blocked_until {
+                return Err(blocked_until - now);
+            }
+
+            state.blocked_until = None;
+        }
+
+        let elapsed = now.saturating_duration_since(state.last_refill);
+        state.tokens =
+            (state.tokens + elapsed.as_secs_f64() * self.refill_per_second).min(self.capacity);
+        state.last_refill = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            Ok(())
+        } else {
+            let missing = 1.0 - state.tokens;
+            Err(Duration::from_secs_f64(missing / self.refill_per_second))
+        }
+    }
+
+    /// Stops giving tokens until `until`, e.g. when the API has answered with a
+    /// rate-limit response.
+    pub fn block_until(&self, until: Instant) {
+        let mut state = self.state.lock().unwrap();
+
+        state.blocked_until = Some(match state.blocked_until {
+            Some(blocked_until) => blocked_until.max(until),
+            None => until,
+        });
+        state.tokens = 0.0;
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A cache of the responses of a game API endpoint that keeps them during a short time.
+pub struct ResponseCache<T: Clone> {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, T)>>,
+}
+
+impl<T: Clone> ResponseCache<T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(ttl: Duration) -> Self {
+        ResponseCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn get(&self, key: &str) -> Option<T> {
+        self.get_at(key, Instant::now())
+    }
+
+    pub fn get_at(&self, key: &str, now: Instant) -> Option<T> {
+        let entries = self.entries.lock().unwrap();
+        let (expires_at, value) = entries.get(key)?;
+
+        if now < *expires_at {
+            Some(value.clone())
+        } else {
+            None
+        }
+    }
+
+    pub fn insert(&self, key: String, value: T) {
+        self.insert_at(key, value, Instant::now())
+    }
+
+    pub fn insert_at(&self, key: String, value: T, now: Instant) {
+        let mut entries = self.entries.lock().unwrap();
+
+        // Remove expired entries to keep the cache small.
+        entries.retain(|_, (expires_at, _)| now < *expires_at);
+        entries.insert(key, (now + self.ttl, value));
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The delay before retrying a request, honoring the one requested by the API if any,
+/// or growing exponentially otherwise.
+pub fn retry_delay(attempt: u32, retry_after: Option<Duration>) -> Duration {
+    let max_delay = Duration::from_secs(GAME_API_MAX_RETRY_DELAY);
+
+    match retry_after {
+        Some(delay) => delay.min(max_delay),
+        None => {
+            let delay = Duration::from_millis(GAME_API_RETRY_BASE_DELAY)
+                .saturating_mul(2u32.saturating_pow(attempt));
+            delay.min(max_delay)
+        }
+    }
+}
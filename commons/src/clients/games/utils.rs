@@ -0,0 +1,76 @@
+use chrono::{TimeZone, Utc};
+use serde::{de, Deserialize, Deserializer};
+
+use crate::database::types::DateTime;
+
+/// Deserializes the battle times of the Supercell APIs, e.g. `20211014T123745.000Z`,
+/// or the timestamps in milliseconds they are stored as in the DB.
+pub(crate) fn deserialize_battle_time<'de, D>(deserializer: D) -> Result<DateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct DateTimeVisitor;
+
+    impl<'de> de::Visitor<'de> for DateTimeVisitor {
+        type Value = DateTime;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a string containing json data")
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(DateTime::new(Utc.timestamp_millis(value)))
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(DateTime::new(Utc.timestamp_millis(value as i64)))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            // 20211014T123745.000Z
+            if v.len() != 20 {
+                return Err(E::custom("incorrect string"));
+            }
+
+            let year = (&v[0..4]).parse::<i32>().map_err(E::custom)?;
+            let month = (&v[4..6]).parse::<u32>().map_err(E::custom)?;
+            let day = (&v[6..8]).parse::<u32>().map_err(E::custom)?;
+
+            let hour = (&v[9..11]).parse::<u32>().map_err(E::custom)?;
+            let minute = (&v[11..13]).parse::<u32>().map_err(E::custom)?;
+            let second = (&v[13..15]).parse::<u32>().map_err(E::custom)?;
+            let millis = (&v[16..19]).parse::<u32>().map_err(E::custom)?;
+
+            Ok(DateTime::new(
+                Utc.ymd(year, month, day)
+                    .and_hms_milli(hour, minute, second, millis),
+            ))
+        }
+    }
+
+    deserializer.deserialize_any(DateTimeVisitor)
+}
+
+/// Like `deserialize_battle_time` for optional fields, e.g. the end of a tournament.
+/// An explicit `null` is treated as a missing value.
+pub(crate) fn deserialize_optional_battle_time<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct BattleTime(#[serde(deserialize_with = "deserialize_battle_time")] DateTime);
+
+    let value = Option::<BattleTime>::deserialize(deserializer)?;
+    Ok(value.map(|v| v.0))
+}
@@ -6,6 +6,7 @@ use serde::Serialize;
 #[serde(rename_all = "camelCase")]
 pub struct GamesConfig {
     pub clash_royale: GameAPIConfig,
+    pub brawl_stars: GameAPIConfig,
 }
 
 // ----------------------------------------------------------------------------
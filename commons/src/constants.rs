@@ -25,6 +25,9 @@ pub static BET_PARTICIPANT_INDEX: &str = "ParticipantAddress";
 pub static BET_CHALLENGE_INDEX: &str = "ChallengeAddress";
 pub static EMAIL_INDEX: &str = "Email";
 pub static AUTHORIZATION_ADDRESS_INDEX: &str = "AuthorizationAddress";
+pub static BATTLELOG_PLAYER_INDEX: &str = "BattlelogPlayer";
+pub static PARTICIPANT_CLASH_ROYALE_TAG_INDEX: &str = "ParticipantClashRoyaleTag";
+pub static PARTICIPANT_BRAWL_STARS_TAG_INDEX: &str = "ParticipantBrawlStarsTag";
 pub static MAX_AQL_RETRIES: usize = 100;
 
 // 1 minute in seconds
@@ -52,6 +55,21 @@ pub static MAX_REWARD_MULTIPLIER: u64 = 1_000_000;
 // 5 minutes in seconds
 pub const SIGNATURE_EXPIRATION_DELAY: u64 = 300;
 
+// BATTLELOGS -----------------------------------------------------------------
+// 30 days in seconds
+pub const BATTLELOG_EXPIRATION_DELAY: u64 = 30 * 24 * 60 * 60;
+
 // AUTHORIZATIONS -------------------------------------------------------------
 // 1 hour in seconds
 pub const AUTHORIZATION_EXPIRATION_DELAY: u64 = 60 * 60;
+
+// GAME APIS ------------------------------------------------------------------
+// 30 seconds
+pub const GAME_API_CACHE_TTL: u64 = 30;
+pub const GAME_API_BUCKET_CAPACITY: u32 = 20;
+pub const GAME_API_BUCKET_REFILL_PER_SECOND: u32 = 10;
+pub const GAME_API_MAX_RETRIES: u32 = 3;
+// 250 ms
+pub const GAME_API_RETRY_BASE_DELAY: u64 = 250;
+// 10 seconds
+pub const GAME_API_MAX_RETRY_DELAY: u64 = 10;
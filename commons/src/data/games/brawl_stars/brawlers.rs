@@ -0,0 +1,200 @@
+//! Info: https://developer.brawlstars.com/#/documentation
+
+use std::convert::TryFrom;
+
+use schemars::JsonSchema_repr;
+use serde_repr::Deserialize_repr;
+use serde_repr::Serialize_repr;
+
+use enum_derive::EnumList;
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize_repr,
+    Deserialize_repr,
+    JsonSchema_repr,
+    EnumList,
+)]
+#[repr(u64)]
+pub enum BrawlStarsBrawler {
+    Shelly = 16000000,
+    Colt = 16000001,
+    Bull = 16000002,
+    Brock = 16000003,
+    Rico = 16000004,
+    Spike = 16000005,
+    Barley = 16000006,
+    Jessie = 16000007,
+    Nita = 16000008,
+    Dynamike = 16000009,
+    ElPrimo = 16000010,
+    Mortis = 16000011,
+    Crow = 16000012,
+    Poco = 16000013,
+    Bo = 16000014,
+    Piper = 16000015,
+    Pam = 16000016,
+    Tara = 16000017,
+    Darryl = 16000018,
+    Penny = 16000019,
+    Frank = 16000020,
+    Gene = 16000021,
+    Tick = 16000022,
+    Leon = 16000023,
+    Rosa = 16000024,
+    Carl = 16000025,
+    Bibi = 16000026,
+    EightBit = 16000027,
+    Sandy = 16000028,
+    Bea = 16000029,
+    Emz = 16000030,
+    MrP = 16000031,
+    Max = 16000032,
+    Jacky = 16000034,
+    Gale = 16000035,
+    Nani = 16000036,
+    Sprout = 16000037,
+    Surge = 16000038,
+    Colette = 16000039,
+    Amber = 16000040,
+    Lou = 16000041,
+    Byron = 16000042,
+    Edgar = 16000043,
+    Ruffs = 16000044,
+    Stu = 16000045,
+    Belle = 16000046,
+    Squeak = 16000047,
+    Grom = 16000048,
+    Buzz = 16000049,
+    Griff = 16000050,
+    Ash = 16000051,
+    Meg = 16000052,
+}
+
+impl BrawlStarsBrawler {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BrawlStarsBrawler::Shelly => "Shelly",
+            BrawlStarsBrawler::Colt => "Colt",
+            BrawlStarsBrawler::Bull => "Bull",
+            BrawlStarsBrawler::Brock => "Brock",
+            BrawlStarsBrawler::Rico => "Rico",
+            BrawlStarsBrawler::Spike => "Spike",
+            BrawlStarsBrawler::Barley => "Barley",
+            BrawlStarsBrawler::Jessie => "Jessie",
+            BrawlStarsBrawler::Nita => "Nita",
+            BrawlStarsBrawler::Dynamike => "Dynamike",
+            BrawlStarsBrawler::ElPrimo => "El Primo",
+            BrawlStarsBrawler::Mortis => "Mortis",
+            BrawlStarsBrawler::Crow => "Crow",
+            BrawlStarsBrawler::Poco => "Poco",
+            BrawlStarsBrawler::Bo => "Bo",
+            BrawlStarsBrawler::Piper => "Piper",
+            BrawlStarsBrawler::Pam => "Pam",
+            BrawlStarsBrawler::Tara => "Tara",
+            BrawlStarsBrawler::Darryl => "Darryl",
+            BrawlStarsBrawler::Penny => "Penny",
+            BrawlStarsBrawler::Frank => "Frank",
+            BrawlStarsBrawler::Gene => "Gene",
+            BrawlStarsBrawler::Tick => "Tick",
+            BrawlStarsBrawler::Leon => "Leon",
+            BrawlStarsBrawler::Rosa => "Rosa",
+            BrawlStarsBrawler::Carl => "Carl",
+            BrawlStarsBrawler::Bibi => "Bibi",
+            BrawlStarsBrawler::EightBit => "8-Bit",
+            BrawlStarsBrawler::Sandy => "Sandy",
+            BrawlStarsBrawler::Bea => "Bea",
+            BrawlStarsBrawler::Emz => "Emz",
+            BrawlStarsBrawler::MrP => "Mr. P",
+            BrawlStarsBrawler::Max => "Max",
+            BrawlStarsBrawler::Jacky => "Jacky",
+            BrawlStarsBrawler::Gale => "Gale",
+            BrawlStarsBrawler::Nani => "Nani",
+            BrawlStarsBrawler::Sprout => "Sprout",
+            BrawlStarsBrawler::Surge => "Surge",
+            BrawlStarsBrawler::Colette => "Colette",
+            BrawlStarsBrawler::Amber => "Amber",
+            BrawlStarsBrawler::Lou => "Lou",
+            BrawlStarsBrawler::Byron => "Byron",
+            BrawlStarsBrawler::Edgar => "Edgar",
+            BrawlStarsBrawler::Ruffs => "Colonel Ruffs",
+            BrawlStarsBrawler::Stu => "Stu",
+            BrawlStarsBrawler::Belle => "Belle",
+            BrawlStarsBrawler::Squeak => "Squeak",
+            BrawlStarsBrawler::Grom => "Grom",
+            BrawlStarsBrawler::Buzz => "Buzz",
+            BrawlStarsBrawler::Griff => "Griff",
+            BrawlStarsBrawler::Ash => "Ash",
+            BrawlStarsBrawler::Meg => "Meg",
+        }
+    }
+}
+
+impl TryFrom<u64> for BrawlStarsBrawler {
+    type Error = ();
+
+    fn try_from(v: u64) -> Result<Self, Self::Error> {
+        match v {
+            16000000 => Ok(BrawlStarsBrawler::Shelly),
+            16000001 => Ok(BrawlStarsBrawler::Colt),
+            16000002 => Ok(BrawlStarsBrawler::Bull),
+            16000003 => Ok(BrawlStarsBrawler::Brock),
+            16000004 => Ok(BrawlStarsBrawler::Rico),
+            16000005 => Ok(BrawlStarsBrawler::Spike),
+            16000006 => Ok(BrawlStarsBrawler::Barley),
+            16000007 => Ok(BrawlStarsBrawler::Jessie),
+            16000008 => Ok(BrawlStarsBrawler::Nita),
+            16000009 => Ok(BrawlStarsBrawler::Dynamike),
+            16000010 => Ok(BrawlStarsBrawler::ElPrimo),
+            16000011 => Ok(BrawlStarsBrawler::Mortis),
+            16000012 => Ok(BrawlStarsBrawler::Crow),
+            16000013 => Ok(BrawlStarsBrawler::Poco),
+            16000014 => Ok(BrawlStarsBrawler::Bo),
+            16000015 => Ok(BrawlStarsBrawler::Piper),
+            16000016 => Ok(BrawlStarsBrawler::Pam),
+            16000017 => Ok(BrawlStarsBrawler::Tara),
+            16000018 => Ok(BrawlStarsBrawler::Darryl),
+            16000019 => Ok(BrawlStarsBrawler::Penny),
+            16000020 => Ok(BrawlStarsBrawler::Frank),
+            16000021 => Ok(BrawlStarsBrawler::Gene),
+            16000022 => Ok(BrawlStarsBrawler::Tick),
+            16000023 => Ok(BrawlStarsBrawler::Leon),
+            16000024 => Ok(BrawlStarsBrawler::Rosa),
+            16000025 => Ok(BrawlStarsBrawler::Carl),
+            16000026 => Ok(BrawlStarsBrawler::Bibi),
+            16000027 => Ok(BrawlStarsBrawler::EightBit),
+            16000028 => Ok(BrawlStarsBrawler::Sandy),
+            16000029 => Ok(BrawlStarsBrawler::Bea),
+            16000030 => Ok(BrawlStarsBrawler::Emz),
+            16000031 => Ok(BrawlStarsBrawler::MrP),
+            16000032 => Ok(BrawlStarsBrawler::Max),
+            16000034 => Ok(BrawlStarsBrawler::Jacky),
+            16000035 => Ok(BrawlStarsBrawler::Gale),
+            16000036 => Ok(BrawlStarsBrawler::Nani),
+            16000037 => Ok(BrawlStarsBrawler::Sprout),
+            16000038 => Ok(BrawlStarsBrawler::Surge),
+            16000039 => Ok(BrawlStarsBrawler::Colette),
+            16000040 => Ok(BrawlStarsBrawler::Amber),
+            16000041 => Ok(BrawlStarsBrawler::Lou),
+            16000042 => Ok(BrawlStarsBrawler::Byron),
+            16000043 => Ok(BrawlStarsBrawler::Edgar),
+            16000044 => Ok(BrawlStarsBrawler::Ruffs),
+            16000045 => Ok(BrawlStarsBrawler::Stu),
+            16000046 => Ok(BrawlStarsBrawler::Belle),
+            16000047 => Ok(BrawlStarsBrawler::Squeak),
+            16000048 => Ok(BrawlStarsBrawler::Grom),
+            16000049 => Ok(BrawlStarsBrawler::Buzz),
+            16000050 => Ok(BrawlStarsBrawler::Griff),
+            16000051 => Ok(BrawlStarsBrawler::Ash),
+            16000052 => Ok(BrawlStarsBrawler::Meg),
+            _ => Err(()),
+        }
+    }
+}
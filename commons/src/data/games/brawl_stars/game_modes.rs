@@ -0,0 +1,73 @@
+//! Info: https://developer.brawlstars.com/#/documentation
+
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use enum_derive::EnumList;
+
+/// The names match those used by the API.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, EnumList)]
+#[serde(rename_all = "camelCase")]
+pub enum BrawlStarsGameMode {
+    GemGrab,
+    BrawlBall,
+    Heist,
+    Bounty,
+    Siege,
+    HotZone,
+    Knockout,
+    SoloShowdown,
+    DuoShowdown,
+    BigGame,
+    RoboRumble,
+    BossFight,
+    Duels,
+}
+
+impl BrawlStarsGameMode {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BrawlStarsGameMode::GemGrab => "Gem Grab",
+            BrawlStarsGameMode::BrawlBall => "Brawl Ball",
+            BrawlStarsGameMode::Heist => "Heist",
+            BrawlStarsGameMode::Bounty => "Bounty",
+            BrawlStarsGameMode::Siege => "Siege",
+            BrawlStarsGameMode::HotZone => "Hot Zone",
+            BrawlStarsGameMode::Knockout => "Knockout",
+            BrawlStarsGameMode::SoloShowdown => "Solo Showdown",
+            BrawlStarsGameMode::DuoShowdown => "Duo Showdown",
+            BrawlStarsGameMode::BigGame => "Big Game",
+            BrawlStarsGameMode::RoboRumble => "Robo Rumble",
+            BrawlStarsGameMode::BossFight => "Boss Fight",
+            BrawlStarsGameMode::Duels => "Duels",
+        }
+    }
+}
+
+impl TryFrom<&str> for BrawlStarsGameMode {
+    type Error = ();
+
+    fn try_from(v: &str) -> Result<Self, Self::Error> {
+        match v {
+            "gemGrab" => Ok(BrawlStarsGameMode::GemGrab),
+            "brawlBall" => Ok(BrawlStarsGameMode::BrawlBall),
+            "heist" => Ok(BrawlStarsGameMode::Heist),
+            "bounty" => Ok(BrawlStarsGameMode::Bounty),
+            "siege" => Ok(BrawlStarsGameMode::Siege),
+            "hotZone" => Ok(BrawlStarsGameMode::HotZone),
+            "knockout" => Ok(BrawlStarsGameMode::Knockout),
+            "soloShowdown" => Ok(BrawlStarsGameMode::SoloShowdown),
+            "duoShowdown" => Ok(BrawlStarsGameMode::DuoShowdown),
+            "bigGame" => Ok(BrawlStarsGameMode::BigGame),
+            "roboRumble" => Ok(BrawlStarsGameMode::RoboRumble),
+            "bossFight" => Ok(BrawlStarsGameMode::BossFight),
+            "duels" => Ok(BrawlStarsGameMode::Duels),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+pub use brawlers::*;
+pub use game_modes::*;
+
+use crate::server::validators::length_validator;
+
+mod brawlers;
+mod game_modes;
+
+pub fn brawl_stars_tag_validator(tag: &str) -> bool {
+    if length_validator(tag.len(), 3, 15, "").is_err() {
+        return false;
+    }
+
+    for char in tag.chars() {
+        if !matches!(char, '0'..='9' | 'A'..='Z') {
+            return false;
+        }
+    }
+
+    true
+}
@@ -1,12 +1,30 @@
 //! Info: https://github.com/RoyaleAPI/cr-api-data/tree/master/docs/json
 //! Info: https://github.com/RoyaleAPI/cr-api-assets
 
+use std::convert::TryFrom;
+
+use schemars::JsonSchema_repr;
 use serde_repr::Deserialize_repr;
 use serde_repr::Serialize_repr;
 
 use enum_derive::EnumList;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize_repr, Deserialize_repr, EnumList)]
+use crate::data::games::{
+    ClashRoyaleCardInfo, ClashRoyaleCardRarity, ClashRoyaleCardType, ClashRoyaleCatalog,
+};
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize_repr,
+    Deserialize_repr,
+    JsonSchema_repr,
+    EnumList,
+)]
 #[repr(u64)]
 pub enum ClashRoyaleCard {
     Knight = 26000000,
@@ -117,327 +135,50 @@ pub enum ClashRoyaleCard {
 impl ClashRoyaleCard {
     // GETTERS ----------------------------------------------------------------
 
+    /// The info of the card in the catalog.
+    pub fn info(&self) -> &'static ClashRoyaleCardInfo {
+        ClashRoyaleCatalog::instance()
+            .card(*self as u64)
+            .expect("Missing card in the Clash Royale catalog")
+    }
+
     pub fn name(&self) -> &'static str {
-        match self {
-            ClashRoyaleCard::Knight => "Knight",
-            ClashRoyaleCard::Archers => "Archers",
-            ClashRoyaleCard::Goblins => "Goblins",
-            ClashRoyaleCard::Giant => "Giant",
-            ClashRoyaleCard::PEKKA => "P.E.K.K.A",
-            ClashRoyaleCard::Minions => "Minions",
-            ClashRoyaleCard::Balloon => "Balloon",
-            ClashRoyaleCard::Witch => "Witch",
-            ClashRoyaleCard::Barbarians => "Barbarians",
-            ClashRoyaleCard::Golem => "Golem",
-            ClashRoyaleCard::Skeletons => "Skeletons",
-            ClashRoyaleCard::Valkyrie => "Valkyrie",
-            ClashRoyaleCard::SkeletonArmy => "Skeleton Army",
-            ClashRoyaleCard::Bomber => "Bomber",
-            ClashRoyaleCard::Musketeer => "Musketeer",
-            ClashRoyaleCard::BabyDragon => "Baby Dragon",
-            ClashRoyaleCard::Prince => "Prince",
-            ClashRoyaleCard::Wizard => "Wizard",
-            ClashRoyaleCard::MiniPEKKA => "Mini P.E.K.K.A",
-            ClashRoyaleCard::SpearGoblins => "Spear Goblins",
-            ClashRoyaleCard::GiantSkeleton => "Giant Skeleton",
-            ClashRoyaleCard::HogRider => "Hog Rider",
-            ClashRoyaleCard::MinionHorde => "Minion Horde",
-            ClashRoyaleCard::IceWizard => "Ice Wizard",
-            ClashRoyaleCard::RoyalGiant => "Royal Giant",
-            ClashRoyaleCard::Guards => "Guards",
-            ClashRoyaleCard::Princess => "Princess",
-            ClashRoyaleCard::DarkPrince => "Dark Prince",
-            ClashRoyaleCard::ThreeMusketeers => "Three Musketeers",
-            ClashRoyaleCard::LavaHound => "Lava Hound",
-            ClashRoyaleCard::IceSpirit => "Ice Spirit",
-            ClashRoyaleCard::FireSpirit => "Fire Spirit",
-            ClashRoyaleCard::Miner => "Miner",
-            ClashRoyaleCard::Sparky => "Sparky",
-            ClashRoyaleCard::Bowler => "Bowler",
-            ClashRoyaleCard::Lumberjack => "Lumberjack",
-            ClashRoyaleCard::BattleRam => "Battle Ram",
-            ClashRoyaleCard::InfernoDragon => "Inferno Dragon",
-            ClashRoyaleCard::IceGolem => "Ice Golem",
-            ClashRoyaleCard::MegaMinion => "Mega Minion",
-            ClashRoyaleCard::DartGoblin => "Dart Goblin",
-            ClashRoyaleCard::GoblinGang => "Goblin Gang",
-            ClashRoyaleCard::ElectroWizard => "Electro Wizard",
-            ClashRoyaleCard::EliteBarbarians => "Elite Barbarians",
-            ClashRoyaleCard::Hunter => "Hunter",
-            ClashRoyaleCard::Executioner => "Executioner",
-            ClashRoyaleCard::Bandit => "Bandit",
-            ClashRoyaleCard::RoyalRecruits => "Royal Recruits",
-            ClashRoyaleCard::NightWitch => "Night Witch",
-            ClashRoyaleCard::Bats => "Bats",
-            ClashRoyaleCard::RoyalGhost => "Royal Ghost",
-            ClashRoyaleCard::RamRider => "Ram Rider",
-            ClashRoyaleCard::Zappies => "Zappies",
-            ClashRoyaleCard::Rascals => "Rascals",
-            ClashRoyaleCard::CannonCart => "Cannon Cart",
-            ClashRoyaleCard::MegaKnight => "Mega Knight",
-            ClashRoyaleCard::SkeletonBarrel => "Skeleton Barrel",
-            ClashRoyaleCard::FlyingMachine => "Flying Machine",
-            ClashRoyaleCard::WallBreakers => "Wall Breakers",
-            ClashRoyaleCard::RoyalHogs => "Royal Hogs",
-            ClashRoyaleCard::GoblinGiant => "Goblin Giant",
-            ClashRoyaleCard::Fisherman => "Fisherman",
-            ClashRoyaleCard::MagicArcher => "Magic Archer",
-            ClashRoyaleCard::ElectroDragon => "Electro Dragon",
-            ClashRoyaleCard::Firecracker => "Firecracker",
-            ClashRoyaleCard::ElixirGolem => "Elixir Golem",
-            ClashRoyaleCard::BattleHealer => "Battle Healer",
-            ClashRoyaleCard::SkeletonDragons => "Skeleton Dragons",
-            ClashRoyaleCard::MotherWitch => "Mother Witch",
-            ClashRoyaleCard::ElectroSpirit => "Electro Spirit",
-            ClashRoyaleCard::ElectroGiant => "Electro Giant",
-            ClashRoyaleCard::Cannon => "Cannon",
-            ClashRoyaleCard::GoblinHut => "Goblin Hut",
-            ClashRoyaleCard::Mortar => "Mortar",
-            ClashRoyaleCard::InfernoTower => "Inferno Tower",
-            ClashRoyaleCard::BombTower => "Bomb Tower",
-            ClashRoyaleCard::BarbarianHut => "Barbarian Hut",
-            ClashRoyaleCard::Tesla => "Tesla",
-            ClashRoyaleCard::ElixirCollector => "Elixir Collector",
-            ClashRoyaleCard::XBow => "X-Bow",
-            ClashRoyaleCard::Tombstone => "Tombstone",
-            ClashRoyaleCard::Furnace => "Furnace",
-            ClashRoyaleCard::GoblinCage => "Goblin Cage",
-            ClashRoyaleCard::GoblinDrill => "Goblin Drill",
-            ClashRoyaleCard::Fireball => "Fireball",
-            ClashRoyaleCard::Arrows => "Arrows",
-            ClashRoyaleCard::Rage => "Rage",
-            ClashRoyaleCard::Rocket => "Rocket",
-            ClashRoyaleCard::GoblinBarrel => "Goblin Barrel",
-            ClashRoyaleCard::Freeze => "Freeze",
-            ClashRoyaleCard::Mirror => "Mirror",
-            ClashRoyaleCard::Lightning => "Lightning",
-            ClashRoyaleCard::Zap => "Zap",
-            ClashRoyaleCard::Poison => "Poison",
-            ClashRoyaleCard::Graveyard => "Graveyard",
-            ClashRoyaleCard::TheLog => "The Log",
-            ClashRoyaleCard::Tornado => "Tornado",
-            ClashRoyaleCard::Clone => "Clone",
-            ClashRoyaleCard::Earthquake => "Earthquake",
-            ClashRoyaleCard::BarbarianBarrel => "Barbarian Barrel",
-            ClashRoyaleCard::HealSpirit => "Heal Spirit",
-            ClashRoyaleCard::GiantSnowball => "Giant Snowball",
-            ClashRoyaleCard::RoyalDelivery => "Royal Delivery",
-        }
+        self.info().name.as_str()
     }
 
     pub fn max_level(&self) -> u64 {
-        match self {
-            ClashRoyaleCard::Knight => 13,
-            ClashRoyaleCard::Archers => 13,
-            ClashRoyaleCard::Goblins => 13,
-            ClashRoyaleCard::Giant => 11,
-            ClashRoyaleCard::PEKKA => 8,
-            ClashRoyaleCard::Minions => 13,
-            ClashRoyaleCard::Balloon => 8,
-            ClashRoyaleCard::Witch => 8,
-            ClashRoyaleCard::Barbarians => 13,
-            ClashRoyaleCard::Golem => 8,
-            ClashRoyaleCard::Skeletons => 13,
-            ClashRoyaleCard::Valkyrie => 11,
-            ClashRoyaleCard::SkeletonArmy => 8,
-            ClashRoyaleCard::Bomber => 13,
-            ClashRoyaleCard::Musketeer => 11,
-            ClashRoyaleCard::BabyDragon => 8,
-            ClashRoyaleCard::Prince => 8,
-            ClashRoyaleCard::Wizard => 11,
-            ClashRoyaleCard::MiniPEKKA => 11,
-            ClashRoyaleCard::SpearGoblins => 13,
-            ClashRoyaleCard::GiantSkeleton => 8,
-            ClashRoyaleCard::HogRider => 11,
-            ClashRoyaleCard::MinionHorde => 13,
-            ClashRoyaleCard::IceWizard => 5,
-            ClashRoyaleCard::RoyalGiant => 13,
-            ClashRoyaleCard::Guards => 8,
-            ClashRoyaleCard::Princess => 5,
-            ClashRoyaleCard::DarkPrince => 8,
-            ClashRoyaleCard::ThreeMusketeers => 11,
-            ClashRoyaleCard::LavaHound => 5,
-            ClashRoyaleCard::IceSpirit => 13,
-            ClashRoyaleCard::FireSpirit => 13,
-            ClashRoyaleCard::Miner => 5,
-            ClashRoyaleCard::Sparky => 5,
-            ClashRoyaleCard::Bowler => 8,
-            ClashRoyaleCard::Lumberjack => 5,
-            ClashRoyaleCard::BattleRam => 11,
-            ClashRoyaleCard::InfernoDragon => 5,
-            ClashRoyaleCard::IceGolem => 11,
-            ClashRoyaleCard::MegaMinion => 11,
-            ClashRoyaleCard::DartGoblin => 11,
-            ClashRoyaleCard::GoblinGang => 13,
-            ClashRoyaleCard::ElectroWizard => 5,
-            ClashRoyaleCard::EliteBarbarians => 13,
-            ClashRoyaleCard::Hunter => 8,
-            ClashRoyaleCard::Executioner => 8,
-            ClashRoyaleCard::Bandit => 5,
-            ClashRoyaleCard::RoyalRecruits => 13,
-            ClashRoyaleCard::NightWitch => 5,
-            ClashRoyaleCard::Bats => 13,
-            ClashRoyaleCard::RoyalGhost => 5,
-            ClashRoyaleCard::RamRider => 5,
-            ClashRoyaleCard::Zappies => 11,
-            ClashRoyaleCard::Rascals => 13,
-            ClashRoyaleCard::CannonCart => 8,
-            ClashRoyaleCard::MegaKnight => 5,
-            ClashRoyaleCard::SkeletonBarrel => 13,
-            ClashRoyaleCard::FlyingMachine => 11,
-            ClashRoyaleCard::WallBreakers => 8,
-            ClashRoyaleCard::RoyalHogs => 11,
-            ClashRoyaleCard::GoblinGiant => 8,
-            ClashRoyaleCard::Fisherman => 5,
-            ClashRoyaleCard::MagicArcher => 5,
-            ClashRoyaleCard::ElectroDragon => 8,
-            ClashRoyaleCard::Firecracker => 13,
-            ClashRoyaleCard::ElixirGolem => 11,
-            ClashRoyaleCard::BattleHealer => 11,
-            ClashRoyaleCard::SkeletonDragons => 13,
-            ClashRoyaleCard::MotherWitch => 5,
-            ClashRoyaleCard::ElectroSpirit => 13,
-            ClashRoyaleCard::ElectroGiant => 8,
-            ClashRoyaleCard::Cannon => 13,
-            ClashRoyaleCard::GoblinHut => 11,
-            ClashRoyaleCard::Mortar => 13,
-            ClashRoyaleCard::InfernoTower => 11,
-            ClashRoyaleCard::BombTower => 11,
-            ClashRoyaleCard::BarbarianHut => 11,
-            ClashRoyaleCard::Tesla => 13,
-            ClashRoyaleCard::ElixirCollector => 11,
-            ClashRoyaleCard::XBow => 8,
-            ClashRoyaleCard::Tombstone => 11,
-            ClashRoyaleCard::Furnace => 11,
-            ClashRoyaleCard::GoblinCage => 11,
-            ClashRoyaleCard::GoblinDrill => 8,
-            ClashRoyaleCard::Fireball => 11,
-            ClashRoyaleCard::Arrows => 13,
-            ClashRoyaleCard::Rage => 8,
-            ClashRoyaleCard::Rocket => 11,
-            ClashRoyaleCard::GoblinBarrel => 8,
-            ClashRoyaleCard::Freeze => 8,
-            ClashRoyaleCard::Mirror => 8,
-            ClashRoyaleCard::Lightning => 8,
-            ClashRoyaleCard::Zap => 13,
-            ClashRoyaleCard::Poison => 8,
-            ClashRoyaleCard::Graveyard => 5,
-            ClashRoyaleCard::TheLog => 5,
-            ClashRoyaleCard::Tornado => 8,
-            ClashRoyaleCard::Clone => 8,
-            ClashRoyaleCard::Earthquake => 11,
-            ClashRoyaleCard::BarbarianBarrel => 8,
-            ClashRoyaleCard::HealSpirit => 11,
-            ClashRoyaleCard::GiantSnowball => 13,
-            ClashRoyaleCard::RoyalDelivery => 13,
-        }
+        self.info().max_level
     }
 
     pub fn icon_url(&self) -> &'static str {
-        match self {
-            ClashRoyaleCard::Knight => "https://api-assets.clashroyale.com/cards/300/jAj1Q5rclXxU9kVImGqSJxa4wEMfEhvwNQ_4jiGUuqg.png",
-            ClashRoyaleCard::Archers => "https://api-assets.clashroyale.com/cards/300/W4Hmp8MTSdXANN8KdblbtHwtsbt0o749BbxNqmJYfA8.png",
-            ClashRoyaleCard::Goblins => "https://api-assets.clashroyale.com/cards/300/X_DQUye_OaS3QN6VC9CPw05Fit7wvSm3XegXIXKP--0.png",
-            ClashRoyaleCard::Giant => "https://api-assets.clashroyale.com/cards/300/Axr4ox5_b7edmLsoHxBX3vmgijAIibuF6RImTbqLlXE.png",
-            ClashRoyaleCard::PEKKA => "https://api-assets.clashroyale.com/cards/300/MlArURKhn_zWAZY-Xj1qIRKLVKquarG25BXDjUQajNs.png",
-            ClashRoyaleCard::Minions => "https://api-assets.clashroyale.com/cards/300/yHGpoEnmUWPGV_hBbhn-Kk-Bs838OjGzWzJJlQpQKQA.png",
-            ClashRoyaleCard::Balloon => "https://api-assets.clashroyale.com/cards/300/qBipxLo-3hhCnPrApp2Nn3b2NgrSrvwzWytvREev0CY.png",
-            ClashRoyaleCard::Witch => "https://api-assets.clashroyale.com/cards/300/cfwk1vzehVyHC-uloEIH6NOI0hOdofCutR5PyhIgO6w.png",
-            ClashRoyaleCard::Barbarians => "https://api-assets.clashroyale.com/cards/300/TvJsuu2S4yhyk1jVYUAQwdKOnW4U77KuWWOTPOWnwfI.png",
-            ClashRoyaleCard::Golem => "https://api-assets.clashroyale.com/cards/300/npdmCnET7jmVjJvjJQkFnNSNnDxYHDBigbvIAloFMds.png",
-            ClashRoyaleCard::Skeletons => "https://api-assets.clashroyale.com/cards/300/oO7iKMU5m0cdxhYPZA3nWQiAUh2yoGgdThLWB1rVSec.png",
-            ClashRoyaleCard::Valkyrie => "https://api-assets.clashroyale.com/cards/300/0lIoYf3Y_plFTzo95zZL93JVxpfb3MMgFDDhgSDGU9A.png",
-            ClashRoyaleCard::SkeletonArmy => "https://api-assets.clashroyale.com/cards/300/fAOToOi1pRy7svN2xQS6mDkhQw2pj9m_17FauaNqyl4.png",
-            ClashRoyaleCard::Bomber => "https://api-assets.clashroyale.com/cards/300/12n1CesxKIcqVYntjxcF36EFA-ONw7Z-DoL0_rQrbdo.png",
-            ClashRoyaleCard::Musketeer => "https://api-assets.clashroyale.com/cards/300/Tex1C48UTq9FKtAX-3tzG0FJmc9jzncUZG3bb5Vf-Ds.png",
-            ClashRoyaleCard::BabyDragon => "https://api-assets.clashroyale.com/cards/300/cjC9n4AvEZJ3urkVh-rwBkJ-aRSsydIMqSAV48hAih0.png",
-            ClashRoyaleCard::Prince => "https://api-assets.clashroyale.com/cards/300/3JntJV62aY0G1Qh6LIs-ek-0ayeYFY3VItpG7cb9I60.png",
-            ClashRoyaleCard::Wizard => "https://api-assets.clashroyale.com/cards/300/Mej7vnv4H_3p_8qPs_N6_GKahy6HDr7pU7i9eTHS84U.png",
-            ClashRoyaleCard::MiniPEKKA => "https://api-assets.clashroyale.com/cards/300/Fmltc4j3Ve9vO_xhHHPEO3PRP3SmU2oKp2zkZQHRZT4.png",
-            ClashRoyaleCard::SpearGoblins => "https://api-assets.clashroyale.com/cards/300/FSDFotjaXidI4ku_WFpVCTWS1hKGnFh1sxX0lxM43_E.png",
-            ClashRoyaleCard::GiantSkeleton => "https://api-assets.clashroyale.com/cards/300/0p0gd0XaVRu1Hb1iSG1hTYbz2AN6aEiZnhaAib5O8Z8.png",
-            ClashRoyaleCard::HogRider => "https://api-assets.clashroyale.com/cards/300/Ubu0oUl8tZkusnkZf8Xv9Vno5IO29Y-jbZ4fhoNJ5oc.png",
-            ClashRoyaleCard::MinionHorde => "https://api-assets.clashroyale.com/cards/300/Wyjq5l0IXHTkX9Rmpap6HaH08MvjbxFp1xBO9a47YSI.png",
-            ClashRoyaleCard::IceWizard => "https://api-assets.clashroyale.com/cards/300/W3dkw0HTw9n1jB-zbknY2w3wHuyuLxSRIAV5fUT1SEY.png",
-            ClashRoyaleCard::RoyalGiant => "https://api-assets.clashroyale.com/cards/300/mnlRaNtmfpQx2e6mp70sLd0ND-pKPF70Cf87_agEKg4.png",
-            ClashRoyaleCard::Guards => "https://api-assets.clashroyale.com/cards/300/1ArKfLJxYo6_NU_S9cAeIrfbXqWH0oULVJXedxBXQlU.png",
-            ClashRoyaleCard::Princess => "https://api-assets.clashroyale.com/cards/300/bAwMcqp9EKVIKH3ZLm_m0MqZFSG72zG-vKxpx8aKoVs.png",
-            ClashRoyaleCard::DarkPrince => "https://api-assets.clashroyale.com/cards/300/M7fXlrKXHu2IvpSGpk36kXVstslbR08Bbxcy0jQcln8.png",
-            ClashRoyaleCard::ThreeMusketeers => "https://api-assets.clashroyale.com/cards/300/_J2GhbkX3vswaFk1wG-dopwiHyNc_YiPhwroiKF3Mek.png",
-            ClashRoyaleCard::LavaHound => "https://api-assets.clashroyale.com/cards/300/unicRQ975sBY2oLtfgZbAI56ZvaWz7azj-vXTLxc0r8.png",
-            ClashRoyaleCard::IceSpirit => "https://api-assets.clashroyale.com/cards/300/lv1budiafU9XmSdrDkk0NYyqASAFYyZ06CPysXKZXlA.png",
-            ClashRoyaleCard::FireSpirit => "https://api-assets.clashroyale.com/cards/300/16-BqusVvynIgYI8_Jci3LDC-r8AI_xaIYLgXqtlmS8.png",
-            ClashRoyaleCard::Miner => "https://api-assets.clashroyale.com/cards/300/Y4yWvdwBCg2FpAZgs8T09Gy34WOwpLZW-ttL52Ae8NE.png",
-            ClashRoyaleCard::Sparky => "https://api-assets.clashroyale.com/cards/300/2GKMkBrArZXgQxf2ygFjDs4VvGYPbx8F6Lj_68iVhIM.png",
-            ClashRoyaleCard::Bowler => "https://api-assets.clashroyale.com/cards/300/SU4qFXmbQXWjvASxVI6z9IJuTYolx4A0MKK90sTIE88.png",
-            ClashRoyaleCard::Lumberjack => "https://api-assets.clashroyale.com/cards/300/E6RWrnCuk13xMX5OE1EQtLEKTZQV6B78d00y8PlXt6Q.png",
-            ClashRoyaleCard::BattleRam => "https://api-assets.clashroyale.com/cards/300/dyc50V2cplKi4H7pq1B3I36pl_sEH5DQrNHboS_dbbM.png",
-            ClashRoyaleCard::InfernoDragon => "https://api-assets.clashroyale.com/cards/300/y5HDbKtTbWG6En6TGWU0xoVIGs1-iQpIP4HC-VM7u8A.png",
-            ClashRoyaleCard::IceGolem => "https://api-assets.clashroyale.com/cards/300/r05cmpwV1o7i7FHodtZwW3fmjbXCW34IJCsDEV5cZC4.png",
-            ClashRoyaleCard::MegaMinion => "https://api-assets.clashroyale.com/cards/300/-T_e4YLbuhPBKbYnBwQfXgynNpp5eOIN_0RracYwL9c.png",
-            ClashRoyaleCard::DartGoblin => "https://api-assets.clashroyale.com/cards/300/BmpK3bqEAviflqHCdxxnfm-_l3pRPJw3qxHkwS55nCY.png",
-            ClashRoyaleCard::GoblinGang => "https://api-assets.clashroyale.com/cards/300/NHflxzVAQT4oAz7eDfdueqpictb5vrWezn1nuqFhE4w.png",
-            ClashRoyaleCard::ElectroWizard => "https://api-assets.clashroyale.com/cards/300/RsFaHgB3w6vXsTjXdPr3x8l_GbV9TbOUCvIx07prbrQ.png",
-            ClashRoyaleCard::EliteBarbarians => "https://api-assets.clashroyale.com/cards/300/C88C5JH_F3lLZj6K-tLcMo5DPjrFmvzIb1R2M6xCfTE.png",
-            ClashRoyaleCard::Hunter => "https://api-assets.clashroyale.com/cards/300/VNabB1WKnYtYRSG7X_FZfnZjQDHTBs9A96OGMFmecrA.png",
-            ClashRoyaleCard::Executioner => "https://api-assets.clashroyale.com/cards/300/9XL5BP2mqzV8kza6KF8rOxrpCZTyuGLp2l413DTjEoM.png",
-            ClashRoyaleCard::Bandit => "https://api-assets.clashroyale.com/cards/300/QWDdXMKJNpv0go-HYaWQWP6p8uIOHjqn-zX7G0p3DyM.png",
-            ClashRoyaleCard::RoyalRecruits => "https://api-assets.clashroyale.com/cards/300/jcNyYGUiXXNz3kuz8NBkHNKNREQKraXlb_Ts7rhCIdM.png",
-            ClashRoyaleCard::NightWitch => "https://api-assets.clashroyale.com/cards/300/NpCrXDEDBBJgNv9QrBAcJmmMFbS7pe3KCY8xJ5VB18A.png",
-            ClashRoyaleCard::Bats => "https://api-assets.clashroyale.com/cards/300/EnIcvO21hxiNpoI-zO6MDjLmzwPbq8Z4JPo2OKoVUjU.png",
-            ClashRoyaleCard::RoyalGhost => "https://api-assets.clashroyale.com/cards/300/3En2cz0ISQAaMTHY3hj3rTveFN2kJYq-H4VxvdJNvCM.png",
-            ClashRoyaleCard::RamRider => "https://api-assets.clashroyale.com/cards/300/QaJyerT7f7oMyZ3Fv1glKymtLSvx7YUXisAulxl7zRI.png",
-            ClashRoyaleCard::Zappies => "https://api-assets.clashroyale.com/cards/300/QZfHRpLRmutZbCr5fpLnTpIp89vLI6NrAwzGZ8tHEc4.png",
-            ClashRoyaleCard::Rascals => "https://api-assets.clashroyale.com/cards/300/KV48DfwVHKx9XCjzBdk3daT_Eb52Me4VgjVO7WctRc4.png",
-            ClashRoyaleCard::CannonCart => "https://api-assets.clashroyale.com/cards/300/aqwxRz8HXzqlMCO4WMXNA1txynjXTsLinknqsgZLbok.png",
-            ClashRoyaleCard::MegaKnight => "https://api-assets.clashroyale.com/cards/300/O2NycChSNhn_UK9nqBXUhhC_lILkiANzPuJjtjoz0CE.png",
-            ClashRoyaleCard::SkeletonBarrel => "https://api-assets.clashroyale.com/cards/300/vCB4DWCcrGbTkarjcOiVz4aNDx6GWLm0yUepg9E1MGo.png",
-            ClashRoyaleCard::FlyingMachine => "https://api-assets.clashroyale.com/cards/300/hzKNE3QwFcrSrDDRuVW3QY_OnrDPijSiIp-PsWgFevE.png",
-            ClashRoyaleCard::WallBreakers => "https://api-assets.clashroyale.com/cards/300/_xPphEfC8eEwFNrfU3cMQG9-f5JaLQ31ARCA7l3XtW4.png",
-            ClashRoyaleCard::RoyalHogs => "https://api-assets.clashroyale.com/cards/300/ASSQJG_MoVq9e81HZzo4bynMnyLNpNJMfSLb3hqydOw.png",
-            ClashRoyaleCard::GoblinGiant => "https://api-assets.clashroyale.com/cards/300/SoW16cY3jXBwaTDvb39DkqiVsoFVaDWbzf5QBYphJrY.png",
-            ClashRoyaleCard::Fisherman => "https://api-assets.clashroyale.com/cards/300/U2KZ3g0wyufcuA5P2Xrn3Z3lr1WiJmc5S0IWOZHgizQ.png",
-            ClashRoyaleCard::MagicArcher => "https://api-assets.clashroyale.com/cards/300/Avli3W7BxU9HQ2SoLiXnBgGx25FoNXUSFm7OcAk68ek.png",
-            ClashRoyaleCard::ElectroDragon => "https://api-assets.clashroyale.com/cards/300/tN9h6lnMNPCNsx0LMFmvpHgznbDZ1fBRkx-C7UfNmfY.png",
-            ClashRoyaleCard::Firecracker => "https://api-assets.clashroyale.com/cards/300/c1rL3LO1U2D9-TkeFfAC18gP3AO8ztSwrcHMZplwL2Q.png",
-            ClashRoyaleCard::ElixirGolem => "https://api-assets.clashroyale.com/cards/300/puhMsZjCIqy21HW3hYxjrk_xt8NIPyFqjRy-BeLKZwo.png",
-            ClashRoyaleCard::BattleHealer => "https://api-assets.clashroyale.com/cards/300/KdwXcoigS2Kg-cgA7BJJIANbUJG6SNgjetRQ-MegZ08.png",
-            ClashRoyaleCard::SkeletonDragons => "https://api-assets.clashroyale.com/cards/300/qPOtg9uONh47_NLxGhhFc_ww9PlZ6z3Ry507q1NZUXs.png",
-            ClashRoyaleCard::MotherWitch => "https://api-assets.clashroyale.com/cards/300/fO-Xah8XZkYKaSK9SCp3wnzwxtvIhun9NVY-zzte1Ng.png",
-            ClashRoyaleCard::ElectroSpirit => "https://api-assets.clashroyale.com/cards/300/WKd4-IAFsgPpMo7dDi9sujmYjRhOMEWiE07OUJpvD9g.png",
-            ClashRoyaleCard::ElectroGiant => "https://api-assets.clashroyale.com/cards/300/_uChZkNHAMq6tPb3v6A49xinOe3CnhjstOhG6OZbPYc.png",
-            ClashRoyaleCard::Cannon => "https://api-assets.clashroyale.com/cards/300/nZK1y-beLxO5vnlyUhK6-2zH2NzXJwqykcosqQ1cmZ8.png",
-            ClashRoyaleCard::GoblinHut => "https://api-assets.clashroyale.com/cards/300/l8ZdzzNLcwB4u7ihGgxNFQOjCT_njFuAhZr7D6PRF7E.png",
-            ClashRoyaleCard::Mortar => "https://api-assets.clashroyale.com/cards/300/lPOSw6H7YOHq2miSCrf7ZDL3ANjhJdPPDYOTujdNrVE.png",
-            ClashRoyaleCard::InfernoTower => "https://api-assets.clashroyale.com/cards/300/GSHY_wrooMMLET6bG_WJB8redtwx66c4i80ipi4gYOM.png",
-            ClashRoyaleCard::BombTower => "https://api-assets.clashroyale.com/cards/300/rirYRyHPc97emRjoH-c1O8uZCBzPVnToaGuNGusF3TQ.png",
-            ClashRoyaleCard::BarbarianHut => "https://api-assets.clashroyale.com/cards/300/ho0nOG2y3Ch86elHHcocQs8Fv_QNe0cFJ2CijsxABZA.png",
-            ClashRoyaleCard::Tesla => "https://api-assets.clashroyale.com/cards/300/OiwnGrxFMNiHetYEerE-UZt0L_uYNzFY7qV_CA_OxR4.png",
-            ClashRoyaleCard::ElixirCollector => "https://api-assets.clashroyale.com/cards/300/BGLo3Grsp81c72EpxLLk-Sofk3VY56zahnUNOv3JcT0.png",
-            ClashRoyaleCard::XBow => "https://api-assets.clashroyale.com/cards/300/zVQ9Hme1hlj9Dc6e1ORl9xWwglcSrP7ejow5mAhLUJc.png",
-            ClashRoyaleCard::Tombstone => "https://api-assets.clashroyale.com/cards/300/LjSfSbwQfkZuRJY4pVxKspZ-a0iM5KAhU8w-a_N5Z7Y.png",
-            ClashRoyaleCard::Furnace => "https://api-assets.clashroyale.com/cards/300/iqbDiG7yYRIzvCPXdt9zPb3IvMt7F7Gi4wIPnh2x4aI.png",
-            ClashRoyaleCard::GoblinCage => "https://api-assets.clashroyale.com/cards/300/vD24bBgK4rSq7wx5QEbuqChtPMRFviL_ep76GwQw1yA.png",
-            ClashRoyaleCard::GoblinDrill => "https://api-assets.clashroyale.com/cards/300/eN2TKUYbih-26yBi0xy5LVFOA0zDftgDqxxnVfdIg1o.png",
-            ClashRoyaleCard::Fireball => "https://api-assets.clashroyale.com/cards/300/lZD9MILQv7O-P3XBr_xOLS5idwuz3_7Ws9G60U36yhc.png",
-            ClashRoyaleCard::Arrows => "https://api-assets.clashroyale.com/cards/300/Flsoci-Y6y8ZFVi5uRFTmgkPnCmMyMVrU7YmmuPvSBo.png",
-            ClashRoyaleCard::Rage => "https://api-assets.clashroyale.com/cards/300/bGP21OOmcpHMJ5ZA79bHVV2D-NzPtDkvBskCNJb7pg0.png",
-            ClashRoyaleCard::Rocket => "https://api-assets.clashroyale.com/cards/300/Ie07nQNK9CjhKOa4-arFAewi4EroqaA-86Xo7r5tx94.png",
-            ClashRoyaleCard::GoblinBarrel => "https://api-assets.clashroyale.com/cards/300/CoZdp5PpsTH858l212lAMeJxVJ0zxv9V-f5xC8Bvj5g.png",
-            ClashRoyaleCard::Freeze => "https://api-assets.clashroyale.com/cards/300/I1M20_Zs_p_BS1NaNIVQjuMJkYI_1-ePtwYZahn0JXQ.png",
-            ClashRoyaleCard::Mirror => "https://api-assets.clashroyale.com/cards/300/wC6Cm9rKLEOk72zTsukVwxewKIoO4ZcMJun54zCPWvA.png",
-            ClashRoyaleCard::Lightning => "https://api-assets.clashroyale.com/cards/300/fpnESbYqe5GyZmaVVYe-SEu7tE0Kxh_HZyVigzvLjks.png",
-            ClashRoyaleCard::Zap => "https://api-assets.clashroyale.com/cards/300/7dxh2-yCBy1x44GrBaL29vjqnEEeJXHEAlsi5g6D1eY.png",
-            ClashRoyaleCard::Poison => "https://api-assets.clashroyale.com/cards/300/98HDkG2189yOULcVG9jz2QbJKtfuhH21DIrIjkOjxI8.png",
-            ClashRoyaleCard::Graveyard => "https://api-assets.clashroyale.com/cards/300/Icp8BIyyfBTj1ncCJS7mb82SY7TPV-MAE-J2L2R48DI.png",
-            ClashRoyaleCard::TheLog => "https://api-assets.clashroyale.com/cards/300/_iDwuDLexHPFZ_x4_a0eP-rxCS6vwWgTs6DLauwwoaY.png",
-            ClashRoyaleCard::Tornado => "https://api-assets.clashroyale.com/cards/300/QJB-QK1QJHdw4hjpAwVSyZBozc2ZWAR9pQ-SMUyKaT0.png",
-            ClashRoyaleCard::Clone => "https://api-assets.clashroyale.com/cards/300/mHVCet-1TkwWq-pxVIU2ZWY9_2z7Z7wtP25ArEUsP_g.png",
-            ClashRoyaleCard::Earthquake => "https://api-assets.clashroyale.com/cards/300/XeQXcrUu59C52DslyZVwCnbi4yamID-WxfVZLShgZmE.png",
-            ClashRoyaleCard::BarbarianBarrel => "https://api-assets.clashroyale.com/cards/300/Gb0G1yNy0i5cIGUHin8uoFWxqntNtRPhY_jeMXg7HnA.png",
-            ClashRoyaleCard::HealSpirit => "https://api-assets.clashroyale.com/cards/300/GITl06sa2nGRLPvboyXbGEv5E3I-wAwn1Eqa5esggbc.png",
-            ClashRoyaleCard::GiantSnowball => "https://api-assets.clashroyale.com/cards/300/7MaJLa6hK9WN2_VIshuh5DIDfGwm0wEv98gXtAxLDPs.png",
-            ClashRoyaleCard::RoyalDelivery => "https://api-assets.clashroyale.com/cards/300/LPg7AGjGI3_xmi7gLLgGC50yKM1jJ2teWkZfoHJcIZo.png",
-        }
+        self.info().icon_url.as_str()
+    }
+
+    pub fn rarity(&self) -> ClashRoyaleCardRarity {
+        self.info().rarity
+    }
+
+    pub fn kind(&self) -> ClashRoyaleCardType {
+        self.info().kind
+    }
+
+    pub fn elixir(&self) -> Option<u8> {
+        self.info().elixir
+    }
+
+    pub fn is_win_condition(&self) -> bool {
+        self.info().win_condition
+    }
+}
+
+impl TryFrom<u64> for ClashRoyaleCard {
+    type Error = ();
+
+    fn try_from(id: u64) -> Result<Self, Self::Error> {
+        Self::enum_list()
+            .iter()
+            .find(|v| **v as u64 == id)
+            .copied()
+            .ok_or(())
     }
 }
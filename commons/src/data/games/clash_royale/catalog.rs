@@ -0,0 +1,162 @@
+//! The catalog of cards and arenas bundled from cr-api-data.
+//! Info: https://github.com/RoyaleAPI/cr-api-data/tree/master/docs/json
+
+use std::collections::HashMap;
+
+use arcstr::ArcStr;
+use serde::{Deserialize, Serialize};
+
+static CARDS_CATALOG: &str = include_str!("catalog/cards.json");
+static ARENAS_CATALOG: &str = include_str!("catalog/arenas.json");
+
+lazy_static! {
+    static ref CATALOG: ClashRoyaleCatalog = ClashRoyaleCatalog::load();
+}
+
+pub struct ClashRoyaleCatalog {
+    cards: Vec<ClashRoyaleCardInfo>,
+    card_indexes: HashMap<u64, usize>,
+
+    /// The highest `max_level` of all cards, i.e. that of the commons.
+    max_card_level: u64,
+
+    /// Sorted by order.
+    arenas: Vec<ClashRoyaleArenaInfo>,
+    arena_indexes: HashMap<u64, usize>,
+}
+
+impl ClashRoyaleCatalog {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    fn load() -> Self {
+        let cards: Vec<ClashRoyaleCardInfo> =
+            serde_json::from_str(CARDS_CATALOG).expect("Incorrect Clash Royale cards catalog");
+        let mut arenas: Vec<ClashRoyaleArenaInfo> =
+            serde_json::from_str(ARENAS_CATALOG).expect("Incorrect Clash Royale arenas catalog");
+        arenas.sort_by_key(|v| v.order);
+
+        ClashRoyaleCatalog {
+            card_indexes: cards.iter().enumerate().map(|(i, v)| (v.id, i)).collect(),
+            max_card_level: cards.iter().map(|v| v.max_level).max().unwrap_or(0),
+            cards,
+            arena_indexes: arenas.iter().enumerate().map(|(i, v)| (v.id, i)).collect(),
+            arenas,
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn cards(&self) -> &[ClashRoyaleCardInfo] {
+        &self.cards
+    }
+
+    pub fn arenas(&self) -> &[ClashRoyaleArenaInfo] {
+        &self.arenas
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn card(&self, id: u64) -> Option<&ClashRoyaleCardInfo> {
+        self.card_indexes.get(&id).map(|i| &self.cards[*i])
+    }
+
+    /// Converts the level of a card to the scale of the commons, because every rarity has a
+    /// different number of levels, e.g. a level 1 legendary is as upgraded as a level 9 common.
+    /// None if the card is not in the catalog.
+    pub fn normalize_card_level(&self, id: u64, level: u64) -> Option<u64> {
+        let card = self.card(id)?;
+        Some(level + self.max_card_level.saturating_sub(card.max_level))
+    }
+
+    pub fn arena(&self, id: u64) -> Option<&ClashRoyaleArenaInfo> {
+        self.arena_indexes.get(&id).map(|i| &self.arenas[*i])
+    }
+
+    /// The arena a player with `trophies` belongs to.
+    pub fn arena_by_trophies(&self, trophies: u64) -> Option<&ClashRoyaleArenaInfo> {
+        self.arenas
+            .iter()
+            .rev()
+            .find(|v| v.min_trophies <= trophies)
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    pub fn instance() -> &'static ClashRoyaleCatalog {
+        &CATALOG
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleCardInfo {
+    pub id: u64,
+    pub key: ArcStr,
+    pub name: ArcStr,
+    pub rarity: ClashRoyaleCardRarity,
+
+    #[serde(rename = "type")]
+    pub kind: ClashRoyaleCardType,
+
+    /// Missing for cards with a variable cost, e.g. Mirror.
+    #[serde(default)]
+    pub elixir: Option<u8>,
+
+    /// Whether the card is the main way of a deck to damage the towers, e.g. Hog Rider.
+    #[serde(default)]
+    pub win_condition: bool,
+
+    pub max_level: u64,
+    pub icon_url: ArcStr,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ClashRoyaleCardRarity {
+    Common,
+    Rare,
+    Epic,
+    Legendary,
+    Champion,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ClashRoyaleCardType {
+    Troop,
+    Building,
+    Spell,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleArenaInfo {
+    pub id: u64,
+    pub key: ArcStr,
+    pub order: u64,
+    pub title: ArcStr,
+    pub subtitle: ArcStr,
+    pub min_trophies: u64,
+
+    /// Missing for the last arena.
+    #[serde(default)]
+    pub max_trophies: Option<u64>,
+
+    pub icon_url: ArcStr,
+}
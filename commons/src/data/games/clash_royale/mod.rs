@@ -1,10 +1,12 @@
 pub use arena::*;
 pub use cards::*;
+pub use catalog::*;
 
 use crate::server::validators::length_validator;
 
 mod arena;
 mod cards;
+mod catalog;
 
 pub fn clash_royale_tag_validator(tag: &str) -> bool {
     if length_validator(tag.len(), 6, 15, "").is_err() {
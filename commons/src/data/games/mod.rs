@@ -1,3 +1,5 @@
+pub use brawl_stars::*;
 pub use clash_royale::*;
 
+mod brawl_stars;
 mod clash_royale;
@@ -0,0 +1,231 @@
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+
+use arangors::document::options::OverwriteMode;
+use arangors::index::{Index, IndexSettings};
+
+use crate::clients::games::{BrawlStarsBattlelogResponse, ClashRoyaleBattlelogResponse};
+use crate::constants::{BATTLELOG_PLAYER_INDEX, DATABASE_TTL_INDEX};
+use crate::database::collections::CollectionKind;
+use crate::database::documents::{BattlelogDBDocument, BattlelogDBDocumentField};
+use crate::database::types::DateTime;
+use crate::database::{
+    AqlBuilder, AqlInsert, AqlReturn, AqlSort, DBCollection, DBInfo, NullableOption,
+    AQL_DOCUMENT_ID,
+};
+use crate::error::AppResult;
+
+lazy_static! {
+    static ref COLLECTION: Mutex<Option<Arc<BattlelogCollection>>> = Mutex::new(None);
+}
+
+#[derive(Debug)]
+pub struct BattlelogCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl BattlelogCollection {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn init(db_info: &Arc<DBInfo>) -> Arc<BattlelogCollection> {
+        let mut collection = COLLECTION.lock().unwrap();
+        match collection.deref() {
+            Some(v) => v.clone(),
+            None => {
+                let value = Arc::new(BattlelogCollection {
+                    db_info: db_info.clone(),
+                });
+
+                *collection = Some(value.clone());
+
+                value
+            }
+        }
+    }
+
+    pub fn instance() -> Arc<BattlelogCollection> {
+        COLLECTION.lock().unwrap().as_ref().unwrap().clone()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Stores the Clash Royale matches of a player ignoring those already stored.
+    pub async fn store_clash_royale_battlelogs(
+        tag: &str,
+        battlelogs: Vec<ClashRoyaleBattlelogResponse>,
+    ) -> AppResult<()> {
+        let documents = battlelogs
+            .into_iter()
+            .map(|battlelog| BattlelogDBDocument::new_clash_royale(tag, battlelog))
+            .collect::<AppResult<Vec<_>>>()?;
+
+        Self::store_documents(documents).await
+    }
+
+    /// Stores the Brawl Stars matches of a player ignoring those already stored.
+    pub async fn store_brawl_stars_battlelogs(
+        tag: &str,
+        battlelogs: Vec<BrawlStarsBattlelogResponse>,
+    ) -> AppResult<()> {
+        let documents = battlelogs
+            .into_iter()
+            .map(|battlelog| BattlelogDBDocument::new_brawl_stars(tag, battlelog))
+            .collect::<AppResult<Vec<_>>>()?;
+
+        Self::store_documents(documents).await
+    }
+
+    /// Gets the Clash Royale matches of a player played since `since` in chronological order.
+    pub async fn get_clash_royale_by_player_since(
+        tag: &str,
+        since: &DateTime,
+    ) -> AppResult<Vec<ClashRoyaleBattlelogResponse>> {
+        let documents = Self::get_by_player_since(tag, since).await?;
+        let battlelogs = documents
+            .into_iter()
+            .filter_map(|v| match v.clash_royale {
+                NullableOption::Value(v) => Some(v),
+                _ => None,
+            })
+            .collect();
+
+        Ok(battlelogs)
+    }
+
+    /// Gets the Brawl Stars matches of a player played since `since` in chronological order.
+    pub async fn get_brawl_stars_by_player_since(
+        tag: &str,
+        since: &DateTime,
+    ) -> AppResult<Vec<BrawlStarsBattlelogResponse>> {
+        let documents = Self::get_by_player_since(tag, since).await?;
+        let battlelogs = documents
+            .into_iter()
+            .filter_map(|v| match v.brawl_stars {
+                NullableOption::Value(v) => Some(v),
+                _ => None,
+            })
+            .collect();
+
+        Ok(battlelogs)
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    async fn store_documents(documents: Vec<BattlelogDBDocument>) -> AppResult<()> {
+        if documents.is_empty() {
+            return Ok(());
+        }
+
+        let collection = Self::instance();
+
+        // FOR i IN <documents>
+        //     INSERT i INTO <collection> OPTIONS { overwriteMode: "ignore" }
+        let mut aql = AqlBuilder::new_for_in_list(AQL_DOCUMENT_ID, &documents);
+
+        aql.insert_step(AqlInsert {
+            overwrite_mode: OverwriteMode::Ignore,
+            ..AqlInsert::new_document(Self::name())
+        });
+
+        collection.send_aql(&aql).await?;
+
+        Ok(())
+    }
+
+    /// Gets the matches of any game of a player played since `since` in chronological order.
+    async fn get_by_player_since(
+        tag: &str,
+        since: &DateTime,
+    ) -> AppResult<Vec<BattlelogDBDocument>> {
+        let collection = Self::instance();
+
+        // FOR i IN <collection>
+        //     FILTER i.<player_tag> == <tag> && i.<battle_time> >= <since>
+        //     SORT i.<battle_time> ASC
+        //     RETURN i
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, Self::name());
+
+        aql.filter_step(
+            format!(
+                "{}.{} == {} && {}.{} >= {}",
+                AQL_DOCUMENT_ID,
+                BattlelogDBDocumentField::PlayerTag(None).path(),
+                serde_json::to_string(BattlelogDBDocument::normalize_tag(tag)).unwrap(),
+                AQL_DOCUMENT_ID,
+                BattlelogDBDocumentField::BattleTime(None).path(),
+                serde_json::to_string(since).unwrap()
+            )
+            .into(),
+        );
+        aql.sort_step(vec![AqlSort {
+            expression: format!(
+                "{}.{}",
+                AQL_DOCUMENT_ID,
+                BattlelogDBDocumentField::BattleTime(None).path()
+            )
+            .into(),
+            is_descending: false,
+        }]);
+        aql.return_step(AqlReturn::new_document());
+
+        let result = collection.send_aql(&aql).await?;
+        Ok(result.results)
+    }
+
+    pub(crate) async fn create_collection(db_info: &Arc<DBInfo>) -> AppResult<()> {
+        let database = &db_info.database;
+
+        // Initialize collection.
+        let collection_name = CollectionKind::Battlelogs.name();
+        let _ = database.create_collection(collection_name).await; // Ignore error because it means already created.
+
+        // Add indexes.
+        database
+            .create_index(
+                collection_name,
+                &Index {
+                    name: BATTLELOG_PLAYER_INDEX.into(),
+                    fields: vec![
+                        BattlelogDBDocumentField::PlayerTag(None).path().to_string(),
+                        BattlelogDBDocumentField::BattleTime(None)
+                            .path()
+                            .to_string(),
+                    ],
+                    settings: IndexSettings::Persistent {
+                        unique: false,
+                        sparse: true,
+                        deduplicate: false,
+                    },
+                    ..Index::default()
+                },
+            )
+            .await?;
+        database
+            .create_index(
+                collection_name,
+                &Index {
+                    name: DATABASE_TTL_INDEX.into(),
+                    fields: vec![BattlelogDBDocumentField::DbExpiresAt(None)
+                        .path()
+                        .to_string()],
+                    settings: IndexSettings::Ttl { expire_after: 0 },
+                    ..Index::default()
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl DBCollection for BattlelogCollection {
+    type Document = BattlelogDBDocument;
+
+    fn name() -> &'static str {
+        CollectionKind::Battlelogs.name()
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
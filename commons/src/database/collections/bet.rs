@@ -7,9 +7,9 @@ use crate::constants::{
     BET_CHALLENGE_INDEX, BET_PARTICIPANT_INDEX, DATABASE_MUTEX_INDEX, DATABASE_TTL_INDEX,
 };
 use crate::database::collections::CollectionKind;
-use crate::database::documents::{BetDBDocument, BetDBDocumentField, DBDocumentField};
-use crate::database::types::{DBMutexField, DBUuid};
-use crate::database::{DBCollection, DBInfo};
+use crate::database::documents::{BetDBDocument, BetDBDocumentField, BetDBState, DBDocumentField};
+use crate::database::types::{DBMutexField, DBUuid, DateTime};
+use crate::database::{AqlBuilder, AqlLimit, AqlReturn, DBCollection, DBInfo, AQL_DOCUMENT_ID};
 use crate::error::{AppError, AppResult, INPUT_VALIDATION_UNDEFINED_BET_ERROR_CODE};
 
 lazy_static! {
@@ -57,6 +57,125 @@ impl BetCollection {
         Self::resolve_bet_or_reject(bet)
     }
 
+    /// Gets all the bets created in the blockchain that are not yet resolved.
+    pub async fn get_all_created() -> AppResult<Vec<BetDBDocument>> {
+        let collection = Self::instance();
+        collection
+            .get_many_by(
+                &BetDBDocumentField::State(None).path(),
+                &BetDBState::Created,
+                None,
+                None,
+            )
+            .await
+    }
+
+    /// Checks whether the participant had a bet created in the blockchain at `battle_time`.
+    /// The bets created before `expires_at` was stored only count while they are not resolved.
+    pub async fn exists_active_by_participant_at(
+        participant_key: &DBUuid,
+        battle_time: &DateTime,
+    ) -> AppResult<bool> {
+        let collection = Self::instance();
+        let battle_time = serde_json::to_string(battle_time).unwrap();
+
+        // FOR i IN <collection>
+        //     FILTER i.<participant> == <participant>
+        //          && i.<created_at> != null && i.<created_at> <= <battle_time>
+        //          && (i.<expires_at> == null ? i.<state> == <created> : i.<expires_at> >= <battle_time>)
+        //     LIMIT 1
+        //     RETURN i
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, Self::name());
+
+        aql.filter_step(
+            format!(
+                "{}.{} == {} && {}.{} != null && {}.{} <= {} && ({}.{} == null ? {}.{} == {} : {}.{} >= {})",
+                AQL_DOCUMENT_ID,
+                BetDBDocumentField::ParticipantKey(None).path(),
+                serde_json::to_string(participant_key).unwrap(),
+                AQL_DOCUMENT_ID,
+                BetDBDocumentField::CreatedAt(None).path(),
+                AQL_DOCUMENT_ID,
+                BetDBDocumentField::CreatedAt(None).path(),
+                battle_time,
+                AQL_DOCUMENT_ID,
+                BetDBDocumentField::ExpiresAt(None).path(),
+                AQL_DOCUMENT_ID,
+                BetDBDocumentField::State(None).path(),
+                serde_json::to_string(&BetDBState::Created).unwrap(),
+                AQL_DOCUMENT_ID,
+                BetDBDocumentField::ExpiresAt(None).path(),
+                battle_time
+            )
+            .into(),
+        );
+
+        aql.limit_step(AqlLimit {
+            offset: None,
+            count: 1,
+        });
+        aql.return_step(AqlReturn::new_document());
+
+        let result = collection.send_aql(&aql).await?;
+        Ok(!result.results.is_empty())
+    }
+
+    /// Checks whether the participant has won a bet in the challenge between `from` and `until`.
+    /// The bets won before `won_at` was stored are dated by their creation.
+    pub async fn exists_won_by_participant_and_challenge(
+        participant_key: &DBUuid,
+        challenge_key: &DBUuid,
+        from: &DateTime,
+        until: &DateTime,
+    ) -> AppResult<bool> {
+        let collection = Self::instance();
+        let won_at = format!(
+            "NOT_NULL({}.{}, {}.{})",
+            AQL_DOCUMENT_ID,
+            BetDBDocumentField::WonAt(None).path(),
+            AQL_DOCUMENT_ID,
+            BetDBDocumentField::CreatedAt(None).path()
+        );
+
+        // FOR i IN <collection>
+        //     FILTER i.<participant> == <participant> && i.<challenge> == <challenge>
+        //          && i.<state> == <won>
+        //          && NOT_NULL(i.<won_at>, i.<created_at>) >= <from>
+        //          && NOT_NULL(i.<won_at>, i.<created_at>) <= <until>
+        //     LIMIT 1
+        //     RETURN i
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, Self::name());
+
+        aql.filter_step(
+            format!(
+                "{}.{} == {} && {}.{} == {} && {}.{} == {} && {} >= {} && {} <= {}",
+                AQL_DOCUMENT_ID,
+                BetDBDocumentField::ParticipantKey(None).path(),
+                serde_json::to_string(participant_key).unwrap(),
+                AQL_DOCUMENT_ID,
+                BetDBDocumentField::ChallengeKey(None).path(),
+                serde_json::to_string(challenge_key).unwrap(),
+                AQL_DOCUMENT_ID,
+                BetDBDocumentField::State(None).path(),
+                serde_json::to_string(&BetDBState::Won).unwrap(),
+                won_at,
+                serde_json::to_string(from).unwrap(),
+                won_at,
+                serde_json::to_string(until).unwrap()
+            )
+            .into(),
+        );
+
+        aql.limit_step(AqlLimit {
+            offset: None,
+            count: 1,
+        });
+        aql.return_step(AqlReturn::new_document());
+
+        let result = collection.send_aql(&aql).await?;
+        Ok(!result.results.is_empty())
+    }
+
     fn resolve_bet_or_reject(bet: Option<BetDBDocument>) -> AppResult<BetDBDocument> {
         match bet {
             Some(v) => Ok(v),
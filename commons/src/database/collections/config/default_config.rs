@@ -9,6 +9,7 @@ use crate::database::documents::APIFilteringConfig;
 use crate::database::documents::APIFilteringStatsConfig;
 use crate::database::documents::CollectionsConfig;
 use crate::database::documents::ConfigurationCollectionConfig;
+use crate::database::documents::FraudConfig;
 use crate::database::documents::{ConfigDBDocument, DatabaseConfig};
 
 pub fn create_default_configuration(config: &Arc<InitServiceConfig>) -> ConfigDBDocument {
@@ -56,5 +57,6 @@ pub fn create_default_configuration(config: &Arc<InitServiceConfig>) -> ConfigDB
                 },
             },
         }),
+        fraud: Arc::new(FraudConfig::default()),
     }
 }
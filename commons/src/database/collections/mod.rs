@@ -4,6 +4,7 @@ use std::sync::Arc;
 use tokio::task::JoinHandle;
 
 pub use authentication::*;
+pub use battlelog::*;
 pub use bet::*;
 pub use challenge::*;
 pub use config::*;
@@ -20,6 +21,7 @@ use crate::database::{DBCollection, DBInfo};
 use crate::error::AppResult;
 
 mod authentication;
+mod battlelog;
 mod bet;
 pub(crate) mod cache;
 mod challenge;
@@ -39,6 +41,7 @@ pub enum CollectionKind {
     Signatures,
     Authentications,
     Emails,
+    Battlelogs,
 }
 
 impl CollectionKind {
@@ -54,6 +57,7 @@ impl CollectionKind {
             CollectionKind::Signatures => "Signatures",
             CollectionKind::Authentications => "Authentications",
             CollectionKind::Emails => "Emails",
+            CollectionKind::Battlelogs => "Battlelogs",
         }
     }
 
@@ -75,6 +79,7 @@ impl CollectionKind {
                 AuthenticationCollection::instance().drop_collection().await
             }
             CollectionKind::Emails => EmailCollection::instance().drop_collection().await,
+            CollectionKind::Battlelogs => BattlelogCollection::instance().drop_collection().await,
         }
     }
 
@@ -106,6 +111,9 @@ impl CollectionKind {
             CollectionKind::Emails => {
                 EmailCollection::init(db_info);
             }
+            CollectionKind::Battlelogs => {
+                BattlelogCollection::init(db_info);
+            }
         }
     }
 
@@ -121,6 +129,7 @@ impl CollectionKind {
                 AuthenticationCollection::create_collection(db_info).await
             }
             CollectionKind::Emails => EmailCollection::create_collection(db_info).await,
+            CollectionKind::Battlelogs => BattlelogCollection::create_collection(db_info).await,
         }
     }
 
@@ -143,6 +152,7 @@ impl CollectionKind {
             CollectionKind::Signatures => None,
             CollectionKind::Authentications => None,
             CollectionKind::Emails => None,
+            CollectionKind::Battlelogs => None,
         }
     }
 }
@@ -160,6 +170,7 @@ impl TryFrom<&str> for CollectionKind {
             "Signatures" => Ok(CollectionKind::Signatures),
             "Authentications" => Ok(CollectionKind::Authentications),
             "Emails" => Ok(CollectionKind::Emails),
+            "Battlelogs" => Ok(CollectionKind::Battlelogs),
             _ => Err(()),
         }
     }
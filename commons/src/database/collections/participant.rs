@@ -3,11 +3,22 @@ use std::sync::{Arc, Mutex};
 
 use arangors::index::{Index, IndexSettings};
 
-use crate::constants::DATABASE_MUTEX_INDEX;
+use arcstr::ArcStr;
+
+use crate::constants::{
+    DATABASE_MUTEX_INDEX, PARTICIPANT_BRAWL_STARS_TAG_INDEX, PARTICIPANT_CLASH_ROYALE_TAG_INDEX,
+};
 use crate::database::collections::CollectionKind;
-use crate::database::documents::{DBDocumentField, ParticipantDBDocument};
+use crate::database::documents::{
+    DBDocumentField, ParticipantBrawlStarsGameDataField, ParticipantClashRoyaleGameData,
+    ParticipantClashRoyaleGameDataField, ParticipantDBDocument, ParticipantDBDocumentField,
+    ParticipantGamesData, ParticipantGamesDataField,
+};
 use crate::database::types::{DBMutexField, DBUuid};
-use crate::database::{DBCollection, DBInfo};
+use crate::database::{
+    AqlBuilder, AqlLimit, AqlReturn, AqlUpdate, DBCollection, DBInfo, NullableOption,
+    AQL_DOCUMENT_ID,
+};
 use crate::error::{AppError, AppResult, INPUT_VALIDATION_UNDEFINED_PARTICIPANT_ERROR_CODE};
 
 lazy_static! {
@@ -55,6 +66,91 @@ impl ParticipantCollection {
         Self::resolve_participant_or_reject(participant)
     }
 
+    /// Gets all the participants that have linked any of `tags` in any game.
+    pub async fn get_all_by_game_tags(tags: &[ArcStr]) -> AppResult<Vec<ParticipantDBDocument>> {
+        let collection = Self::instance();
+
+        // FOR i IN <collection>
+        //     FILTER i.<clash_royale_tag> IN <tags> || i.<brawl_stars_tag> IN <tags>
+        //     RETURN i
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, Self::name());
+        let tags = serde_json::to_string(tags).unwrap();
+
+        aql.filter_step(
+            format!(
+                "{}.{} IN {} || {}.{} IN {}",
+                AQL_DOCUMENT_ID,
+                Self::clash_royale_tag_path(),
+                tags,
+                AQL_DOCUMENT_ID,
+                Self::brawl_stars_tag_path(),
+                tags
+            )
+            .into(),
+        );
+
+        aql.return_step(AqlReturn::new_document());
+
+        let result = collection.send_aql(&aql).await?;
+        Ok(result.results)
+    }
+
+    /// Marks the Clash Royale tag of the participant as verified only if it is still `tag`,
+    /// so that a tag changed meanwhile is never marked.
+    /// Missing: the participant does not exist or its tag has changed.
+    pub async fn verify_clash_royale_tag(
+        participant_key: &DBUuid,
+        tag: &ArcStr,
+    ) -> AppResult<Option<ParticipantDBDocument>> {
+        let collection = Self::instance();
+        let games_data = ParticipantGamesData {
+            clash_royale: NullableOption::Value(ParticipantClashRoyaleGameData {
+                verified: NullableOption::Value(true),
+                verification: NullableOption::Null,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // FOR i IN <collection>
+        //     FILTER i._key == <key> && i.<clash_royale_tag> == <tag>
+        //     LIMIT 1
+        //     UPDATE i WITH { <games_data>: <games_data> } IN <collection>
+        //     RETURN NEW
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, Self::name());
+
+        aql.filter_step(
+            format!(
+                "{}.{} == {} && {}.{} == {}",
+                AQL_DOCUMENT_ID,
+                DBDocumentField::Key.path(),
+                serde_json::to_string(participant_key).unwrap(),
+                AQL_DOCUMENT_ID,
+                Self::clash_royale_tag_path(),
+                serde_json::to_string(tag).unwrap()
+            )
+            .into(),
+        );
+
+        aql.limit_step(AqlLimit {
+            offset: None,
+            count: 1,
+        });
+        aql.update_step(AqlUpdate::new_document(
+            Self::name(),
+            format!(
+                "{{ {}: {} }}",
+                ParticipantDBDocumentField::GamesData(None).path(),
+                serde_json::to_string(&games_data).unwrap()
+            )
+            .into(),
+        ));
+        aql.return_step(AqlReturn::new_updated());
+
+        let mut result = collection.send_aql(&aql).await?;
+        Ok(result.results.pop())
+    }
+
     fn resolve_participant_or_reject(
         participant: Option<ParticipantDBDocument>,
     ) -> AppResult<ParticipantDBDocument> {
@@ -97,9 +193,55 @@ impl ParticipantCollection {
                 },
             )
             .await?;
+        database
+            .create_index(
+                collection_name,
+                &Index {
+                    name: PARTICIPANT_CLASH_ROYALE_TAG_INDEX.into(),
+                    fields: vec![Self::clash_royale_tag_path()],
+                    settings: IndexSettings::Persistent {
+                        unique: false,
+                        sparse: true,
+                        deduplicate: false,
+                    },
+                    ..Index::default()
+                },
+            )
+            .await?;
+        database
+            .create_index(
+                collection_name,
+                &Index {
+                    name: PARTICIPANT_BRAWL_STARS_TAG_INDEX.into(),
+                    fields: vec![Self::brawl_stars_tag_path()],
+                    settings: IndexSettings::Persistent {
+                        unique: false,
+                        sparse: true,
+                        deduplicate: false,
+                    },
+                    ..Index::default()
+                },
+            )
+            .await?;
 
         Ok(())
     }
+
+    fn clash_royale_tag_path() -> String {
+        ParticipantDBDocumentField::GamesData(Some(ParticipantGamesDataField::ClashRoyale(Some(
+            ParticipantClashRoyaleGameDataField::Tag(None),
+        ))))
+        .path()
+        .to_string()
+    }
+
+    fn brawl_stars_tag_path() -> String {
+        ParticipantDBDocumentField::GamesData(Some(ParticipantGamesDataField::BrawlStars(Some(
+            ParticipantBrawlStarsGameDataField::Tag(None),
+        ))))
+        .path()
+        .to_string()
+    }
 }
 
 impl DBCollection for ParticipantCollection {
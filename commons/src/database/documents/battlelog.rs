@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use arcstr::ArcStr;
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::clients::games::{BrawlStarsBattlelogResponse, ClashRoyaleBattlelogResponse};
+use crate::constants::BATTLELOG_EXPIRATION_DELAY;
+use crate::database::collections::BattlelogCollection;
+use crate::database::collections::CollectionKind;
+use crate::database::traits::AQLMapping;
+use crate::database::traits::DBNormalize;
+use crate::database::traits::DBNormalizeResult;
+use crate::database::types::{DBExpiration, DateTime};
+use crate::database::types::{DBId, DBUuid, DBUuidType};
+use crate::database::AqlBuilder;
+use crate::database::{DBDocument, NullableOption};
+use crate::error::AppResult;
+
+/// The char used to pad the tags in the keys of the Clash Royale matches.
+const CLASH_ROYALE_KEY_PADDING: char = '-';
+
+/// The char used to pad the tags in the keys of the Brawl Stars matches.
+const BRAWL_STARS_KEY_PADDING: char = '_';
+
+model!(
+    pub struct Battlelog {
+        /// The tag of the player that played the match, without the leading '#'.
+        #[db_name = "P"]
+        pub player_tag: NullableOption<ArcStr>,
+
+        /// The time the match was played.
+        #[db_name = "T"]
+        pub battle_time: NullableOption<DateTime>,
+
+        /// The Clash Royale match as returned by the API.
+        #[db_name = "CR"]
+        pub clash_royale: NullableOption<ClashRoyaleBattlelogResponse>,
+
+        /// The Brawl Stars match as returned by the API.
+        #[db_name = "BS"]
+        pub brawl_stars: NullableOption<BrawlStarsBattlelogResponse>,
+
+        /// The time this battlelog expires.
+        #[db_name = "X"]
+        pub db_expires_at: NullableOption<DBExpiration>,
+    }
+);
+
+impl BattlelogDBDocument {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds the document of a Clash Royale match. Its key depends only on the player
+    /// and the battle time so the same match is always stored once.
+    pub fn new_clash_royale(tag: &str, battlelog: ClashRoyaleBattlelogResponse) -> AppResult<Self> {
+        let tag = Self::normalize_tag(tag);
+        let battle_time = battlelog.battle_time.clone();
+        let db_key = Self::build_key(&tag, &battle_time, CLASH_ROYALE_KEY_PADDING)?;
+
+        Ok(BattlelogDBDocument {
+            db_key: Some(db_key),
+            player_tag: NullableOption::Value(tag.into()),
+            db_expires_at: NullableOption::Value(
+                battle_time.after_seconds(BATTLELOG_EXPIRATION_DELAY).into(),
+            ),
+            battle_time: NullableOption::Value(battle_time),
+            clash_royale: NullableOption::Value(battlelog),
+            ..Default::default()
+        })
+    }
+
+    /// Builds the document of a Brawl Stars match. Its key depends only on the player
+    /// and the battle time so the same match is always stored once.
+    pub fn new_brawl_stars(tag: &str, battlelog: BrawlStarsBattlelogResponse) -> AppResult<Self> {
+        let tag = Self::normalize_tag(tag);
+        let battle_time = battlelog.battle_time.clone();
+        let db_key = Self::build_key(&tag, &battle_time, BRAWL_STARS_KEY_PADDING)?;
+
+        Ok(BattlelogDBDocument {
+            db_key: Some(db_key),
+            player_tag: NullableOption::Value(tag.into()),
+            db_expires_at: NullableOption::Value(
+                battle_time.after_seconds(BATTLELOG_EXPIRATION_DELAY).into(),
+            ),
+            battle_time: NullableOption::Value(battle_time),
+            brawl_stars: NullableOption::Value(battlelog),
+            ..Default::default()
+        })
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Removes the leading '#' of a tag.
+    pub fn normalize_tag(tag: &str) -> &str {
+        tag.trim_start_matches('#')
+    }
+
+    /// Builds the key of the battlelog from the battle time and the tag padded with
+    /// `padding`, that is different for each game so their matches never collide.
+    fn build_key(tag: &str, battle_time: &DateTime, padding: char) -> AppResult<DBUuid> {
+        let width = DBUuidType::DBKey.random_chars();
+        let mut code: String = std::iter::repeat(padding)
+            .take(width.saturating_sub(tag.len()))
+            .collect();
+        code.push_str(tag);
+
+        DBUuid::new_with_date_and_code(battle_time.clone(), &code)
+    }
+}
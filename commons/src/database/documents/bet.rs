@@ -24,6 +24,7 @@ use crate::database::APIReference;
 use crate::database::AqlBuilder;
 use crate::database::DBReference;
 use crate::database::{DBDocument, NullableOption};
+use crate::games::{FraudSignal, GameSnapshot, MilestoneProgress};
 
 model!(
     #![sync_level = "document"]
@@ -62,14 +63,43 @@ model!(
         #[api_sensible_info]
         pub fungible_token_account_keypair: NullableOption<ArcStr>,
 
+        /// The data of the participant in each game when the bet was created.
+        /// Note: only present when any game requires it, e.g. to verify Clash Royale
+        /// achievement milestones.
+        #[db_name = "GS"]
+        #[api_sensible_info]
+        pub snapshots: NullableOption<Vec<GameSnapshot>>,
+
+        /// The evaluation of the challenge milestones in the last check, including
+        /// every condition checked against each match.
+        /// Note: only present when the bet has been checked at least once.
+        #[db_name = "E"]
+        pub evaluation: NullableOption<MilestoneProgress>,
+
+        /// The signs of collusion found when the bet was won.
+        /// Note: only present when the bet is under review.
+        #[db_name = "FS"]
+        #[api_sensible_info]
+        pub fraud_signals: NullableOption<Vec<FraudSignal>>,
+
         /// The won NFT.
         #[db_name = "N"]
         pub won_nft: NullableOption<Address>,
 
+        /// The time this bet was won.
+        /// Note: only present when the bet is won.
+        #[db_name = "W"]
+        pub won_at: NullableOption<DateTime>,
+
         /// The time this bet expires.
         #[db_name = "T"]
         pub created_at: NullableOption<DateTime>,
 
+        /// The time the bet expires in the blockchain.
+        /// Note: only present when the bet has been created in the blockchain.
+        #[db_name = "XA"]
+        pub expires_at: NullableOption<DateTime>,
+
         /// The time this bet expires.
         #[db_name = "X"]
         #[api_sensible_info]
@@ -101,6 +131,11 @@ sub_model!(
         #[db_name = "L"]
         Lost,
 
+        /// The bet was won but it must be reviewed before paying the reward because
+        /// of signs of collusion.
+        #[db_name = "R"]
+        UnderReview,
+
         /// The bet is expired but it is not in reflected in the blockchain.
         #[db_name = "X"]
         ExpiredNotInBlockchain,
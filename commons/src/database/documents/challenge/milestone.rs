@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
 use arcstr::ArcStr;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -9,13 +10,17 @@ use crate::database::traits::AQLMapping;
 use crate::database::traits::DBNormalize;
 use crate::database::traits::DBNormalizeResult;
 use crate::database::types::game::GameMilestone;
+use crate::database::types::CompositeMilestoneKind;
 use crate::database::APIReference;
+use crate::database::APIReferenceKey;
 use crate::database::AqlBuilder;
 use crate::database::DBReference;
 
 sub_model!(
     #![build_api]
 
+    #[api_attr(derive(JsonSchema))]
+    #[api_attr(schemars(rename = "ChallengeMilestone"))]
     pub enum ChallengeMilestone {
         /// A milestone related to a specific game.
         #[db_name = "G"]
@@ -26,16 +31,71 @@ sub_model!(
         #[db_name = "O"]
         #[api_inner_type = "APIOtherChallengeMilestone"]
         OtherChallenge(OtherChallengeMilestone),
+
+        /// A combination of other milestones.
+        #[db_name = "C"]
+        #[api_inner_type = "APICompositeChallengeMilestone"]
+        Composite(CompositeChallengeMilestone),
     }
 );
 
+impl ChallengeMilestone {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Gets all the non-composite milestones, including the nested ones.
+    pub fn leaves(&self) -> Vec<&ChallengeMilestone> {
+        match self {
+            ChallengeMilestone::Composite(v) => {
+                v.milestones.iter().flat_map(|v| v.leaves()).collect()
+            }
+            _ => vec![self],
+        }
+    }
+
+    /// Gets the non-composite milestones that must always be completed, i.e. excluding those
+    /// nested in optional branches.
+    pub fn required_leaves(&self) -> Vec<&ChallengeMilestone> {
+        match self {
+            ChallengeMilestone::Composite(v) => match v.kind {
+                CompositeMilestoneKind::AllOf | CompositeMilestoneKind::Sequence => v
+                    .milestones
+                    .iter()
+                    .flat_map(|v| v.required_leaves())
+                    .collect(),
+                CompositeMilestoneKind::AnyOf | CompositeMilestoneKind::AtLeast(_) => vec![],
+            },
+            _ => vec![self],
+        }
+    }
+
+    pub fn game_milestone(&self) -> Option<&GameMilestone> {
+        match self {
+            ChallengeMilestone::GameMilestone(v) => Some(&v.milestone),
+            _ => None,
+        }
+    }
+}
+
 impl APIChallengeMilestone {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Gets all the non-composite milestones, including the nested ones.
+    pub fn leaves(&self) -> Vec<&APIChallengeMilestone> {
+        match self {
+            APIChallengeMilestone::Composite(v) => {
+                v.milestones.iter().flat_map(|v| v.leaves()).collect()
+            }
+            _ => vec![self],
+        }
+    }
+
     // METHODS ----------------------------------------------------------------
 
     pub fn validate(&self) -> Option<ArcStr> {
         match self {
             APIChallengeMilestone::GameMilestone(v) => v.validate(),
             APIChallengeMilestone::OtherChallenge(v) => v.validate(),
+            APIChallengeMilestone::Composite(v) => v.validate(),
         }
     }
 }
@@ -47,6 +107,7 @@ impl APIChallengeMilestone {
 sub_model!(
     #![build_api]
 
+    #[api_attr(derive(JsonSchema))]
     pub struct GameChallengeMilestone {
         #[db_name = "M"]
         pub milestone: GameMilestone,
@@ -68,9 +129,12 @@ impl APIGameChallengeMilestone {
 sub_model!(
     #![build_api]
 
+    #[api_attr(derive(JsonSchema))]
     pub struct OtherChallengeMilestone {
+        // Only references by key are described in the schema.
         #[db_name = "C"]
         #[api_inner_type = "ChallengeAPIDocument"]
+        #[api_attr(schemars(with = "APIReferenceKey"))]
         pub challenge: DBReference<ChallengeDBDocument>,
     }
 );
@@ -82,3 +146,43 @@ impl APIOtherChallengeMilestone {
         None
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+sub_model!(
+    #![build_api]
+
+    #[api_attr(derive(JsonSchema))]
+    pub struct CompositeChallengeMilestone {
+        /// How the milestones are combined.
+        #[db_name = "K"]
+        pub kind: CompositeMilestoneKind,
+
+        /// The combined milestones.
+        #[db_name = "M"]
+        #[inner_model = "struct"]
+        #[api_inner_type = "APIChallengeMilestone"]
+        #[api_attr(schemars(length(min = 1)))]
+        pub milestones: Vec<ChallengeMilestone>,
+    }
+);
+
+impl APICompositeChallengeMilestone {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self) -> Option<ArcStr> {
+        if let Some(error) = self.kind.validate(self.milestones.len()) {
+            return Some(error);
+        }
+
+        for milestone in &self.milestones {
+            if let Some(error) = milestone.validate() {
+                return Some(error);
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The signals used to detect collusion when verifying the bets.
+/// A won bet that raises any signal is kept under review instead of being paid.
+///
+/// The missing fields take the values of `FraudConfig::default`, so the signals are only
+/// checked when the configuration enables them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FraudConfig {
+    /// Whether the signals are checked or not.
+    pub enabled: bool,
+
+    /// The maximum number of matches of the bet played against the same opponent.
+    /// Null: the signal is disabled.
+    pub max_matches_per_opponent: Option<u32>,
+
+    /// Whether to flag the matches played against opponents that have an active bet too.
+    pub check_opponent_bets: bool,
+
+    /// The maximum difference of starting trophies between the team and the opponent.
+    /// Null: the signal is disabled.
+    pub max_trophy_gap: Option<u64>,
+
+    /// The minimum time between two consecutive matches of the bet, in seconds.
+    /// Null: the signal is disabled.
+    pub min_seconds_between_matches: Option<u64>,
+}
+
+impl Default for FraudConfig {
+    fn default() -> Self {
+        FraudConfig {
+            enabled: false,
+            max_matches_per_opponent: Some(2),
+            check_opponent_bets: true,
+            max_trophy_gap: Some(1500),
+            min_seconds_between_matches: Some(60),
+        }
+    }
+}
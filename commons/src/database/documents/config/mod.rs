@@ -8,6 +8,7 @@ use serde::Serialize;
 
 pub use api::*;
 pub use database::*;
+pub use fraud::*;
 
 use crate::database::collections::{CollectionKind, ConfigCollection};
 use crate::database::document::DBDocument;
@@ -17,6 +18,7 @@ use crate::database::types::{DBId, DBUuid};
 
 mod api;
 mod database;
+mod fraud;
 
 model!(
     #![collection_kind = "Configuration"]
@@ -27,5 +29,9 @@ model!(
 
         #[skip_normalize]
         pub api: Arc<APIConfig>,
+
+        #[skip_normalize]
+        #[db_attr(serde(default))]
+        pub fraud: Arc<FraudConfig>,
     }
 );
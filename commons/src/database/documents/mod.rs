@@ -4,6 +4,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 pub use authentication::*;
+pub use battlelog::*;
 pub use bet::*;
 pub use challenge::*;
 pub use config::*;
@@ -13,6 +14,7 @@ pub use participant::*;
 pub use signature::*;
 
 mod authentication;
+mod battlelog;
 mod bet;
 mod challenge;
 mod config;
@@ -1,19 +1,189 @@
 use std::borrow::Cow;
+use std::collections::HashSet;
+use std::convert::TryFrom;
 
 use arcstr::ArcStr;
+use rand::seq::SliceRandom;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::clients::games::ClashRoyalePlayerInfoResponse;
+use crate::data::games::ClashRoyaleCard;
 use crate::database::traits::AQLMapping;
 use crate::database::traits::DBNormalize;
 use crate::database::traits::DBNormalizeResult;
+use crate::database::types::DateTime;
+use crate::database::AqlBuilder;
 use crate::database::NullableOption;
 
+/// The number of cards of a deck.
+const DECK_SIZE: usize = 8;
+
 sub_model!(
     #![build_api]
 
     pub struct ParticipantClashRoyaleGameData {
         #[db_name = "T"]
         pub tag: NullableOption<ArcStr>,
+
+        /// Whether the participant has proven to own the tag.
+        #[db_name = "V"]
+        pub verified: NullableOption<bool>,
+
+        /// The pending proof of the ownership of the tag.
+        #[db_name = "P"]
+        #[inner_model = "struct"]
+        #[api_inner_type = "APIClashRoyaleTagVerification"]
+        pub verification: NullableOption<ClashRoyaleTagVerification>,
     }
 );
+
+impl ParticipantClashRoyaleGameData {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn is_verified(&self) -> bool {
+        matches!(self.verified, NullableOption::Value(true))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+sub_model!(
+    #![build_api]
+
+    pub struct ClashRoyaleTagVerification {
+        /// The tag to verify.
+        #[db_name = "T"]
+        pub tag: NullableOption<ArcStr>,
+
+        /// What the participant must set in the game.
+        #[db_name = "K"]
+        pub kind: NullableOption<ClashRoyaleTagVerificationKind>,
+
+        /// The favourite card or the cards of the deck the participant must set.
+        #[db_name = "C"]
+        pub cards: NullableOption<Vec<ClashRoyaleCard>>,
+
+        /// The time the verification expires.
+        #[db_name = "X"]
+        pub expires_at: NullableOption<DateTime>,
+    }
+);
+
+impl ClashRoyaleTagVerification {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a verification with random cards of the player that are not already set,
+    /// or `None` if the player does not have enough cards.
+    pub fn new_random(
+        tag: ArcStr,
+        kind: ClashRoyaleTagVerificationKind,
+        player: &ClashRoyalePlayerInfoResponse,
+        expires_at: DateTime,
+    ) -> Option<Self> {
+        let current_cards = Self::current_cards(kind, player);
+        let candidates: Vec<_> = player
+            .cards
+            .iter()
+            .flatten()
+            .filter_map(|v| ClashRoyaleCard::try_from(v.id).ok())
+            .collect();
+
+        let mut rng = rand::thread_rng();
+        let cards = match kind {
+            ClashRoyaleTagVerificationKind::FavouriteCard => {
+                let candidates: Vec<_> = candidates
+                    .into_iter()
+                    .filter(|v| !current_cards.contains(&(*v as u64)))
+                    .collect();
+
+                vec![*candidates.choose(&mut rng)?]
+            }
+            ClashRoyaleTagVerificationKind::Deck => {
+                if candidates.len() < DECK_SIZE {
+                    return None;
+                }
+
+                // Retry a few times in case it matches the current deck.
+                (0..10)
+                    .map(|_| {
+                        candidates
+                            .choose_multiple(&mut rng, DECK_SIZE)
+                            .copied()
+                            .collect::<Vec<_>>()
+                    })
+                    .find(|cards| cards.iter().any(|v| !current_cards.contains(&(*v as u64))))?
+            }
+        };
+
+        Some(ClashRoyaleTagVerification {
+            tag: NullableOption::Value(tag),
+            kind: NullableOption::Value(kind),
+            cards: NullableOption::Value(cards),
+            expires_at: NullableOption::Value(expires_at),
+        })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn is_expired(&self) -> bool {
+        match &self.expires_at {
+            NullableOption::Value(v) => v.is_expired(),
+            _ => true,
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Checks whether the player has set the required cards.
+    pub fn verify(&self, player: &ClashRoyalePlayerInfoResponse) -> bool {
+        let (kind, cards) = match (&self.kind, &self.cards) {
+            (NullableOption::Value(kind), NullableOption::Value(cards)) => (*kind, cards),
+            _ => return false,
+        };
+
+        let current_cards = Self::current_cards(kind, player);
+        let required_cards: HashSet<u64> = cards.iter().map(|v| *v as u64).collect();
+
+        !required_cards.is_empty() && current_cards == required_cards
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    fn current_cards(
+        kind: ClashRoyaleTagVerificationKind,
+        player: &ClashRoyalePlayerInfoResponse,
+    ) -> HashSet<u64> {
+        match kind {
+            ClashRoyaleTagVerificationKind::FavouriteCard => {
+                player.current_favourite_card.iter().map(|v| v.id).collect()
+            }
+            ClashRoyaleTagVerificationKind::Deck => {
+                player.current_deck.iter().flatten().map(|v| v.id).collect()
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// What the participant must set in the game to prove the ownership of a tag.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ClashRoyaleTagVerificationKind {
+    /// Set a specific card as the favourite one.
+    FavouriteCard,
+
+    /// Set a specific deck as the current one, in any order.
+    Deck,
+}
+
+impl DBNormalize for ClashRoyaleTagVerificationKind {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use serde::Deserialize;
 use serde::Serialize;
 
+pub use brawl_stars::*;
 pub use clash_royale::*;
 
 use crate::database::traits::AQLMapping;
@@ -11,6 +12,7 @@ use crate::database::traits::DBNormalizeResult;
 use crate::database::AqlBuilder;
 use crate::database::NullableOption;
 
+mod brawl_stars;
 mod clash_royale;
 
 sub_model!(
@@ -21,5 +23,10 @@ sub_model!(
         #[inner_model = "struct"]
         #[api_inner_type = "APIParticipantClashRoyaleGameData"]
         pub clash_royale: NullableOption<ParticipantClashRoyaleGameData>,
+
+        #[db_name = "BS"]
+        #[inner_model = "struct"]
+        #[api_inner_type = "APIParticipantBrawlStarsGameData"]
+        pub brawl_stars: NullableOption<ParticipantBrawlStarsGameData>,
     }
 );
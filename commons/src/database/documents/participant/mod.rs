@@ -32,3 +32,17 @@ model!(
         pub games_data: NullableOption<ParticipantGamesData>,
     }
 );
+
+impl ParticipantDBDocument {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn clash_royale_data(&self) -> Option<&ParticipantClashRoyaleGameData> {
+        match &self.games_data {
+            NullableOption::Value(v) => match &v.clash_royale {
+                NullableOption::Value(v) => Some(v),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
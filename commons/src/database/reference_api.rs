@@ -1,3 +1,4 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::database::types::DBUuid;
@@ -12,8 +13,9 @@ pub enum APIReference<T: APIDocument> {
     Key(APIReferenceKey),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct APIReferenceKey {
+    #[schemars(with = "String")]
     id: DBUuid,
 }
 
@@ -418,16 +418,21 @@ impl<'de> Deserialize<'de> for DayTime {
             where
                 E: de::Error,
             {
-                Ok(DayTime(chrono::NaiveTime::from_num_seconds_from_midnight(
-                    value as u32,
-                    0,
-                )))
+                if value < 0 {
+                    return Err(E::custom("the day time cannot be negative"));
+                }
+
+                self.visit_u64(value as u64)
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
+                if value >= 86_400 {
+                    return Err(E::custom("the day time must be lower than 86400 seconds"));
+                }
+
                 Ok(DayTime(chrono::NaiveTime::from_num_seconds_from_midnight(
                     value as u32,
                     0,
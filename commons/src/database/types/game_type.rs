@@ -6,6 +6,7 @@ use crate::database::traits::{DBNormalize, DBNormalizeResult};
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum GameType {
     ClashRoyale,
+    BrawlStars,
 }
 
 impl DBNormalize for GameType {
@@ -0,0 +1,50 @@
+use arcstr::ArcStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::database::traits::{DBNormalize, DBNormalizeResult};
+
+/// How the milestones of a composite milestone are combined.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", content = "value")]
+#[serde(rename_all = "camelCase")]
+pub enum CompositeMilestoneKind {
+    /// All milestones must be completed.
+    AllOf,
+
+    /// At least one milestone must be completed.
+    AnyOf,
+
+    /// At least the specified number of milestones must be completed.
+    AtLeast(#[schemars(range(min = 1))] u32),
+
+    /// All milestones must be completed in order, i.e. each one after the previous one.
+    Sequence,
+}
+
+impl CompositeMilestoneKind {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self, milestone_count: usize) -> Option<ArcStr> {
+        if milestone_count == 0 {
+            return Some(arcstr::literal!("Composite milestone without milestones"));
+        }
+
+        if let CompositeMilestoneKind::AtLeast(count) = self {
+            if *count == 0 || *count as usize > milestone_count {
+                return Some(arcstr::literal!(
+                    "Composite milestone with an incorrect 'at least' count"
+                ));
+            }
+        }
+
+        None
+    }
+}
+
+impl DBNormalize for CompositeMilestoneKind {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
@@ -0,0 +1,68 @@
+use arcstr::ArcStr;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The result of evaluating a single condition against some game data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionCheck {
+    /// The path of the condition inside the milestone, e.g. `team.allowedCards[2].level`.
+    pub condition: ArcStr,
+
+    pub passed: bool,
+
+    /// The condition that was evaluated.
+    /// Missing: the condition has no value to show, e.g. it requires data to be present.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<serde_json::Value>,
+
+    /// The value found in the game data.
+    /// Missing: the value is not present in the game data.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual: Option<serde_json::Value>,
+}
+
+impl ConditionCheck {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(condition: &str, passed: bool) -> Self {
+        ConditionCheck {
+            condition: ArcStr::from(condition),
+            passed,
+            expected: None,
+            actual: None,
+        }
+    }
+
+    pub fn new_with_values<E: Serialize, A: Serialize>(
+        condition: &str,
+        passed: bool,
+        expected: &E,
+        actual: Option<&A>,
+    ) -> Self {
+        ConditionCheck {
+            condition: ArcStr::from(condition),
+            passed,
+            expected: serde_json::to_value(expected).ok(),
+            actual: actual.and_then(|v| serde_json::to_value(v).ok()),
+        }
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// The path of a nested condition, i.e. `name` if `path` is the root.
+    pub fn join_path(path: &str, name: &str) -> String {
+        if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", path, name)
+        }
+    }
+
+    /// Whether all the checks passed.
+    pub fn all_passed(checks: &[ConditionCheck]) -> bool {
+        checks.iter().all(|v| v.passed)
+    }
+}
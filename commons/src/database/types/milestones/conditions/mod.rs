@@ -1,5 +1,9 @@
+pub use check::*;
 pub use option::*;
 pub use ordered::*;
+pub use time_window::*;
 
+mod check;
 mod option;
 mod ordered;
+mod time_window;
@@ -1,7 +1,8 @@
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "condition", content = "value")]
 pub enum OptionCondition<T: PartialEq> {
     #[serde(rename = "anyOf")]
@@ -0,0 +1,165 @@
+use arcstr::ArcStr;
+use chrono::{Datelike, FixedOffset};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use enum_derive::EnumList;
+
+use crate::database::types::conditions::OptionCondition;
+use crate::database::types::{DateTime, DayTime};
+
+/// The maximum offset of a timezone in minutes, i.e. 14 hours.
+pub(crate) const MAX_UTC_OFFSET: i32 = 14 * 60;
+
+/// The minimum offset of a timezone in minutes.
+pub(crate) const MIN_UTC_OFFSET: i32 = -MAX_UTC_OFFSET;
+
+/// A time window that restricts when a match can be played.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeWindowCondition {
+    /// The offset of the timezone in which the window is defined, in minutes from UTC.
+    /// Missing: UTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = "MIN_UTC_OFFSET", max = "MAX_UTC_OFFSET"))]
+    pub utc_offset: Option<i32>,
+
+    /// The day time ranges in which the match must start.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1))]
+    pub day_times: Option<Vec<DayTimeRange>>,
+
+    /// The day(s) of the week (dis)allowed to start the match. Within a day time range that
+    /// wraps midnight, the day is the one the range started, e.g. Friday for a match on
+    /// Saturday at 01:00 in a range from 22:00 to 02:00.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekdays: Option<OptionCondition<Weekday>>,
+}
+
+impl TimeWindowCondition {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self) -> Option<ArcStr> {
+        if let Some(utc_offset) = self.utc_offset {
+            if !(MIN_UTC_OFFSET..=MAX_UTC_OFFSET).contains(&utc_offset) {
+                return Some(arcstr::literal!(
+                    "The UTC offset of a time window must be between -840 and 840 minutes"
+                ));
+            }
+        }
+
+        if let Some(day_times) = &self.day_times {
+            if day_times.is_empty() {
+                return Some(arcstr::literal!("Time window without day time ranges"));
+            }
+
+            if day_times.iter().any(|v| v.from == v.to) {
+                return Some(arcstr::literal!("Time window with an empty day time range"));
+            }
+        }
+
+        None
+    }
+
+    /// Verifies the condition against a moment, usually the battle time.
+    pub fn verify(&self, time: &DateTime) -> bool {
+        let local_time = self.local_time(time);
+        let day_time = local_time.time();
+        let weekday = local_time.weekday();
+
+        match &self.day_times {
+            Some(day_times) => day_times.iter().any(|v| {
+                v.contains(&day_time) && self.verify_weekday(v.start_weekday(&day_time, weekday))
+            }),
+            None => self.verify_weekday(weekday),
+        }
+    }
+
+    /// Converts a moment to the timezone of the window.
+    pub fn local_time(&self, time: &DateTime) -> chrono::DateTime<FixedOffset> {
+        let utc_offset = self
+            .utc_offset
+            .unwrap_or(0)
+            .clamp(MIN_UTC_OFFSET, MAX_UTC_OFFSET);
+        time.0.with_timezone(&FixedOffset::east(utc_offset * 60))
+    }
+
+    fn verify_weekday(&self, weekday: chrono::Weekday) -> bool {
+        match &self.weekdays {
+            Some(weekdays) => weekdays.verify(&weekday.into()),
+            None => true,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A range of the day, `from` included and `to` excluded.
+/// When `from` is later than `to` the range wraps midnight, e.g. 22:00 to 02:00.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DayTimeRange {
+    pub from: DayTime,
+    pub to: DayTime,
+}
+
+impl DayTimeRange {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn contains(&self, time: &chrono::NaiveTime) -> bool {
+        if self.from.0 <= self.to.0 {
+            self.from.0 <= *time && *time < self.to.0
+        } else {
+            self.from.0 <= *time || *time < self.to.0
+        }
+    }
+
+    /// The day the range containing `time` of `weekday` started, i.e. the previous day
+    /// when the range wraps midnight and `time` is after it.
+    pub fn start_weekday(
+        &self,
+        time: &chrono::NaiveTime,
+        weekday: chrono::Weekday,
+    ) -> chrono::Weekday {
+        if self.from.0 > self.to.0 && *time < self.to.0 {
+            weekday.pred()
+        } else {
+            weekday
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, EnumList)]
+#[serde(rename_all = "camelCase")]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<chrono::Weekday> for Weekday {
+    fn from(v: chrono::Weekday) -> Self {
+        match v {
+            chrono::Weekday::Mon => Weekday::Monday,
+            chrono::Weekday::Tue => Weekday::Tuesday,
+            chrono::Weekday::Wed => Weekday::Wednesday,
+            chrono::Weekday::Thu => Weekday::Thursday,
+            chrono::Weekday::Fri => Weekday::Friday,
+            chrono::Weekday::Sat => Weekday::Saturday,
+            chrono::Weekday::Sun => Weekday::Sunday,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use enum_derive::EnumList;
+
+use crate::database::traits::{DBNormalize, DBNormalizeResult};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, EnumList)]
+#[serde(rename_all = "camelCase")]
+pub enum BrawlStarsGameResult {
+    Victory,
+    Defeat,
+    Draw,
+}
+
+impl DBNormalize for BrawlStarsGameResult {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
@@ -0,0 +1,169 @@
+use std::convert::TryFrom;
+
+use arcstr::ArcStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::clients::games::BrawlStarsBattlelogResponse;
+use crate::data::games::{BrawlStarsBrawler, BrawlStarsGameMode};
+use crate::database::types::conditions::{ConditionCheck, OptionCondition, OrderedCondition};
+use crate::database::types::game::BrawlStarsGameResult;
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsMatchConditions {
+    /// Whether player must win or not the match.
+    /// In showdown modes, the result depends on the final rank like the trophies do.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<OptionCondition<BrawlStarsGameResult>>,
+
+    /// The game mode(s) (dis)allowed of the match.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_mode: Option<OptionCondition<BrawlStarsGameMode>>,
+
+    /// The brawler(s) (dis)allowed to play the match with.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brawler: Option<OptionCondition<BrawlStarsBrawler>>,
+
+    /// The trophies of the player's brawler when the match started.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trophies: Option<OrderedCondition<u64>>,
+}
+
+impl BrawlStarsMatchConditions {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self) -> Option<ArcStr> {
+        None
+    }
+
+    pub fn verify_response(&self, battlelog: &BrawlStarsBattlelogResponse, tag: &str) -> bool {
+        ConditionCheck::all_passed(&self.check_response(battlelog, tag))
+    }
+
+    /// Evaluates each condition against the match of the player with `tag` independently.
+    pub fn check_response(
+        &self,
+        battlelog: &BrawlStarsBattlelogResponse,
+        tag: &str,
+    ) -> Vec<ConditionCheck> {
+        let battle = &battlelog.battle;
+        let player = match battle.find_player(tag) {
+            Some(v) => v,
+            None => return vec![ConditionCheck::new("player", false)],
+        };
+
+        let game_mode = battle
+            .mode
+            .as_ref()
+            .or_else(|| battlelog.event.as_ref().and_then(|v| v.mode.as_ref()))
+            .and_then(|v| BrawlStarsGameMode::try_from(v.as_str()).ok());
+
+        let mut checks = Vec::new();
+
+        if let Some(result) = &self.result {
+            let actual_result = Self::get_result(battlelog, game_mode);
+            let passed = match &actual_result {
+                Some(v) => result.verify(v),
+                None => false,
+            };
+
+            checks.push(ConditionCheck::new_with_values(
+                "result",
+                passed,
+                result,
+                actual_result.as_ref(),
+            ));
+        }
+
+        if let Some(game_mode_option) = &self.game_mode {
+            let passed = match &game_mode {
+                Some(v) => game_mode_option.verify(v),
+                None => false,
+            };
+
+            checks.push(ConditionCheck::new_with_values(
+                "gameMode",
+                passed,
+                game_mode_option,
+                game_mode.as_ref(),
+            ));
+        }
+
+        if let Some(brawler) = &self.brawler {
+            let brawler_id = player
+                .brawler
+                .as_ref()
+                .and_then(|v| BrawlStarsBrawler::try_from(v.id).ok());
+            let passed = match &brawler_id {
+                Some(v) => brawler.verify(v),
+                None => false,
+            };
+
+            checks.push(ConditionCheck::new_with_values(
+                "brawler",
+                passed,
+                brawler,
+                brawler_id.as_ref(),
+            ));
+        }
+
+        if let Some(trophies) = &self.trophies {
+            let actual_trophies = player.brawler.as_ref().and_then(|v| v.trophies);
+            let passed = match &actual_trophies {
+                Some(v) => trophies.verify(v),
+                None => false,
+            };
+
+            checks.push(ConditionCheck::new_with_values(
+                "trophies",
+                passed,
+                trophies,
+                actual_trophies.as_ref(),
+            ));
+        }
+
+        checks
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    fn get_result(
+        battlelog: &BrawlStarsBattlelogResponse,
+        game_mode: Option<BrawlStarsGameMode>,
+    ) -> Option<BrawlStarsGameResult> {
+        let battle = &battlelog.battle;
+
+        if let Some(result) = &battle.result {
+            return match result.as_str() {
+                "victory" => Some(BrawlStarsGameResult::Victory),
+                "defeat" => Some(BrawlStarsGameResult::Defeat),
+                "draw" => Some(BrawlStarsGameResult::Draw),
+                _ => None,
+            };
+        }
+
+        // Showdown modes only report the rank.
+        let rank = battle.rank?;
+        let (victory_max_rank, draw_rank) = match game_mode? {
+            BrawlStarsGameMode::SoloShowdown => (4, 5),
+            BrawlStarsGameMode::DuoShowdown => (2, 3),
+            _ => return None,
+        };
+
+        let result = if rank <= victory_max_rank {
+            BrawlStarsGameResult::Victory
+        } else if rank == draw_rank {
+            BrawlStarsGameResult::Draw
+        } else {
+            BrawlStarsGameResult::Defeat
+        };
+
+        Some(result)
+    }
+}
@@ -0,0 +1,37 @@
+use arcstr::ArcStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub use game_result::*;
+pub use match_conditions::*;
+pub use win_matches::*;
+
+use crate::database::traits::{DBNormalize, DBNormalizeResult};
+
+mod game_result;
+mod match_conditions;
+mod win_matches;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", content = "params")]
+#[serde(rename_all = "camelCase")]
+pub enum BrawlStarsMilestone {
+    WinMatches(BrawlStarsWinMatches),
+}
+
+impl BrawlStarsMilestone {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self) -> Option<ArcStr> {
+        match self {
+            BrawlStarsMilestone::WinMatches(params) => params.validate(),
+        }
+    }
+}
+
+impl DBNormalize for BrawlStarsMilestone {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
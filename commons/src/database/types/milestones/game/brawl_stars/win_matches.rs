@@ -0,0 +1,107 @@
+use arcstr::ArcStr;
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::database::types::game::{
+    BrawlStarsMatchConditions, NonQualifyingMatchPolicy, WinMatchesMode,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "BrawlStarsWinMatchesFormat")]
+#[serde(rename_all = "camelCase")]
+pub struct BrawlStarsWinMatches {
+    /// How the matches are paired with the conditions.
+    /// Missing: first N.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<WinMatchesMode>,
+
+    /// What to do with the matches that do not fulfill the next condition.
+    /// Missing: ignore.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_qualifying: Option<NonQualifyingMatchPolicy>,
+
+    /// The conditions of each match in order.
+    pub matches: Vec<BrawlStarsMatchConditions>,
+}
+
+impl BrawlStarsWinMatches {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self) -> Option<ArcStr> {
+        if self.matches.is_empty() {
+            return Some(arcstr::literal!(
+                "Brawl Stars 'win matches' milestone without conditions"
+            ));
+        }
+
+        for condition in &self.matches {
+            if let Some(error) = condition.validate() {
+                return Some(error);
+            }
+        }
+
+        None
+    }
+}
+
+impl From<Vec<BrawlStarsMatchConditions>> for BrawlStarsWinMatches {
+    fn from(matches: Vec<BrawlStarsMatchConditions>) -> Self {
+        BrawlStarsWinMatches {
+            mode: None,
+            non_qualifying: None,
+            matches,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Accepts the legacy format, i.e. the list of conditions alone, besides the current one.
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum BrawlStarsWinMatchesFormat {
+    /// Legacy format: the conditions of each match alone.
+    Legacy(#[schemars(length(min = 1))] Vec<BrawlStarsMatchConditions>),
+    Current {
+        mode: Option<WinMatchesMode>,
+
+        #[serde(rename = "nonQualifying")]
+        non_qualifying: Option<NonQualifyingMatchPolicy>,
+
+        #[schemars(length(min = 1))]
+        matches: Vec<BrawlStarsMatchConditions>,
+    },
+}
+
+// The schema describes every accepted format.
+impl JsonSchema for BrawlStarsWinMatches {
+    fn schema_name() -> String {
+        "BrawlStarsWinMatches".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        BrawlStarsWinMatchesFormat::json_schema(generator)
+    }
+}
+
+impl From<BrawlStarsWinMatchesFormat> for BrawlStarsWinMatches {
+    fn from(format: BrawlStarsWinMatchesFormat) -> Self {
+        match format {
+            BrawlStarsWinMatchesFormat::Legacy(matches) => matches.into(),
+            BrawlStarsWinMatchesFormat::Current {
+                mode,
+                non_qualifying,
+                matches,
+            } => BrawlStarsWinMatches {
+                mode,
+                non_qualifying,
+                matches,
+            },
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use enum_derive::EnumList;
+
+use crate::database::traits::{DBNormalize, DBNormalizeResult};
+
+/// The kind of a battle, parsed from the `type` and `gameMode` fields of the battlelog.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, EnumList)]
+#[serde(rename_all = "camelCase")]
+pub enum ClashRoyaleBattleKind {
+    /// 1v1 ladder battles.
+    Ladder,
+    /// 2v2 matchmaking battles.
+    TwoVsTwo,
+    PathOfLegends,
+    Challenge,
+    Tournament,
+    /// Includes the old clan wars and the river races.
+    ClanWar,
+    BoatBattle,
+    /// Includes the battles between clan mates.
+    Friendly,
+    Casual,
+    /// Any kind not supported yet.
+    Unknown,
+}
+
+impl ClashRoyaleBattleKind {
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses the kind from the `type` and `gameMode.name` fields of a battlelog.
+    pub fn parse(kind: &str, game_mode: Option<&str>) -> Self {
+        match kind {
+            "PvP" => match game_mode {
+                Some(v) if v.contains("2v2") || v.starts_with("TeamVsTeam") => {
+                    ClashRoyaleBattleKind::TwoVsTwo
+                }
+                _ => ClashRoyaleBattleKind::Ladder,
+            },
+            "pathOfLegend" => ClashRoyaleBattleKind::PathOfLegends,
+            "challenge" => ClashRoyaleBattleKind::Challenge,
+            "tournament" => ClashRoyaleBattleKind::Tournament,
+            "boatBattle" => ClashRoyaleBattleKind::BoatBattle,
+            "casual1v1" | "casual2v2" => ClashRoyaleBattleKind::Casual,
+            _ if kind.starts_with("friendly") || kind.starts_with("clanMate") => {
+                ClashRoyaleBattleKind::Friendly
+            }
+            _ if kind.starts_with("clanWar") || kind.starts_with("riverRace") => {
+                ClashRoyaleBattleKind::ClanWar
+            }
+            _ => ClashRoyaleBattleKind::Unknown,
+        }
+    }
+}
+
+impl DBNormalize for ClashRoyaleBattleKind {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
@@ -1,4 +1,5 @@
 use arcstr::ArcStr;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -6,9 +7,9 @@ use crate::clients::games::ClashRoyaleBattlelogPlayerResponse;
 use crate::clients::games::ClashRoyaleBattlelogResponse;
 use crate::clients::games::ClashRoyaleCardResponse;
 use crate::data::games::ClashRoyaleCard;
-use crate::database::types::conditions::OrderedCondition;
+use crate::database::types::conditions::{ConditionCheck, OrderedCondition};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleCardConditions {
     pub conditions: Vec<ClashRoyaleCardCondition>,
@@ -23,50 +24,162 @@ impl ClashRoyaleCardConditions {
 
     pub fn verify_allowed_response(
         &self,
-        _battlelog: &ClashRoyaleBattlelogResponse,
+        battlelog: &ClashRoyaleBattlelogResponse,
         player: &ClashRoyaleBattlelogPlayerResponse,
     ) -> bool {
+        ConditionCheck::all_passed(&self.check_allowed_response(battlelog, player, ""))
+    }
+
+    /// Evaluates that every card of the player fulfills at least one of the conditions.
+    /// For each card only the checks of the fulfilled condition are included or, if none
+    /// is fulfilled, the checks of every condition that applies to it.
+    pub fn check_allowed_response(
+        &self,
+        _battlelog: &ClashRoyaleBattlelogResponse,
+        player: &ClashRoyaleBattlelogPlayerResponse,
+        path: &str,
+    ) -> Vec<ConditionCheck> {
         let cards = match &player.cards {
             Some(v) => v,
-            None => return false,
+            None => return vec![ConditionCheck::new(path, false)],
         };
 
+        let mut checks = Vec::new();
+
         for card in cards {
-            if !self
+            let card_checks: Vec<_> = self
                 .conditions
                 .iter()
-                .filter_map(|v| v.verify_response(card))
-                .any(|v| v)
-            {
-                return false;
+                .enumerate()
+                .filter_map(|(i, v)| v.check_response(card, &format!("{}[{}]", path, i)))
+                .collect();
+
+            match card_checks.iter().find(|v| ConditionCheck::all_passed(v)) {
+                Some(passed_checks) => checks.extend(passed_checks.iter().cloned()),
+                None if card_checks.is_empty() => {
+                    checks.push(ConditionCheck::new_with_values(
+                        path,
+                        false,
+                        &self.conditions,
+                        Some(&card.id),
+                    ));
+                }
+                None => checks.extend(card_checks.into_iter().flatten()),
             }
         }
 
-        true
+        checks
     }
 
     pub fn verify_forbidden_response(
         &self,
-        _battlelog: &ClashRoyaleBattlelogResponse,
+        battlelog: &ClashRoyaleBattlelogResponse,
         player: &ClashRoyaleBattlelogPlayerResponse,
     ) -> bool {
+        ConditionCheck::all_passed(&self.check_forbidden_response(battlelog, player, ""))
+    }
+
+    /// Evaluates that no card of the player fulfills any of the conditions.
+    /// Only the fulfilled conditions are included as failures.
+    pub fn check_forbidden_response(
+        &self,
+        _battlelog: &ClashRoyaleBattlelogResponse,
+        player: &ClashRoyaleBattlelogPlayerResponse,
+        path: &str,
+    ) -> Vec<ConditionCheck> {
         let cards = match &player.cards {
             Some(v) => v,
-            None => return false,
+            None => return vec![ConditionCheck::new(path, false)],
         };
 
+        let mut checks = Vec::new();
+
         for card in cards {
-            if self
-                .conditions
-                .iter()
-                .filter_map(|v| v.verify_response(card))
-                .any(|v| v)
-            {
-                return false;
+            for (i, condition) in self.conditions.iter().enumerate() {
+                let condition_path = format!("{}[{}]", path, i);
+
+                if let Some(card_checks) = condition.check_response(card, &condition_path) {
+                    if ConditionCheck::all_passed(&card_checks) {
+                        checks.push(ConditionCheck::new_with_values(
+                            &condition_path,
+                            false,
+                            condition,
+                            Some(&card.id),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if checks.is_empty() {
+            checks.push(ConditionCheck::new(path, true));
+        }
+
+        checks
+    }
+
+    /// Verifies that every condition is fulfilled by at least one card of the collection.
+    pub fn verify_allowed_collection(&self, cards: &[ClashRoyaleCardResponse]) -> bool {
+        ConditionCheck::all_passed(&self.check_allowed_collection(cards, ""))
+    }
+
+    /// Evaluates that every condition is fulfilled by at least one card of the collection.
+    /// The checks include the first card that fulfills each condition.
+    pub fn check_allowed_collection(
+        &self,
+        cards: &[ClashRoyaleCardResponse],
+        path: &str,
+    ) -> Vec<ConditionCheck> {
+        self.conditions
+            .iter()
+            .enumerate()
+            .map(|(i, condition)| {
+                let card = cards
+                    .iter()
+                    .find(|card| condition.verify_response(card) == Some(true));
+
+                ConditionCheck::new_with_values(
+                    &format!("{}[{}]", path, i),
+                    card.is_some(),
+                    condition,
+                    card.map(|v| &v.id),
+                )
+            })
+            .collect()
+    }
+
+    /// Verifies that no card of the collection fulfills any of the conditions.
+    pub fn verify_forbidden_collection(&self, cards: &[ClashRoyaleCardResponse]) -> bool {
+        ConditionCheck::all_passed(&self.check_forbidden_collection(cards, ""))
+    }
+
+    /// Evaluates that no card of the collection fulfills any of the conditions.
+    /// Only the fulfilled conditions are included as failures.
+    pub fn check_forbidden_collection(
+        &self,
+        cards: &[ClashRoyaleCardResponse],
+        path: &str,
+    ) -> Vec<ConditionCheck> {
+        let mut checks = Vec::new();
+
+        for card in cards {
+            for (i, condition) in self.conditions.iter().enumerate() {
+                if condition.verify_response(card) == Some(true) {
+                    checks.push(ConditionCheck::new_with_values(
+                        &format!("{}[{}]", path, i),
+                        false,
+                        condition,
+                        Some(&card.id),
+                    ));
+                }
             }
         }
 
-        true
+        if checks.is_empty() {
+            checks.push(ConditionCheck::new(path, true));
+        }
+
+        checks
     }
 }
 
@@ -75,7 +188,7 @@ impl ClashRoyaleCardConditions {
 // ----------------------------------------------------------------------------
 
 /// The conditions are applied to a single card (if `card_id` is present) or any of them (if not).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleCardCondition {
     /// The exact card.
@@ -98,29 +211,55 @@ impl ClashRoyaleCardCondition {
     // METHODS ----------------------------------------------------------------
 
     pub fn verify_response(&self, card: &ClashRoyaleCardResponse) -> Option<bool> {
-        if let Some(card_id) = self.card_id {
+        self.check_response(card, "")
+            .map(|checks| ConditionCheck::all_passed(&checks))
+    }
+
+    /// Evaluates each condition against the card independently.
+    /// None if the condition does not apply to the card.
+    pub fn check_response(
+        &self,
+        card: &ClashRoyaleCardResponse,
+        path: &str,
+    ) -> Option<Vec<ConditionCheck>> {
+        let mut checks = Vec::new();
+
+        if let Some(card_id) = &self.card_id {
             // Ignore the check if not the same card.
-            if card_id as u64 != card.id {
+            if *card_id as u64 != card.id {
                 return None;
             }
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "cardId"),
+                true,
+                card_id,
+                Some(&card.id),
+            ));
         }
 
         if let Some(level) = &self.level {
             if let Some(card_level) = card.level {
-                if !level.verify(&card_level) {
-                    return Some(false);
-                }
+                checks.push(ConditionCheck::new_with_values(
+                    &ConditionCheck::join_path(path, "level"),
+                    level.verify(&card_level),
+                    level,
+                    Some(&card_level),
+                ));
             }
         }
 
         if let Some(count) = &self.count {
             if let Some(card_count) = card.count {
-                if !count.verify(&card_count) {
-                    return Some(false);
-                }
+                checks.push(ConditionCheck::new_with_values(
+                    &ConditionCheck::join_path(path, "count"),
+                    count.verify(&card_count),
+                    count,
+                    Some(&card_count),
+                ));
             }
         }
 
-        Some(true)
+        Some(checks)
     }
 }
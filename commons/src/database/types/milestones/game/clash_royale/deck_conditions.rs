@@ -0,0 +1,252 @@
+use arcstr::ArcStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::clients::games::ClashRoyaleBattlelogPlayerResponse;
+use crate::clients::games::ClashRoyaleBattlelogResponse;
+use crate::data::games::{ClashRoyaleCardRarity, ClashRoyaleCardType, ClashRoyaleCatalog};
+use crate::database::types::conditions::{ConditionCheck, OrderedCondition};
+use crate::database::types::schema::{DeckCardCount, ElixirCost};
+
+/// The number of cards of a deck.
+pub(crate) const DECK_SIZE: u8 = 8;
+
+/// The maximum elixir cost of a card.
+pub(crate) const MAX_ELIXIR: f64 = 10.0;
+
+/// Conditions over the composition of the deck used in a match, evaluated with the
+/// metadata of the cards catalog.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleDeckConditions {
+    /// The average elixir cost of the deck rounded to one decimal. Cards with a variable
+    /// cost, e.g. Mirror, are not included.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<OrderedCondition<ElixirCost>>")]
+    pub average_elixir: Option<OrderedCondition<f64>>,
+
+    /// The number of common cards in the deck.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<OrderedCondition<DeckCardCount>>")]
+    pub common_cards: Option<OrderedCondition<u8>>,
+
+    /// The number of rare cards in the deck.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<OrderedCondition<DeckCardCount>>")]
+    pub rare_cards: Option<OrderedCondition<u8>>,
+
+    /// The number of epic cards in the deck.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<OrderedCondition<DeckCardCount>>")]
+    pub epic_cards: Option<OrderedCondition<u8>>,
+
+    /// The number of legendary cards in the deck, e.g. `= 0` to forbid them.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<OrderedCondition<DeckCardCount>>")]
+    pub legendary_cards: Option<OrderedCondition<u8>>,
+
+    /// The number of champion cards in the deck.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<OrderedCondition<DeckCardCount>>")]
+    pub champion_cards: Option<OrderedCondition<u8>>,
+
+    /// The number of troop cards in the deck.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<OrderedCondition<DeckCardCount>>")]
+    pub troop_cards: Option<OrderedCondition<u8>>,
+
+    /// The number of building cards in the deck.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<OrderedCondition<DeckCardCount>>")]
+    pub building_cards: Option<OrderedCondition<u8>>,
+
+    /// The number of spell cards in the deck.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<OrderedCondition<DeckCardCount>>")]
+    pub spell_cards: Option<OrderedCondition<u8>>,
+
+    /// Whether the deck must include a win condition, e.g. Hog Rider, or must not.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub win_condition: Option<bool>,
+}
+
+impl ClashRoyaleDeckConditions {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self) -> Option<ArcStr> {
+        if let Some(average_elixir) = &self.average_elixir {
+            if average_elixir
+                .value()
+                .iter()
+                .any(|v| !(0.0..=MAX_ELIXIR).contains(v))
+            {
+                return Some(arcstr::literal!(
+                    "The average elixir must be between 0 and 10"
+                ));
+            }
+        }
+
+        let counters = [
+            &self.common_cards,
+            &self.rare_cards,
+            &self.epic_cards,
+            &self.legendary_cards,
+            &self.champion_cards,
+            &self.troop_cards,
+            &self.building_cards,
+            &self.spell_cards,
+        ];
+
+        for condition in counters.iter().copied().flatten() {
+            if condition.value().iter().any(|v| *v > DECK_SIZE) {
+                return Some(arcstr::literal!(
+                    "The number of cards of a deck must be between 0 and 8"
+                ));
+            }
+        }
+
+        None
+    }
+
+    pub fn verify_response(
+        &self,
+        battlelog: &ClashRoyaleBattlelogResponse,
+        player: &ClashRoyaleBattlelogPlayerResponse,
+    ) -> bool {
+        ConditionCheck::all_passed(&self.check_response(battlelog, player, ""))
+    }
+
+    /// Evaluates each condition against the deck of the player independently.
+    /// The deck fails if any of its cards is missing in the catalog.
+    pub fn check_response(
+        &self,
+        _battlelog: &ClashRoyaleBattlelogResponse,
+        player: &ClashRoyaleBattlelogPlayerResponse,
+        path: &str,
+    ) -> Vec<ConditionCheck> {
+        let cards = match &player.cards {
+            Some(v) => v,
+            None => return vec![ConditionCheck::new(path, false)],
+        };
+
+        let catalog = ClashRoyaleCatalog::instance();
+        let mut infos = Vec::with_capacity(cards.len());
+        let mut unknown_cards = Vec::new();
+
+        for card in cards {
+            match catalog.card(card.id) {
+                Some(info) => infos.push(info),
+                None => unknown_cards.push(card.id),
+            }
+        }
+
+        if !unknown_cards.is_empty() {
+            let mut check = ConditionCheck::new(path, false);
+            check.actual = serde_json::to_value(&unknown_cards).ok();
+            return vec![check];
+        }
+
+        let mut checks = Vec::new();
+
+        if let Some(average_elixir) = &self.average_elixir {
+            let costs: Vec<u8> = infos.iter().filter_map(|v| v.elixir).collect();
+            let actual_average = if costs.is_empty() {
+                None
+            } else {
+                let sum: u64 = costs.iter().map(|v| *v as u64).sum();
+                Some((sum as f64 * 10.0 / costs.len() as f64).round() / 10.0)
+            };
+            let passed = match actual_average {
+                Some(v) => average_elixir.verify(&v),
+                None => false,
+            };
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "averageElixir"),
+                passed,
+                average_elixir,
+                actual_average.as_ref(),
+            ));
+        }
+
+        let rarities = [
+            (
+                &self.common_cards,
+                ClashRoyaleCardRarity::Common,
+                "commonCards",
+            ),
+            (&self.rare_cards, ClashRoyaleCardRarity::Rare, "rareCards"),
+            (&self.epic_cards, ClashRoyaleCardRarity::Epic, "epicCards"),
+            (
+                &self.legendary_cards,
+                ClashRoyaleCardRarity::Legendary,
+                "legendaryCards",
+            ),
+            (
+                &self.champion_cards,
+                ClashRoyaleCardRarity::Champion,
+                "championCards",
+            ),
+        ];
+
+        for (condition, rarity, name) in rarities.iter() {
+            if let Some(condition) = condition {
+                let count = infos.iter().filter(|v| v.rarity == *rarity).count() as u8;
+
+                checks.push(ConditionCheck::new_with_values(
+                    &ConditionCheck::join_path(path, name),
+                    condition.verify(&count),
+                    condition,
+                    Some(&count),
+                ));
+            }
+        }
+
+        let kinds = [
+            (&self.troop_cards, ClashRoyaleCardType::Troop, "troopCards"),
+            (
+                &self.building_cards,
+                ClashRoyaleCardType::Building,
+                "buildingCards",
+            ),
+            (&self.spell_cards, ClashRoyaleCardType::Spell, "spellCards"),
+        ];
+
+        for (condition, kind, name) in kinds.iter() {
+            if let Some(condition) = condition {
+                let count = infos.iter().filter(|v| v.kind == *kind).count() as u8;
+
+                checks.push(ConditionCheck::new_with_values(
+                    &ConditionCheck::join_path(path, name),
+                    condition.verify(&count),
+                    condition,
+                    Some(&count),
+                ));
+            }
+        }
+
+        if let Some(win_condition) = &self.win_condition {
+            let has_win_condition = infos.iter().any(|v| v.win_condition);
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "winCondition"),
+                *win_condition == has_win_condition,
+                win_condition,
+                Some(&has_win_condition),
+            ));
+        }
+
+        checks
+    }
+}
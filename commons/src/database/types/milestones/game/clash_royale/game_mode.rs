@@ -1,9 +1,12 @@
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
+use enum_derive::EnumList;
+
 use crate::database::traits::{DBNormalize, DBNormalizeResult};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, EnumList)]
 #[serde(rename_all = "camelCase")]
 pub enum ClashRoyaleGameMode {
     OneVsOne,
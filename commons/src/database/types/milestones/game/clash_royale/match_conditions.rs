@@ -1,18 +1,21 @@
 use std::cmp::Ordering;
-use std::convert::TryFrom;
 
 use arcstr::ArcStr;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::clients::games::ClashRoyaleBattlelogResponse;
 use crate::data::games::ClashRoyaleArena;
-use crate::database::types::conditions::{OptionCondition, OrderedCondition};
+use crate::database::types::conditions::{
+    ConditionCheck, OptionCondition, OrderedCondition, TimeWindowCondition,
+};
 use crate::database::types::game::{
-    ClashRoyaleGameMode, ClashRoyaleGameResult, ClashRoyaleTeamConditions,
+    ClashRoyaleBattleKind, ClashRoyaleGameMode, ClashRoyaleGameResult,
+    ClashRoyaleRelativeConditions, ClashRoyaleTeamConditions,
 };
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleMatchConditions {
     /// Whether player must win or not the match.
@@ -25,6 +28,11 @@ pub struct ClashRoyaleMatchConditions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_friends: Option<bool>,
 
+    /// The battle kind(s) (dis)allowed of the match.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battle_kind: Option<OptionCondition<ClashRoyaleBattleKind>>,
+
     /// The arena(s) (dis)allowed of the match.
     /// Missing: ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -35,6 +43,11 @@ pub struct ClashRoyaleMatchConditions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub game_mode: Option<OptionCondition<ClashRoyaleGameMode>>,
 
+    /// The time window in which the match must start.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_window: Option<TimeWindowCondition>,
+
     /// The conditions for the team.
     /// Missing: ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -44,12 +57,23 @@ pub struct ClashRoyaleMatchConditions {
     /// Missing: ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub opponent: Option<ClashRoyaleTeamConditions>,
+
+    /// The conditions for the opponent relative to the team.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relative: Option<ClashRoyaleRelativeConditions>,
 }
 
 impl ClashRoyaleMatchConditions {
     // METHODS ----------------------------------------------------------------
 
     pub fn validate(&self) -> Option<ArcStr> {
+        if let Some(time_window) = &self.time_window {
+            if let Some(error) = time_window.validate() {
+                return Some(error);
+            }
+        }
+
         if let Some(team) = &self.team {
             if let Some(error) = team.validate() {
                 return Some(error);
@@ -62,27 +86,46 @@ impl ClashRoyaleMatchConditions {
             }
         }
 
+        if let Some(relative) = &self.relative {
+            if let Some(error) = relative.validate() {
+                return Some(error);
+            }
+        }
+
         None
     }
 
-    pub fn verify_response(&self, battlelog: &ClashRoyaleBattlelogResponse) -> bool {
-        let battlelog_team = match &battlelog.team {
-            Some(v) => v,
-            None => return false,
-        };
-        let battlelog_opponent = match &battlelog.opponent {
-            Some(v) => v,
-            None => return false,
+    pub fn verify_response(
+        &self,
+        battlelog: &ClashRoyaleBattlelogResponse,
+        previous_battlelogs: &[ClashRoyaleBattlelogResponse],
+    ) -> bool {
+        ConditionCheck::all_passed(&self.check_response(battlelog, previous_battlelogs))
+    }
+
+    /// Evaluates each condition against the match independently.
+    /// `previous_battlelogs` are the matches of the bet played before this one.
+    pub fn check_response(
+        &self,
+        battlelog: &ClashRoyaleBattlelogResponse,
+        previous_battlelogs: &[ClashRoyaleBattlelogResponse],
+    ) -> Vec<ConditionCheck> {
+        let (battlelog_team, battlelog_opponent) = match (&battlelog.team, &battlelog.opponent) {
+            (Some(team), Some(opponent)) => (team, opponent),
+            _ => return vec![ConditionCheck::new("team", false)],
         };
 
         let team_crows = battlelog_team[0].crowns;
         let opponent_crows = battlelog_opponent[0].crowns;
+        let battle_kind = battlelog.battle_kind();
         let game_mode = if battlelog_team.len() == 1 {
             ClashRoyaleGameMode::OneVsOne
         } else {
             ClashRoyaleGameMode::TwoVsTwo
         };
 
+        let mut checks = Vec::new();
+
         if let Some(result) = &self.result {
             let actual_result = match team_crows.cmp(&opponent_crows) {
                 Ordering::Less => ClashRoyaleGameResult::Loose,
@@ -90,56 +133,97 @@ impl ClashRoyaleMatchConditions {
                 Ordering::Greater => ClashRoyaleGameResult::Win,
             };
 
-            if !result.verify(&actual_result) {
-                return false;
-            }
+            checks.push(ConditionCheck::new_with_values(
+                "result",
+                result.verify(&actual_result),
+                result,
+                Some(&actual_result),
+            ));
         }
 
         if let Some(allow_friends) = self.allow_friends {
-            let kind = match &battlelog.kind {
-                Some(v) => v,
-                None => return false,
+            let passed = match &battlelog.kind {
+                Some(_) => allow_friends || battle_kind != ClashRoyaleBattleKind::Friendly,
+                None => false,
             };
 
-            if !allow_friends && kind.contains("friendly") {
-                return false;
-            }
+            checks.push(ConditionCheck::new_with_values(
+                "allowFriends",
+                passed,
+                &allow_friends,
+                Some(&battle_kind),
+            ));
         }
 
-        if let Some(arena) = &self.arena {
-            let battlelog_arena = match &battlelog.arena {
-                Some(v) => v,
-                None => return false,
-            };
+        if let Some(battle_kind_option) = &self.battle_kind {
+            checks.push(ConditionCheck::new_with_values(
+                "battleKind",
+                battle_kind_option.verify(&battle_kind),
+                battle_kind_option,
+                Some(&battle_kind),
+            ));
+        }
 
-            let arena_id = match ClashRoyaleArena::try_from(battlelog_arena.id) {
-                Ok(v) => v,
-                Err(_) => return false,
+        if let Some(arena) = &self.arena {
+            // Unknown arenas are resolved by the trophies of the team.
+            let arena_id = battlelog
+                .arena
+                .as_ref()
+                .and_then(|v| ClashRoyaleArena::resolve(v.id, battlelog_team[0].starting_trophies));
+            let passed = match arena_id {
+                Some(v) => arena.verify(&v),
+                None => false,
             };
 
-            if !arena.verify(&arena_id) {
-                return false;
-            }
+            checks.push(ConditionCheck::new_with_values(
+                "arena",
+                passed,
+                arena,
+                arena_id.as_ref(),
+            ));
         }
 
         if let Some(game_mode_option) = &self.game_mode {
-            if !game_mode_option.verify(&game_mode) {
-                return false;
-            }
+            checks.push(ConditionCheck::new_with_values(
+                "gameMode",
+                game_mode_option.verify(&game_mode),
+                game_mode_option,
+                Some(&game_mode),
+            ));
+        }
+
+        if let Some(time_window) = &self.time_window {
+            let local_time = time_window.local_time(&battlelog.battle_time).to_rfc3339();
+
+            checks.push(ConditionCheck::new_with_values(
+                "timeWindow",
+                time_window.verify(&battlelog.battle_time),
+                time_window,
+                Some(&local_time),
+            ));
         }
 
         if let Some(team) = &self.team {
-            if !team.verify_response(battlelog, battlelog_team.as_slice()) {
-                return false;
-            }
+            checks.extend(team.check_response(battlelog, battlelog_team.as_slice(), "team"));
         }
 
         if let Some(opponent) = &self.opponent {
-            if !opponent.verify_response(battlelog, battlelog_opponent.as_slice()) {
-                return false;
-            }
+            checks.extend(opponent.check_response(
+                battlelog,
+                battlelog_opponent.as_slice(),
+                "opponent",
+            ));
+        }
+
+        if let Some(relative) = &self.relative {
+            checks.extend(relative.check_response(
+                battlelog_team.as_slice(),
+                battlelog_opponent.as_slice(),
+                previous_battlelogs,
+                "relative",
+            ));
         }
 
-        true
+        checks
     }
 }
@@ -1,13 +1,14 @@
 use arcstr::ArcStr;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::clients::games::ClashRoyaleBattlelogPlayerResponse;
 use crate::clients::games::ClashRoyaleBattlelogResponse;
-use crate::database::types::conditions::OrderedCondition;
-use crate::database::types::game::ClashRoyaleCardConditions;
+use crate::database::types::conditions::{ConditionCheck, OrderedCondition};
+use crate::database::types::game::{ClashRoyaleCardConditions, ClashRoyaleDeckConditions};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleTeamMemberConditions {
     /// The number of starting trophies of the team member.
@@ -15,20 +16,23 @@ pub struct ClashRoyaleTeamMemberConditions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub starting_trophies: Option<OrderedCondition<u64>>,
 
-    // /// Note: not always available.
-    // /// The hits made to the opponent's king tower.
-    // /// Missing: ignored.
-    // pub king_tower_hit_points: Option<OrderedCondition<u8>>,
+    /// The remaining hit points of the team member's king tower, 0 if destroyed.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub king_tower_hit_points: Option<OrderedCondition<u32>>,
+
+    /// The remaining hit points of the team member's princess tower with the most of them,
+    /// i.e. 0 only if both towers are destroyed.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub princess_tower_1_hit_points: Option<OrderedCondition<u32>>,
 
-    // /// Note: not always available.
-    // /// The hits made to the opponent's princess tower 1.
-    // /// Missing: ignored.
-    // pub princess_tower_1_hit_points: Option<OrderedCondition<u8>>,
+    /// The remaining hit points of the team member's princess tower with the fewest of them,
+    /// i.e. 0 if any tower is destroyed.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub princess_tower_2_hit_points: Option<OrderedCondition<u32>>,
 
-    // /// Note: not always available.
-    // /// The hits made to the opponent's princess tower 2.
-    // /// Missing: ignored.
-    // pub princess_tower_2_hit_points: Option<OrderedCondition<u8>>,
     /// The allowed cards for this member.
     /// Missing: ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -38,6 +42,11 @@ pub struct ClashRoyaleTeamMemberConditions {
     /// Missing: ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forbidden_cards: Option<ClashRoyaleCardConditions>,
+
+    /// The composition of the deck of this member.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deck: Option<ClashRoyaleDeckConditions>,
 }
 
 impl ClashRoyaleTeamMemberConditions {
@@ -56,6 +65,12 @@ impl ClashRoyaleTeamMemberConditions {
             }
         }
 
+        if let Some(deck) = &self.deck {
+            if let Some(error) = deck.validate() {
+                return Some(error);
+            }
+        }
+
         None
     }
 
@@ -64,29 +79,91 @@ impl ClashRoyaleTeamMemberConditions {
         battlelog: &ClashRoyaleBattlelogResponse,
         player: &ClashRoyaleBattlelogPlayerResponse,
     ) -> bool {
+        ConditionCheck::all_passed(&self.check_response(battlelog, player, ""))
+    }
+
+    /// Evaluates each condition against the team member independently.
+    pub fn check_response(
+        &self,
+        battlelog: &ClashRoyaleBattlelogResponse,
+        player: &ClashRoyaleBattlelogPlayerResponse,
+        path: &str,
+    ) -> Vec<ConditionCheck> {
+        let mut checks = Vec::new();
+
         if let Some(starting_trophies) = &self.starting_trophies {
-            let actual_trophies = match player.starting_trophies {
-                Some(v) => v,
-                None => return false,
+            let passed = match player.starting_trophies {
+                Some(v) => starting_trophies.verify(&v),
+                None => false,
             };
 
-            if !starting_trophies.verify(&actual_trophies) {
-                return false;
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "startingTrophies"),
+                passed,
+                starting_trophies,
+                player.starting_trophies.as_ref(),
+            ));
+        }
+
+        if let Some(king_tower_hit_points) = &self.king_tower_hit_points {
+            let actual_hit_points = player.remaining_king_tower_hit_points();
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "kingTowerHitPoints"),
+                king_tower_hit_points.verify(&actual_hit_points),
+                king_tower_hit_points,
+                Some(&actual_hit_points),
+            ));
+        }
+
+        let princess_towers = [
+            (
+                &self.princess_tower_1_hit_points,
+                player.max_princess_tower_hit_points(),
+                "princessTower1HitPoints",
+            ),
+            (
+                &self.princess_tower_2_hit_points,
+                player.min_princess_tower_hit_points(),
+                "princessTower2HitPoints",
+            ),
+        ];
+
+        for (condition, actual_hit_points, name) in princess_towers.iter() {
+            if let Some(condition) = condition {
+                checks.push(ConditionCheck::new_with_values(
+                    &ConditionCheck::join_path(path, name),
+                    condition.verify(actual_hit_points),
+                    condition,
+                    Some(actual_hit_points),
+                ));
             }
         }
 
         if let Some(allowed_cards) = &self.allowed_cards {
-            if !allowed_cards.verify_allowed_response(battlelog, player) {
-                return false;
-            }
+            checks.extend(allowed_cards.check_allowed_response(
+                battlelog,
+                player,
+                &ConditionCheck::join_path(path, "allowedCards"),
+            ));
         }
 
         if let Some(forbidden_cards) = &self.forbidden_cards {
-            if !forbidden_cards.verify_forbidden_response(battlelog, player) {
-                return false;
-            }
+            checks.extend(forbidden_cards.check_forbidden_response(
+                battlelog,
+                player,
+                &ConditionCheck::join_path(path, "forbiddenCards"),
+            ));
+        }
+
+        if let Some(deck) = &self.deck {
+            checks.extend(deck.check_response(
+                battlelog,
+                player,
+                &ConditionCheck::join_path(path, "deck"),
+            ));
         }
 
-        true
+        checks
     }
 }
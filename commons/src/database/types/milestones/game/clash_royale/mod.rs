@@ -1,53 +1,66 @@
 use arcstr::ArcStr;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
+pub use battle_kind::*;
 pub use card_conditions::*;
+pub use deck_conditions::*;
 pub use game_mode::*;
 pub use game_result::*;
 pub use match_conditions::*;
 pub use member_conditions::*;
+pub use relative_conditions::*;
+pub use statistics_conditions::*;
 pub use team_conditions::*;
 pub use user_conditions::*;
+pub use win_matches::*;
 
 use crate::database::traits::{DBNormalize, DBNormalizeResult};
+use crate::database::types::rules::{parse_clash_royale_rule, print_clash_royale_rule, RuleError};
+use crate::database::types::schema::statistics_conditions_schema;
 
+mod battle_kind;
 mod card_conditions;
+mod deck_conditions;
 mod game_mode;
 mod game_result;
 mod match_conditions;
 mod member_conditions;
+mod relative_conditions;
+mod statistics_conditions;
 mod team_conditions;
 mod user_conditions;
+mod win_matches;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", content = "params")]
 #[serde(rename_all = "camelCase")]
 pub enum ClashRoyaleMilestone {
-    WinMatches(Vec<ClashRoyaleMatchConditions>),
+    WinMatches(ClashRoyaleWinMatches),
     Achievement(Box<ClashRoyaleUserConditions>),
+    #[schemars(schema_with = "statistics_conditions_schema")]
+    Statistics(Box<ClashRoyaleStatisticsConditions>),
 }
 
 impl ClashRoyaleMilestone {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Parses a milestone written as a rule, e.g. `win 3 matches in 1v1 where team.crowns >= 2`.
+    pub fn from_rule(rule: &str) -> Result<Self, RuleError> {
+        parse_clash_royale_rule(rule)
+    }
+
     // METHODS ----------------------------------------------------------------
 
+    /// Writes the milestone as a rule, failing if it has conditions that cannot be written.
+    pub fn to_rule(&self) -> Result<String, ArcStr> {
+        print_clash_royale_rule(self)
+    }
+
     pub fn validate(&self) -> Option<ArcStr> {
         match self {
-            ClashRoyaleMilestone::WinMatches(conditions) => {
-                if conditions.is_empty() {
-                    return Some(arcstr::literal!(
-                        "Clash Royale 'win matches' milestone without conditions"
-                    ));
-                }
-
-                for condition in conditions {
-                    if let Some(error) = condition.validate() {
-                        return Some(error);
-                    }
-                }
-
-                None
-            }
+            ClashRoyaleMilestone::WinMatches(params) => params.validate(),
             ClashRoyaleMilestone::Achievement(condition) => {
                 if let Some(error) = condition.validate() {
                     return Some(error);
@@ -55,6 +68,7 @@ impl ClashRoyaleMilestone {
 
                 None
             }
+            ClashRoyaleMilestone::Statistics(conditions) => conditions.validate(),
         }
     }
 }
@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use arcstr::ArcStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::clients::games::{ClashRoyaleBattlelogPlayerResponse, ClashRoyaleBattlelogResponse};
+use crate::data::games::ClashRoyaleCatalog;
+use crate::database::types::conditions::{ConditionCheck, OrderedCondition};
+
+/// Conditions of the opponent relative to the team.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleRelativeConditions {
+    /// The opponent's starting trophies minus the team's ones, summing all the members.
+    /// The condition fails if the trophies of any player are unknown.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trophy_difference: Option<OrderedCondition<i64>>,
+
+    /// The opponent's average card level minus the team's one, with the levels of every
+    /// rarity normalized to the scale of the commons.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_level_difference: Option<OrderedCondition<f64>>,
+
+    /// Whether the opponent can belong to the clan of any team member or not.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_clan_mates: Option<bool>,
+
+    /// Whether an opponent already faced in a previous match of the bet is accepted or not.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_repeated_opponents: Option<bool>,
+}
+
+impl ClashRoyaleRelativeConditions {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self) -> Option<ArcStr> {
+        if let Some(card_level_difference) = &self.card_level_difference {
+            if card_level_difference.value().iter().any(|v| !v.is_finite()) {
+                return Some(arcstr::literal!(
+                    "The card level difference must be a finite number"
+                ));
+            }
+        }
+
+        None
+    }
+
+    /// Evaluates each condition against the match independently.
+    /// `previous_battlelogs` are the matches of the bet played before this one.
+    pub fn check_response(
+        &self,
+        team: &[ClashRoyaleBattlelogPlayerResponse],
+        opponent: &[ClashRoyaleBattlelogPlayerResponse],
+        previous_battlelogs: &[ClashRoyaleBattlelogResponse],
+        path: &str,
+    ) -> Vec<ConditionCheck> {
+        let mut checks = Vec::new();
+
+        if let Some(trophy_difference) = &self.trophy_difference {
+            let team_trophies = ClashRoyaleBattlelogPlayerResponse::total_starting_trophies(team);
+            let opponent_trophies =
+                ClashRoyaleBattlelogPlayerResponse::total_starting_trophies(opponent);
+            let actual_difference = match (team_trophies, opponent_trophies) {
+                (Some(team_trophies), Some(opponent_trophies)) => {
+                    Some(opponent_trophies as i64 - team_trophies as i64)
+                }
+                _ => None,
+            };
+            let passed = match &actual_difference {
+                Some(v) => trophy_difference.verify(v),
+                None => false,
+            };
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "trophyDifference"),
+                passed,
+                trophy_difference,
+                actual_difference.as_ref(),
+            ));
+        }
+
+        if let Some(card_level_difference) = &self.card_level_difference {
+            let actual_difference = match (average_card_level(team), average_card_level(opponent)) {
+                (Some(team_level), Some(opponent_level)) => Some(opponent_level - team_level),
+                _ => None,
+            };
+            let passed = match &actual_difference {
+                Some(v) => card_level_difference.verify(v),
+                None => false,
+            };
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "cardLevelDifference"),
+                passed,
+                card_level_difference,
+                actual_difference.as_ref(),
+            ));
+        }
+
+        if let Some(allow_clan_mates) = self.allow_clan_mates {
+            let team_clans: HashSet<_> = team
+                .iter()
+                .filter_map(|v| v.clan.as_ref().map(|v| &v.tag))
+                .collect();
+            let shared_clan = opponent
+                .iter()
+                .filter_map(|v| v.clan.as_ref().map(|v| &v.tag))
+                .find(|v| team_clans.contains(v));
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "allowClanMates"),
+                allow_clan_mates || shared_clan.is_none(),
+                &allow_clan_mates,
+                shared_clan,
+            ));
+        }
+
+        if let Some(allow_repeated_opponents) = self.allow_repeated_opponents {
+            let previous_opponents: HashSet<_> = previous_battlelogs
+                .iter()
+                .flat_map(|v| v.opponent.iter().flatten())
+                .filter_map(|v| v.tag.as_ref())
+                .collect();
+            let repeated_opponent = opponent
+                .iter()
+                .filter_map(|v| v.tag.as_ref())
+                .find(|v| previous_opponents.contains(v));
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "allowRepeatedOpponents"),
+                allow_repeated_opponents || repeated_opponent.is_none(),
+                &allow_repeated_opponents,
+                repeated_opponent,
+            ));
+        }
+
+        checks
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The average level of the cards of all the players, missing if no level is known.
+/// The levels are normalized by rarity before averaging and unknown cards are ignored.
+fn average_card_level(players: &[ClashRoyaleBattlelogPlayerResponse]) -> Option<f64> {
+    let catalog = ClashRoyaleCatalog::instance();
+    let levels: Vec<_> = players
+        .iter()
+        .flat_map(|v| v.cards.iter().flatten())
+        .filter_map(|v| catalog.normalize_card_level(v.id, u64::from(v.level?)))
+        .collect();
+
+    if levels.is_empty() {
+        return None;
+    }
+
+    let total: u64 = levels.iter().sum();
+    Some(total as f64 / levels.len() as f64)
+}
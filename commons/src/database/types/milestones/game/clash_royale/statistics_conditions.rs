@@ -0,0 +1,158 @@
+use arcstr::ArcStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::clients::games::ClashRoyaleBattlelogResponse;
+use crate::database::types::conditions::{ConditionCheck, OptionCondition, OrderedCondition};
+use crate::database::types::game::{ClashRoyaleBattleKind, ClashRoyaleGameMode};
+use crate::database::types::schema::Percentage;
+use crate::games::ClashRoyaleMatchStatistics;
+
+/// Conditions over the aggregated statistics of the matches played during the bet.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleStatisticsConditions {
+    /// The battle kind(s) (dis)allowed of the matches to aggregate.
+    /// Missing: all but friendly matches are aggregated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battle_kind: Option<OptionCondition<ClashRoyaleBattleKind>>,
+
+    /// The game mode(s) (dis)allowed of the matches to aggregate.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_mode: Option<OptionCondition<ClashRoyaleGameMode>>,
+
+    /// The number of matches played.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_count: Option<OrderedCondition<u64>>,
+
+    /// The number of matches won.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wins: Option<OrderedCondition<u64>>,
+
+    /// The percentage of matches won, rounded down, i.e. between 0 and 100.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<OrderedCondition<Percentage>>")]
+    pub win_rate: Option<OrderedCondition<u8>>,
+
+    /// The total sum of the crowns obtained by the team.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crowns: Option<OrderedCondition<u64>>,
+
+    /// The number of distinct cards used by the player.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distinct_cards: Option<OrderedCondition<u64>>,
+
+    /// The number of aggregated matches from which the milestone is completed as soon as the
+    /// conditions are fulfilled, e.g. to be followed by other milestones in a sequence.
+    /// Missing: the milestone is only completed or failed when the bet expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1))]
+    pub min_matches: Option<u32>,
+}
+
+impl ClashRoyaleStatisticsConditions {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self) -> Option<ArcStr> {
+        if self.match_count.is_none()
+            && self.wins.is_none()
+            && self.win_rate.is_none()
+            && self.crowns.is_none()
+            && self.distinct_cards.is_none()
+        {
+            return Some(arcstr::literal!(
+                "Clash Royale 'statistics' milestone without conditions"
+            ));
+        }
+
+        if self.min_matches == Some(0) {
+            return Some(arcstr::literal!(
+                "The minimum number of matches must be greater than 0"
+            ));
+        }
+
+        if let Some(win_rate) = &self.win_rate {
+            if win_rate.value().iter().any(|v| *v > 100) {
+                return Some(arcstr::literal!(
+                    "The win rate must be a percentage between 0 and 100"
+                ));
+            }
+        }
+
+        None
+    }
+
+    /// Whether a match must be aggregated in the statistics.
+    pub fn qualifies(&self, battlelog: &ClashRoyaleBattlelogResponse) -> bool {
+        let battle_kind = battlelog.battle_kind();
+        let qualifies_kind = match &self.battle_kind {
+            Some(v) => v.verify(&battle_kind),
+            None => battle_kind != ClashRoyaleBattleKind::Friendly,
+        };
+
+        if !qualifies_kind {
+            return false;
+        }
+
+        match (&self.game_mode, &battlelog.team) {
+            (Some(game_mode), Some(team)) => {
+                let actual_game_mode = if team.len() == 1 {
+                    ClashRoyaleGameMode::OneVsOne
+                } else {
+                    ClashRoyaleGameMode::TwoVsTwo
+                };
+
+                game_mode.verify(&actual_game_mode)
+            }
+            (Some(_), None) => false,
+            (None, _) => true,
+        }
+    }
+
+    /// Evaluates each condition against the statistics independently.
+    pub fn check_statistics(&self, statistics: &ClashRoyaleMatchStatistics) -> Vec<ConditionCheck> {
+        let mut checks = Vec::new();
+
+        let counters = [
+            (&self.match_count, statistics.match_count, "matchCount"),
+            (&self.wins, statistics.wins, "wins"),
+            (&self.crowns, statistics.crowns, "crowns"),
+            (
+                &self.distinct_cards,
+                statistics.cards.len() as u64,
+                "distinctCards",
+            ),
+        ];
+
+        for (condition, value, name) in counters.iter() {
+            if let Some(condition) = condition {
+                checks.push(ConditionCheck::new_with_values(
+                    name,
+                    condition.verify(value),
+                    condition,
+                    Some(value),
+                ));
+            }
+        }
+
+        if let Some(win_rate) = &self.win_rate {
+            let actual_win_rate = statistics.win_rate();
+
+            checks.push(ConditionCheck::new_with_values(
+                "winRate",
+                win_rate.verify(&actual_win_rate),
+                win_rate,
+                Some(&actual_win_rate),
+            ));
+        }
+
+        checks
+    }
+}
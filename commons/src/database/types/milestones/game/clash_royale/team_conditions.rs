@@ -1,13 +1,16 @@
 use arcstr::ArcStr;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::clients::games::ClashRoyaleBattlelogPlayerResponse;
 use crate::clients::games::ClashRoyaleBattlelogResponse;
-use crate::database::types::conditions::OrderedCondition;
-use crate::database::types::game::{ClashRoyaleCardConditions, ClashRoyaleTeamMemberConditions};
+use crate::database::types::conditions::{ConditionCheck, OrderedCondition};
+use crate::database::types::game::{
+    ClashRoyaleCardConditions, ClashRoyaleDeckConditions, ClashRoyaleTeamMemberConditions,
+};
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleTeamConditions {
     /// The number of crowns the team must achieve.
@@ -17,27 +20,29 @@ pub struct ClashRoyaleTeamConditions {
     pub crowns: Option<OrderedCondition<u8>>,
 
     /// The sum number of starting trophies of the whole team.
+    /// The condition fails if the trophies of any member are unknown.
     /// Missing: ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub starting_trophies: Option<OrderedCondition<u64>>,
 
-    // /// Note: not always available.
-    // /// The total sum of the hits made to the opponent's king tower.
-    // /// Missing: ignored.
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    // pub king_tower_hit_points: Option<OrderedCondition<u8>>,
-
-    // /// Note: not always available.
-    // /// The total sum of the hits made to the opponent's princess tower 1.
-    // /// Missing: ignored.
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    // pub princess_tower_1_hit_points: Option<OrderedCondition<u8>>,
-
-    // /// Note: not always available.
-    // /// The total sum of the hits made to the opponent's princess tower 2.
-    // /// Missing: ignored.
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    // pub princess_tower_2_hit_points: Option<OrderedCondition<u8>>,
+    /// The remaining hit points of the king tower of each member added together, counting
+    /// 0 for a destroyed tower.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub king_tower_hit_points: Option<OrderedCondition<u32>>,
+
+    /// The remaining hit points of the princess tower with the most of them of each member
+    /// added together, i.e. a member adds 0 only if both of its towers are destroyed.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub princess_tower_1_hit_points: Option<OrderedCondition<u32>>,
+
+    /// The remaining hit points of the princess tower with the fewest of them of each member
+    /// added together, i.e. a member adds 0 if any of its towers is destroyed.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub princess_tower_2_hit_points: Option<OrderedCondition<u32>>,
+
     /// The allowed cards for the whole team.
     /// Missing: ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -48,6 +53,11 @@ pub struct ClashRoyaleTeamConditions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forbidden_cards: Option<ClashRoyaleCardConditions>,
 
+    /// The composition of the deck of every member, each deck checked on its own.
+    /// Missing: ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deck: Option<ClashRoyaleDeckConditions>,
+
     /// The conditions of the first team member.
     /// Missing: ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -75,6 +85,12 @@ impl ClashRoyaleTeamConditions {
             }
         }
 
+        if let Some(deck) = &self.deck {
+            if let Some(error) = deck.validate() {
+                return Some(error);
+            }
+        }
+
         if let Some(first_member) = &self.first_member {
             if let Some(error) = first_member.validate() {
                 return Some(error);
@@ -95,67 +111,171 @@ impl ClashRoyaleTeamConditions {
         battlelog: &ClashRoyaleBattlelogResponse,
         players: &[ClashRoyaleBattlelogPlayerResponse],
     ) -> bool {
-        let team_starting_trophies = players.iter().filter_map(|v| v.starting_trophies).sum();
+        ConditionCheck::all_passed(&self.check_response(battlelog, players, ""))
+    }
+
+    /// Evaluates each condition against the team independently.
+    pub fn check_response(
+        &self,
+        battlelog: &ClashRoyaleBattlelogResponse,
+        players: &[ClashRoyaleBattlelogPlayerResponse],
+        path: &str,
+    ) -> Vec<ConditionCheck> {
+        let mut checks = Vec::new();
 
         if let Some(crowns) = &self.crowns {
-            let team_crowns = match players[0].crowns {
-                Some(v) => v,
-                None => return false,
+            let team_crowns = players[0].crowns;
+            let passed = match team_crowns {
+                Some(v) => crowns.verify(&v),
+                None => false,
             };
 
-            if !crowns.verify(&team_crowns) {
-                return false;
-            }
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "crowns"),
+                passed,
+                crowns,
+                team_crowns.as_ref(),
+            ));
         }
 
         if let Some(starting_trophies) = &self.starting_trophies {
-            if !starting_trophies.verify(&team_starting_trophies) {
-                return false;
+            let team_starting_trophies =
+                ClashRoyaleBattlelogPlayerResponse::total_starting_trophies(players);
+            let passed = match &team_starting_trophies {
+                Some(v) => starting_trophies.verify(v),
+                None => false,
+            };
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "startingTrophies"),
+                passed,
+                starting_trophies,
+                team_starting_trophies.as_ref(),
+            ));
+        }
+
+        if let Some(king_tower_hit_points) = &self.king_tower_hit_points {
+            let team_hit_points: u32 = players
+                .iter()
+                .map(|v| v.remaining_king_tower_hit_points())
+                .sum();
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "kingTowerHitPoints"),
+                king_tower_hit_points.verify(&team_hit_points),
+                king_tower_hit_points,
+                Some(&team_hit_points),
+            ));
+        }
+
+        let princess_towers = [
+            (
+                &self.princess_tower_1_hit_points,
+                players
+                    .iter()
+                    .map(|v| v.max_princess_tower_hit_points())
+                    .sum::<u32>(),
+                "princessTower1HitPoints",
+            ),
+            (
+                &self.princess_tower_2_hit_points,
+                players
+                    .iter()
+                    .map(|v| v.min_princess_tower_hit_points())
+                    .sum::<u32>(),
+                "princessTower2HitPoints",
+            ),
+        ];
+
+        for (condition, team_hit_points, name) in princess_towers.iter() {
+            if let Some(condition) = condition {
+                checks.push(ConditionCheck::new_with_values(
+                    &ConditionCheck::join_path(path, name),
+                    condition.verify(team_hit_points),
+                    condition,
+                    Some(team_hit_points),
+                ));
             }
         }
 
         if let Some(allowed_cards) = &self.allowed_cards {
+            let cards_path = ConditionCheck::join_path(path, "allowedCards");
+
             for player in players {
-                if !allowed_cards.verify_allowed_response(battlelog, player) {
-                    return false;
-                }
+                checks.extend(allowed_cards.check_allowed_response(battlelog, player, &cards_path));
             }
         }
 
         if let Some(forbidden_cards) = &self.forbidden_cards {
+            let cards_path = ConditionCheck::join_path(path, "forbiddenCards");
+
             for player in players {
-                if !forbidden_cards.verify_forbidden_response(battlelog, player) {
-                    return false;
-                }
+                checks.extend(forbidden_cards.check_forbidden_response(
+                    battlelog,
+                    player,
+                    &cards_path,
+                ));
+            }
+        }
+
+        if let Some(deck) = &self.deck {
+            for (index, player) in players.iter().enumerate() {
+                let deck_path = format!("{}[{}]", ConditionCheck::join_path(path, "deck"), index);
+                checks.extend(deck.check_response(battlelog, player, &deck_path));
             }
         }
 
+        let first_member_path = ConditionCheck::join_path(path, "firstMember");
+        let second_member_path = ConditionCheck::join_path(path, "secondMember");
+
         if players.len() == 1 {
             if let Some(first_member) = &self.first_member {
-                if !first_member.verify_response(battlelog, &players[0]) {
-                    return false;
-                }
+                checks.extend(first_member.check_response(
+                    battlelog,
+                    &players[0],
+                    &first_member_path,
+                ));
             }
         } else {
             match (&self.first_member, &self.second_member) {
                 (Some(first_member), Some(second_member)) => {
-                    if !first_member.verify_response(battlelog, &players[0]) {
-                        if !second_member.verify_response(battlelog, &players[0]) {
-                            return false;
-                        }
+                    // The members can be in any order.
+                    let first_checks =
+                        first_member.check_response(battlelog, &players[0], &first_member_path);
+
+                    if ConditionCheck::all_passed(&first_checks) {
+                        checks.extend(first_checks);
+                        checks.extend(second_member.check_response(
+                            battlelog,
+                            &players[1],
+                            &second_member_path,
+                        ));
+                    } else {
+                        let second_checks = second_member.check_response(
+                            battlelog,
+                            &players[0],
+                            &second_member_path,
+                        );
 
-                        if !first_member.verify_response(battlelog, &players[1]) {
-                            return false;
+                        if ConditionCheck::all_passed(&second_checks) {
+                            checks.extend(second_checks);
+                            checks.extend(first_member.check_response(
+                                battlelog,
+                                &players[1],
+                                &first_member_path,
+                            ));
+                        } else {
+                            checks.extend(first_checks);
+                            checks.extend(second_checks);
                         }
-                    } else if !second_member.verify_response(battlelog, &players[1]) {
-                        return false;
                     }
                 }
                 (None, None) => {}
-                _ => return false,
+                (Some(_), None) => checks.push(ConditionCheck::new(&second_member_path, false)),
+                (None, Some(_)) => checks.push(ConditionCheck::new(&first_member_path, false)),
             }
         }
 
-        true
+        checks
     }
 }
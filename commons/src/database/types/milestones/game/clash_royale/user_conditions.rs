@@ -1,14 +1,27 @@
+use std::convert::TryFrom;
+
 use arcstr::ArcStr;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
+use enum_derive::EnumList;
+
+use crate::clients::games::ClashRoyalePlayerInfoResponse;
 use crate::data::games::ClashRoyaleArena;
-use crate::database::types::conditions::OrderedCondition;
+use crate::database::types::conditions::{ConditionCheck, OrderedCondition};
 use crate::database::types::game::ClashRoyaleCardConditions;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ClashRoyaleUserConditions {
+    /// How the numeric statistics are measured.
+    /// Note: `current_arena` and the card conditions are always measured in absolute values.
+    /// Note: bets cannot be placed while the absolute conditions are already met.
+    /// Missing: absolute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub measure: Option<ClashRoyaleStatisticMeasure>,
+
     /// The level of the user.
     /// Missing: ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -116,4 +129,240 @@ impl ClashRoyaleUserConditions {
 
         None
     }
+
+    /// Whether the conditions are measured in absolute values and the player already meets
+    /// them, i.e. a bet would be won without playing.
+    pub fn is_already_met(&self, player: &ClashRoyalePlayerInfoResponse) -> bool {
+        let measure = self
+            .measure
+            .unwrap_or(ClashRoyaleStatisticMeasure::Absolute);
+
+        measure == ClashRoyaleStatisticMeasure::Absolute && self.verify_response(player, None)
+    }
+
+    /// Verifies the conditions against the current state of the player.
+    /// `snapshot` is the state of the player when the bet was created and it is required
+    /// to measure increments.
+    pub fn verify_response(
+        &self,
+        player: &ClashRoyalePlayerInfoResponse,
+        snapshot: Option<&ClashRoyalePlayerInfoResponse>,
+    ) -> bool {
+        ConditionCheck::all_passed(&self.check_response(player, snapshot, ""))
+    }
+
+    /// Evaluates the conditions against the current state of the player, tracing every
+    /// condition with the measured value.
+    pub fn check_response(
+        &self,
+        player: &ClashRoyalePlayerInfoResponse,
+        snapshot: Option<&ClashRoyalePlayerInfoResponse>,
+        path: &str,
+    ) -> Vec<ConditionCheck> {
+        let mut checks = Vec::new();
+        let measure = self
+            .measure
+            .unwrap_or(ClashRoyaleStatisticMeasure::Absolute);
+        let snapshot = match measure {
+            ClashRoyaleStatisticMeasure::Absolute => None,
+            ClashRoyaleStatisticMeasure::Increment => match snapshot {
+                Some(v) => Some(v),
+                None => {
+                    checks.push(ConditionCheck::new_with_values::<_, ()>(
+                        &ConditionCheck::join_path(path, "measure"),
+                        false,
+                        &measure,
+                        None,
+                    ));
+                    return checks;
+                }
+            },
+        };
+
+        if let Some(exp_level) = &self.exp_level {
+            let current = player.exp_level.map(u64::from);
+            let initial = snapshot.map(|v| v.exp_level.map(u64::from));
+            let value =
+                measure_statistic(current, initial).map(|v| u8::try_from(v).unwrap_or(u8::MAX));
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "expLevel"),
+                matches!(value, Some(v) if exp_level.verify(&v)),
+                exp_level,
+                value.as_ref(),
+            ));
+        }
+
+        let statistics = [
+            (
+                "trophies",
+                &self.trophies,
+                player.trophies,
+                snapshot.map(|v| v.trophies),
+            ),
+            (
+                "bestTrophies",
+                &self.best_trophies,
+                player.best_trophies,
+                snapshot.map(|v| v.best_trophies),
+            ),
+            ("wins", &self.wins, player.wins, snapshot.map(|v| v.wins)),
+            (
+                "threeCrownsWins",
+                &self.three_crowns_wins,
+                player.three_crown_wins,
+                snapshot.map(|v| v.three_crown_wins),
+            ),
+            (
+                "losses",
+                &self.losses,
+                player.losses,
+                snapshot.map(|v| v.losses),
+            ),
+            (
+                "battleCount",
+                &self.battle_count,
+                player.battle_count,
+                snapshot.map(|v| v.battle_count),
+            ),
+            (
+                "challengeCardsWon",
+                &self.challenge_cards_won,
+                player.challenge_cards_won,
+                snapshot.map(|v| v.challenge_cards_won),
+            ),
+            (
+                "challengeMaxWins",
+                &self.challenge_max_wins,
+                player.challenge_max_wins,
+                snapshot.map(|v| v.challenge_max_wins),
+            ),
+            (
+                "tournamentCardsWon",
+                &self.tournament_cards_won,
+                player.tournament_cards_won,
+                snapshot.map(|v| v.tournament_cards_won),
+            ),
+            (
+                "tournamentBattleCount",
+                &self.tournament_battle_count,
+                player.tournament_battle_count,
+                snapshot.map(|v| v.tournament_battle_count),
+            ),
+            (
+                "donations",
+                &self.donations,
+                player.donations,
+                snapshot.map(|v| v.donations),
+            ),
+            (
+                "donationsReceived",
+                &self.donations_received,
+                player.donations_received,
+                snapshot.map(|v| v.donations_received),
+            ),
+            (
+                "totalDonations",
+                &self.total_donations,
+                player.total_donations,
+                snapshot.map(|v| v.total_donations),
+            ),
+            (
+                "warDayWins",
+                &self.war_day_wins,
+                player.war_day_wins,
+                snapshot.map(|v| v.war_day_wins),
+            ),
+            (
+                "clanCardsCollected",
+                &self.clan_cards_collected,
+                player.clan_cards_collected,
+                snapshot.map(|v| v.clan_cards_collected),
+            ),
+        ];
+
+        for (name, condition, current, initial) in statistics {
+            if let Some(condition) = condition {
+                let value = measure_statistic(current, initial);
+
+                checks.push(ConditionCheck::new_with_values(
+                    &ConditionCheck::join_path(path, name),
+                    matches!(value, Some(v) if condition.verify(&v)),
+                    condition,
+                    value.as_ref(),
+                ));
+            }
+        }
+
+        if let Some(current_arena) = &self.current_arena {
+            let arena = player
+                .arena
+                .as_ref()
+                .and_then(|v| ClashRoyaleArena::resolve(v.id, player.trophies));
+
+            checks.push(ConditionCheck::new_with_values(
+                &ConditionCheck::join_path(path, "currentArena"),
+                matches!(arena, Some(v) if current_arena.verify(&v)),
+                current_arena,
+                arena.as_ref(),
+            ));
+        }
+
+        let collections = [
+            ("allowedCards", &self.allowed_cards, true),
+            ("forbiddenCards", &self.forbidden_cards, false),
+        ];
+
+        for (name, condition, allowed) in collections {
+            if let Some(condition) = condition {
+                let condition_path = ConditionCheck::join_path(path, name);
+
+                match &player.cards {
+                    Some(cards) => {
+                        if allowed {
+                            checks
+                                .extend(condition.check_allowed_collection(cards, &condition_path));
+                        } else {
+                            checks.extend(
+                                condition.check_forbidden_collection(cards, &condition_path),
+                            );
+                        }
+                    }
+                    None => checks.push(ConditionCheck::new(&condition_path, false)),
+                }
+            }
+        }
+
+        checks
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, EnumList)]
+#[serde(rename_all = "camelCase")]
+pub enum ClashRoyaleStatisticMeasure {
+    /// The conditions are applied to the value of the statistic.
+    Absolute,
+
+    /// The conditions are applied to the increment of the statistic since the bet was created.
+    /// Note: a decrement is considered a zero increment.
+    Increment,
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Gets the value to compare against the condition. `initial` must only be present when
+/// measuring increments. None if the value cannot be measured, e.g. the snapshot lacks it.
+fn measure_statistic(current: Option<u64>, initial: Option<Option<u64>>) -> Option<u64> {
+    let current = current?;
+
+    match initial {
+        Some(initial) => Some(current.saturating_sub(initial?)),
+        None => Some(current),
+    }
 }
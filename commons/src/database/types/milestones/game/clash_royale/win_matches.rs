@@ -0,0 +1,117 @@
+use arcstr::ArcStr;
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::database::types::game::{
+    ClashRoyaleMatchConditions, NonQualifyingMatchPolicy, WinMatchesMode,
+};
+
+/// The maximum number of matches of the milestone.
+pub(crate) const MAX_WIN_MATCHES: usize = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "ClashRoyaleWinMatchesFormat")]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRoyaleWinMatches {
+    /// How the matches are paired with the conditions.
+    /// Missing: first N.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<WinMatchesMode>,
+
+    /// What to do with the matches that do not fulfill the next condition.
+    /// Missing: ignore.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_qualifying: Option<NonQualifyingMatchPolicy>,
+
+    /// The conditions of each match in order.
+    pub matches: Vec<ClashRoyaleMatchConditions>,
+}
+
+impl ClashRoyaleWinMatches {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn validate(&self) -> Option<ArcStr> {
+        if self.matches.is_empty() {
+            return Some(arcstr::literal!(
+                "Clash Royale 'win matches' milestone without conditions"
+            ));
+        }
+
+        if self.matches.len() > MAX_WIN_MATCHES {
+            return Some(ArcStr::from(format!(
+                "Clash Royale 'win matches' milestone with more than {} matches",
+                MAX_WIN_MATCHES
+            )));
+        }
+
+        for condition in &self.matches {
+            if let Some(error) = condition.validate() {
+                return Some(error);
+            }
+        }
+
+        None
+    }
+}
+
+impl From<Vec<ClashRoyaleMatchConditions>> for ClashRoyaleWinMatches {
+    fn from(matches: Vec<ClashRoyaleMatchConditions>) -> Self {
+        ClashRoyaleWinMatches {
+            mode: None,
+            non_qualifying: None,
+            matches,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Accepts the legacy format, i.e. the list of conditions alone, besides the current one.
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum ClashRoyaleWinMatchesFormat {
+    /// Legacy format: the conditions of each match alone.
+    Legacy(#[schemars(length(min = 1))] Vec<ClashRoyaleMatchConditions>),
+    Current {
+        mode: Option<WinMatchesMode>,
+
+        #[serde(rename = "nonQualifying")]
+        non_qualifying: Option<NonQualifyingMatchPolicy>,
+
+        #[schemars(length(min = 1))]
+        matches: Vec<ClashRoyaleMatchConditions>,
+    },
+}
+
+// The schema describes every accepted format.
+impl JsonSchema for ClashRoyaleWinMatches {
+    fn schema_name() -> String {
+        "ClashRoyaleWinMatches".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        ClashRoyaleWinMatchesFormat::json_schema(generator)
+    }
+}
+
+impl From<ClashRoyaleWinMatchesFormat> for ClashRoyaleWinMatches {
+    fn from(format: ClashRoyaleWinMatchesFormat) -> Self {
+        match format {
+            ClashRoyaleWinMatchesFormat::Legacy(matches) => matches.into(),
+            ClashRoyaleWinMatchesFormat::Current {
+                mode,
+                non_qualifying,
+                matches,
+            } => ClashRoyaleWinMatches {
+                mode,
+                non_qualifying,
+                matches,
+            },
+        }
+    }
+}
@@ -1,25 +1,62 @@
 use arcstr::ArcStr;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
+pub use brawl_stars::*;
 pub use clash_royale::*;
+pub use win_matches::*;
 
 use crate::database::traits::{DBNormalize, DBNormalizeResult};
+use crate::database::types::rules::RuleError;
+use crate::database::types::GameType;
 
+mod brawl_stars;
 mod clash_royale;
+mod win_matches;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "game", content = "challenge")]
 pub enum GameMilestone {
     ClashRoyale(ClashRoyaleMilestone),
+    BrawlStars(BrawlStarsMilestone),
 }
 
 impl GameMilestone {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Parses a milestone written as a rule. Only Clash Royale supports rules.
+    pub fn from_rule(rule: &str) -> Result<Self, RuleError> {
+        Ok(GameMilestone::ClashRoyale(ClashRoyaleMilestone::from_rule(
+            rule,
+        )?))
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn game_type(&self) -> GameType {
+        match self {
+            GameMilestone::ClashRoyale(_) => GameType::ClashRoyale,
+            GameMilestone::BrawlStars(_) => GameType::BrawlStars,
+        }
+    }
+
     // METHODS ----------------------------------------------------------------
 
     pub fn validate(&self) -> Option<ArcStr> {
         match self {
             GameMilestone::ClashRoyale(v) => v.validate(),
+            GameMilestone::BrawlStars(v) => v.validate(),
+        }
+    }
+
+    /// Writes the milestone as a rule, failing if it has conditions that cannot be written.
+    pub fn to_rule(&self) -> Result<String, ArcStr> {
+        match self {
+            GameMilestone::ClashRoyale(v) => v.to_rule(),
+            GameMilestone::BrawlStars(_) => Err(arcstr::literal!(
+                "Brawl Stars milestones cannot be written in a rule"
+            )),
         }
     }
 }
@@ -28,6 +65,7 @@ impl DBNormalize for GameMilestone {
     fn normalize(&mut self) -> DBNormalizeResult {
         match self {
             GameMilestone::ClashRoyale(v) => v.normalize(),
+            GameMilestone::BrawlStars(v) => v.normalize(),
         }
     }
 }
@@ -0,0 +1,54 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use enum_derive::EnumList;
+
+use crate::database::traits::{DBNormalize, DBNormalizeResult};
+
+/// How the matches played are paired with the conditions of a 'win matches' milestone.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, EnumList)]
+#[serde(rename_all = "camelCase")]
+pub enum WinMatchesMode {
+    /// The first N matches must fulfill the conditions in order.
+    #[serde(rename = "firstN")]
+    FirstN,
+
+    /// Any N matches must fulfill the conditions in order, i.e. the matches that do not
+    /// fulfill the next condition are treated depending on the non-qualifying policy.
+    #[serde(rename = "anyN")]
+    AnyN,
+
+    /// N consecutive matches must fulfill the conditions in order, i.e. a match that does
+    /// not fulfill the next condition restarts the streak unless the non-qualifying
+    /// policy fails the milestone.
+    Consecutive,
+}
+
+impl DBNormalize for WinMatchesMode {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// What to do with the matches that do not fulfill the next condition once the first
+/// qualifying match has been played. Does not apply to `WinMatchesMode::FirstN`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, EnumList)]
+#[serde(rename_all = "camelCase")]
+pub enum NonQualifyingMatchPolicy {
+    /// The match is skipped, or restarts the streak in consecutive mode.
+    Ignore,
+
+    /// The milestone fails.
+    Fail,
+}
+
+impl DBNormalize for NonQualifyingMatchPolicy {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
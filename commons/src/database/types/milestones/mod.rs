@@ -1,2 +1,7 @@
+pub use composite::*;
+
+mod composite;
 pub mod conditions;
 pub mod game;
+pub mod rules;
+pub mod schema;
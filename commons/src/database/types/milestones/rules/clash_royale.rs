@@ -0,0 +1,216 @@
+use arcstr::ArcStr;
+
+use crate::database::types::conditions::{DayTimeRange, TimeWindowCondition};
+use crate::database::types::game::{
+    ClashRoyaleCardConditions, ClashRoyaleDeckConditions, ClashRoyaleMatchConditions,
+    ClashRoyaleRelativeConditions, ClashRoyaleStatisticMeasure, ClashRoyaleStatisticsConditions,
+    ClashRoyaleTeamConditions, ClashRoyaleTeamMemberConditions, ClashRoyaleUserConditions,
+};
+use crate::database::types::rules::fields::{
+    apply_single_value, apply_unsupported, print_single_value, print_unsupported, RuleCondition,
+    RuleConditions, RuleField,
+};
+use crate::database::types::rules::RuleError;
+
+impl RuleConditions for ClashRoyaleMatchConditions {
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn RuleField)> {
+        vec![
+            ("result", &mut self.result),
+            ("allowFriends", &mut self.allow_friends),
+            ("battleKind", &mut self.battle_kind),
+            ("arena", &mut self.arena),
+            ("gameMode", &mut self.game_mode),
+            ("timeWindow", &mut self.time_window),
+            ("team", &mut self.team),
+            ("opponent", &mut self.opponent),
+            ("relative", &mut self.relative),
+        ]
+    }
+}
+
+impl RuleConditions for ClashRoyaleTeamConditions {
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn RuleField)> {
+        vec![
+            ("crowns", &mut self.crowns),
+            ("trophies", &mut self.starting_trophies),
+            ("kingTowerHitPoints", &mut self.king_tower_hit_points),
+            (
+                "princessTower1HitPoints",
+                &mut self.princess_tower_1_hit_points,
+            ),
+            (
+                "princessTower2HitPoints",
+                &mut self.princess_tower_2_hit_points,
+            ),
+            ("allowedCards", &mut self.allowed_cards),
+            ("forbiddenCards", &mut self.forbidden_cards),
+            ("deck", &mut self.deck),
+            ("firstMember", &mut self.first_member),
+            ("secondMember", &mut self.second_member),
+        ]
+    }
+}
+
+impl RuleConditions for ClashRoyaleTeamMemberConditions {
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn RuleField)> {
+        vec![
+            ("trophies", &mut self.starting_trophies),
+            ("kingTowerHitPoints", &mut self.king_tower_hit_points),
+            (
+                "princessTower1HitPoints",
+                &mut self.princess_tower_1_hit_points,
+            ),
+            (
+                "princessTower2HitPoints",
+                &mut self.princess_tower_2_hit_points,
+            ),
+            ("allowedCards", &mut self.allowed_cards),
+            ("forbiddenCards", &mut self.forbidden_cards),
+            ("deck", &mut self.deck),
+        ]
+    }
+}
+
+impl RuleConditions for ClashRoyaleDeckConditions {
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn RuleField)> {
+        vec![
+            ("averageElixir", &mut self.average_elixir),
+            ("commonCards", &mut self.common_cards),
+            ("rareCards", &mut self.rare_cards),
+            ("epicCards", &mut self.epic_cards),
+            ("legendaryCards", &mut self.legendary_cards),
+            ("championCards", &mut self.champion_cards),
+            ("troopCards", &mut self.troop_cards),
+            ("buildingCards", &mut self.building_cards),
+            ("spellCards", &mut self.spell_cards),
+            ("winCondition", &mut self.win_condition),
+        ]
+    }
+}
+
+impl RuleConditions for ClashRoyaleRelativeConditions {
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn RuleField)> {
+        vec![
+            ("trophyDifference", &mut self.trophy_difference),
+            ("cardLevelDifference", &mut self.card_level_difference),
+            ("allowClanMates", &mut self.allow_clan_mates),
+            ("allowRepeatedOpponents", &mut self.allow_repeated_opponents),
+        ]
+    }
+}
+
+impl RuleConditions for TimeWindowCondition {
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn RuleField)> {
+        vec![
+            ("utcOffset", &mut self.utc_offset),
+            ("dayTimes", &mut self.day_times),
+            ("weekdays", &mut self.weekdays),
+        ]
+    }
+}
+
+impl RuleConditions for ClashRoyaleUserConditions {
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn RuleField)> {
+        vec![
+            ("measure", &mut self.measure),
+            ("expLevel", &mut self.exp_level),
+            ("trophies", &mut self.trophies),
+            ("bestTrophies", &mut self.best_trophies),
+            ("wins", &mut self.wins),
+            ("threeCrownsWins", &mut self.three_crowns_wins),
+            ("losses", &mut self.losses),
+            ("battleCount", &mut self.battle_count),
+            ("challengeCardsWon", &mut self.challenge_cards_won),
+            ("challengeMaxWins", &mut self.challenge_max_wins),
+            ("tournamentCardsWon", &mut self.tournament_cards_won),
+            ("tournamentBattleCount", &mut self.tournament_battle_count),
+            ("donations", &mut self.donations),
+            ("donationsReceived", &mut self.donations_received),
+            ("totalDonations", &mut self.total_donations),
+            ("warDayWins", &mut self.war_day_wins),
+            ("clanCardsCollected", &mut self.clan_cards_collected),
+            ("currentArena", &mut self.current_arena),
+            ("allowedCards", &mut self.allowed_cards),
+            ("forbiddenCards", &mut self.forbidden_cards),
+        ]
+    }
+}
+
+impl RuleConditions for ClashRoyaleStatisticsConditions {
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn RuleField)> {
+        vec![
+            ("battleKind", &mut self.battle_kind),
+            ("gameMode", &mut self.game_mode),
+            ("matchCount", &mut self.match_count),
+            ("wins", &mut self.wins),
+            ("winRate", &mut self.win_rate),
+            ("crowns", &mut self.crowns),
+            ("distinctCards", &mut self.distinct_cards),
+            ("minMatches", &mut self.min_matches),
+        ]
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl RuleField for Option<bool> {
+    fn apply(&mut self, path: &str, condition: &RuleCondition) -> Result<(), RuleError> {
+        apply_single_value(self, path, condition)
+    }
+
+    fn print(&self, path: &str, output: &mut Vec<String>) -> Result<(), ArcStr> {
+        print_single_value(self, path, output)
+    }
+}
+
+impl RuleField for Option<u32> {
+    fn apply(&mut self, path: &str, condition: &RuleCondition) -> Result<(), RuleError> {
+        apply_single_value(self, path, condition)
+    }
+
+    fn print(&self, path: &str, output: &mut Vec<String>) -> Result<(), ArcStr> {
+        print_single_value(self, path, output)
+    }
+}
+
+impl RuleField for Option<i32> {
+    fn apply(&mut self, path: &str, condition: &RuleCondition) -> Result<(), RuleError> {
+        apply_single_value(self, path, condition)
+    }
+
+    fn print(&self, path: &str, output: &mut Vec<String>) -> Result<(), ArcStr> {
+        print_single_value(self, path, output)
+    }
+}
+
+impl RuleField for Option<ClashRoyaleStatisticMeasure> {
+    fn apply(&mut self, path: &str, condition: &RuleCondition) -> Result<(), RuleError> {
+        apply_single_value(self, path, condition)
+    }
+
+    fn print(&self, path: &str, output: &mut Vec<String>) -> Result<(), ArcStr> {
+        print_single_value(self, path, output)
+    }
+}
+
+impl RuleField for Option<ClashRoyaleCardConditions> {
+    fn apply(&mut self, _path: &str, condition: &RuleCondition) -> Result<(), RuleError> {
+        apply_unsupported(condition)
+    }
+
+    fn print(&self, path: &str, _output: &mut Vec<String>) -> Result<(), ArcStr> {
+        print_unsupported(self, path)
+    }
+}
+
+impl RuleField for Option<Vec<DayTimeRange>> {
+    fn apply(&mut self, _path: &str, condition: &RuleCondition) -> Result<(), RuleError> {
+        apply_unsupported(condition)
+    }
+
+    fn print(&self, path: &str, _output: &mut Vec<String>) -> Result<(), ArcStr> {
+        print_unsupported(self, path)
+    }
+}
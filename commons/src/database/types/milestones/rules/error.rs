@@ -0,0 +1,30 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use arcstr::ArcStr;
+
+/// An error found while parsing a rule.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RuleError {
+    /// The byte offset in the rule where the error was found.
+    pub position: usize,
+
+    /// A human-readable message.
+    pub message: ArcStr,
+}
+
+impl RuleError {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(position: usize, message: ArcStr) -> Self {
+        RuleError { position, message }
+    }
+}
+
+impl Display for RuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl Error for RuleError {}
@@ -0,0 +1,473 @@
+use std::mem::discriminant;
+
+use arcstr::ArcStr;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::database::types::conditions::{OptionCondition, OrderedCondition};
+use crate::database::types::rules::lexer::is_word_char;
+use crate::database::types::rules::RuleError;
+
+/// The names of the values that differ from their serialized form.
+const VALUE_ALIASES: [(&str, &str); 2] = [("1v1", "oneVsOne"), ("2v2", "twoVsTwo")];
+
+/// The names of the fields that differ from their serialized form.
+const FIELD_ALIASES: [(&str, &str); 1] = [("startingTrophies", "trophies")];
+
+/// The operators of a condition.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RuleOperator {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterOrEqualThan,
+    LowerThan,
+    LowerOrEqualThan,
+    In,
+    NotIn,
+}
+
+impl RuleOperator {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            RuleOperator::Equal => "=",
+            RuleOperator::NotEqual => "!=",
+            RuleOperator::GreaterThan => ">",
+            RuleOperator::GreaterOrEqualThan => ">=",
+            RuleOperator::LowerThan => "<",
+            RuleOperator::LowerOrEqualThan => "<=",
+            RuleOperator::In => "in",
+            RuleOperator::NotIn => "not in",
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A condition of a rule: `path operator value` or `path operator [value, ...]`.
+#[derive(Debug, Clone)]
+pub struct RuleCondition<'a> {
+    pub path: &'a str,
+    pub position: usize,
+    pub operator: RuleOperator,
+    pub operator_position: usize,
+    pub values: Vec<RuleValue>,
+}
+
+impl<'a> RuleCondition<'a> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Converts every value to the type of the field.
+    fn parse_values<T: DeserializeOwned>(&self) -> Result<Vec<T>, RuleError> {
+        self.values
+            .iter()
+            .map(|v| {
+                serde_json::from_value(v.value.clone()).map_err(|_| {
+                    RuleError::new(
+                        v.position,
+                        ArcStr::from(format!(
+                            "Invalid value {} for '{}'",
+                            print_value(&v.value).unwrap_or_default(),
+                            self.path
+                        )),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn unsupported_operator(&self) -> RuleError {
+        RuleError::new(
+            self.operator_position,
+            ArcStr::from(format!(
+                "Operator '{}' not supported for '{}'",
+                self.operator.symbol(),
+                self.path
+            )),
+        )
+    }
+
+    fn duplicated(&self) -> RuleError {
+        RuleError::new(
+            self.position,
+            ArcStr::from(format!("Duplicated condition for '{}'", self.path)),
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A value of a condition and its byte offset in the rule.
+#[derive(Debug, Clone)]
+pub struct RuleValue {
+    pub value: serde_json::Value,
+    pub position: usize,
+}
+
+impl RuleValue {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds the value from an unquoted word, i.e. a number, a boolean or a name.
+    pub fn from_word(word: &str, position: usize) -> Self {
+        let starts_as_number = word.starts_with(|c: char| c.is_ascii_digit() || c == '-');
+        let value = if let Ok(v) = word.parse::<u64>() {
+            serde_json::Value::from(v)
+        } else if let Ok(v) = word.parse::<i64>() {
+            serde_json::Value::from(v)
+        } else if let (true, Ok(v)) = (starts_as_number, word.parse::<f64>()) {
+            serde_json::Value::from(v)
+        } else if let Ok(v) = word.parse::<bool>() {
+            serde_json::Value::from(v)
+        } else {
+            let name = VALUE_ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == word)
+                .map_or(word, |(_, name)| *name);
+            serde_json::Value::from(name)
+        };
+
+        RuleValue { value, position }
+    }
+
+    /// Builds the value from a quoted text.
+    pub fn from_text(text: String, position: usize) -> Self {
+        RuleValue {
+            value: serde_json::Value::from(text),
+            position,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A field of a set of conditions that can be written in a rule.
+pub trait RuleField {
+    /// Applies a condition to the field. `path` is the remaining path after the field,
+    /// empty for the fields that are not a set of conditions.
+    fn apply(&mut self, path: &str, condition: &RuleCondition) -> Result<(), RuleError>;
+
+    /// Writes the conditions of the field as `path operator value`.
+    fn print(&self, path: &str, output: &mut Vec<String>) -> Result<(), ArcStr>;
+}
+
+/// A set of conditions that can be written in a rule.
+pub trait RuleConditions: Default + Clone {
+    /// The fields of the conditions by name.
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn RuleField)>;
+}
+
+impl<S: RuleConditions> RuleField for Option<S> {
+    fn apply(&mut self, path: &str, condition: &RuleCondition) -> Result<(), RuleError> {
+        if path.is_empty() {
+            return Err(RuleError::new(
+                condition.position,
+                ArcStr::from(format!(
+                    "'{}' is a set of conditions, use one of its fields",
+                    condition.path
+                )),
+            ));
+        }
+
+        apply_condition(self.get_or_insert_with(S::default), path, condition)
+    }
+
+    fn print(&self, path: &str, output: &mut Vec<String>) -> Result<(), ArcStr> {
+        match self {
+            Some(conditions) => print_conditions(conditions, path, output),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: PartialOrd + Serialize + DeserializeOwned> RuleField for Option<OrderedCondition<T>> {
+    fn apply(&mut self, path: &str, condition: &RuleCondition) -> Result<(), RuleError> {
+        check_leaf(path, condition)?;
+
+        let values = condition.parse_values()?;
+        let new_condition = match condition.operator {
+            RuleOperator::Equal | RuleOperator::In => OrderedCondition::AnyOf(values),
+            RuleOperator::NotEqual | RuleOperator::NotIn => OrderedCondition::NoneOf(values),
+            RuleOperator::GreaterThan => OrderedCondition::GreaterThan(values),
+            RuleOperator::GreaterOrEqualThan => OrderedCondition::GreaterOrEqualThan(values),
+            RuleOperator::LowerThan => OrderedCondition::LowerThan(values),
+            RuleOperator::LowerOrEqualThan => OrderedCondition::LowerOrEqualThan(values),
+        };
+
+        match self {
+            // Repeated alternatives cannot be merged, e.g. `a = 1 and a = 2` is not `a in [1, 2]`.
+            Some(OrderedCondition::AnyOf(_)) => return Err(condition.duplicated()),
+            Some(current) if discriminant(current) == discriminant(&new_condition) => {
+                // Repeated comparisons are merged, e.g. `a > 1 and a > 2`.
+                let values = match new_condition {
+                    OrderedCondition::AnyOf(v)
+                    | OrderedCondition::NoneOf(v)
+                    | OrderedCondition::GreaterThan(v)
+                    | OrderedCondition::GreaterOrEqualThan(v)
+                    | OrderedCondition::LowerThan(v)
+                    | OrderedCondition::LowerOrEqualThan(v) => v,
+                };
+
+                match current {
+                    OrderedCondition::AnyOf(v)
+                    | OrderedCondition::NoneOf(v)
+                    | OrderedCondition::GreaterThan(v)
+                    | OrderedCondition::GreaterOrEqualThan(v)
+                    | OrderedCondition::LowerThan(v)
+                    | OrderedCondition::LowerOrEqualThan(v) => v.extend(values),
+                }
+            }
+            Some(_) => return Err(condition.duplicated()),
+            None => *self = Some(new_condition),
+        }
+
+        Ok(())
+    }
+
+    fn print(&self, path: &str, output: &mut Vec<String>) -> Result<(), ArcStr> {
+        let condition = match self {
+            Some(v) => v,
+            None => return Ok(()),
+        };
+
+        let (operator, list_operator) = match condition {
+            OrderedCondition::AnyOf(_) => (RuleOperator::Equal, Some(RuleOperator::In)),
+            OrderedCondition::NoneOf(_) => (RuleOperator::NotEqual, Some(RuleOperator::NotIn)),
+            OrderedCondition::GreaterThan(_) => (RuleOperator::GreaterThan, None),
+            OrderedCondition::GreaterOrEqualThan(_) => (RuleOperator::GreaterOrEqualThan, None),
+            OrderedCondition::LowerThan(_) => (RuleOperator::LowerThan, None),
+            OrderedCondition::LowerOrEqualThan(_) => (RuleOperator::LowerOrEqualThan, None),
+        };
+
+        print_list(path, operator, list_operator, condition.value(), output)
+    }
+}
+
+impl<T: PartialEq + Serialize + DeserializeOwned> RuleField for Option<OptionCondition<T>> {
+    fn apply(&mut self, path: &str, condition: &RuleCondition) -> Result<(), RuleError> {
+        check_leaf(path, condition)?;
+
+        let values = condition.parse_values()?;
+        let new_condition = match condition.operator {
+            RuleOperator::Equal | RuleOperator::In => OptionCondition::AnyOf(values),
+            RuleOperator::NotEqual | RuleOperator::NotIn => OptionCondition::NoneOf(values),
+            _ => return Err(condition.unsupported_operator()),
+        };
+
+        match (self.as_mut(), new_condition) {
+            // Repeated exclusions are merged, e.g. `a != 1 and a != 2`, but repeated
+            // alternatives cannot be, e.g. `a = 1 and a = 2` is not `a in [1, 2]`.
+            (Some(OptionCondition::NoneOf(current)), OptionCondition::NoneOf(values)) => {
+                current.extend(values)
+            }
+            (Some(_), _) => return Err(condition.duplicated()),
+            (None, new_condition) => *self = Some(new_condition),
+        }
+
+        Ok(())
+    }
+
+    fn print(&self, path: &str, output: &mut Vec<String>) -> Result<(), ArcStr> {
+        match self {
+            Some(OptionCondition::AnyOf(values)) => print_list(
+                path,
+                RuleOperator::Equal,
+                Some(RuleOperator::In),
+                values,
+                output,
+            ),
+            Some(OptionCondition::NoneOf(values)) => print_list(
+                path,
+                RuleOperator::NotEqual,
+                Some(RuleOperator::NotIn),
+                values,
+                output,
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Applies a condition to the field of `conditions` pointed by `path`.
+pub fn apply_condition<S: RuleConditions>(
+    conditions: &mut S,
+    path: &str,
+    condition: &RuleCondition,
+) -> Result<(), RuleError> {
+    let (name, remaining_path) = match path.find('.') {
+        Some(index) => (&path[..index], &path[index + 1..]),
+        None => (path, ""),
+    };
+    let name = FIELD_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map_or(name, |(_, name)| *name);
+
+    match conditions.fields().into_iter().find(|(v, _)| *v == name) {
+        Some((_, field)) => field.apply(remaining_path, condition),
+        None => Err(RuleError::new(
+            condition.position,
+            ArcStr::from(format!("Unknown field '{}'", condition.path)),
+        )),
+    }
+}
+
+/// Writes every condition of `conditions` prefixing their paths with `path`.
+pub fn print_conditions<S: RuleConditions>(
+    conditions: &S,
+    path: &str,
+    output: &mut Vec<String>,
+) -> Result<(), ArcStr> {
+    let mut conditions = conditions.clone();
+
+    for (name, field) in conditions.fields() {
+        if path.is_empty() {
+            field.print(name, output)?;
+        } else {
+            field.print(&format!("{}.{}", path, name), output)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies a condition to a field that only accepts a single value.
+pub fn apply_single_value<T: DeserializeOwned>(
+    field: &mut Option<T>,
+    path: &str,
+    condition: &RuleCondition,
+) -> Result<(), RuleError> {
+    check_leaf(path, condition)?;
+
+    if condition.operator != RuleOperator::Equal {
+        return Err(condition.unsupported_operator());
+    }
+
+    if field.is_some() {
+        return Err(condition.duplicated());
+    }
+
+    *field = condition.parse_values()?.pop();
+    Ok(())
+}
+
+/// Writes a field that only accepts a single value.
+pub fn print_single_value<T: Serialize>(
+    field: &Option<T>,
+    path: &str,
+    output: &mut Vec<String>,
+) -> Result<(), ArcStr> {
+    match field {
+        Some(value) => print_list(path, RuleOperator::Equal, None, &[value], output),
+        None => Ok(()),
+    }
+}
+
+/// Rejects the conditions on fields that cannot be written in a rule.
+pub fn apply_unsupported(condition: &RuleCondition) -> Result<(), RuleError> {
+    Err(RuleError::new(
+        condition.position,
+        ArcStr::from(format!(
+            "'{}' cannot be written in a rule, use JSON instead",
+            condition.path
+        )),
+    ))
+}
+
+/// Fails when a field that cannot be written in a rule is present.
+pub fn print_unsupported<T>(field: &Option<T>, path: &str) -> Result<(), ArcStr> {
+    match field {
+        Some(_) => Err(ArcStr::from(format!(
+            "'{}' cannot be written in a rule",
+            path
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn check_leaf(path: &str, condition: &RuleCondition) -> Result<(), RuleError> {
+    if path.is_empty() {
+        return Ok(());
+    }
+
+    Err(RuleError::new(
+        condition.position,
+        ArcStr::from(format!("Unknown field '{}'", condition.path)),
+    ))
+}
+
+/// Writes a condition with a single value using `operator` and with many using
+/// `list_operator`, or repeating the condition for each value if missing.
+fn print_list<T: Serialize>(
+    path: &str,
+    operator: RuleOperator,
+    list_operator: Option<RuleOperator>,
+    values: &[T],
+    output: &mut Vec<String>,
+) -> Result<(), ArcStr> {
+    let values = values
+        .iter()
+        .map(|v| print_field_value(v, path))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match list_operator {
+        Some(list_operator) if values.len() != 1 => output.push(format!(
+            "{} {} [{}]",
+            path,
+            list_operator.symbol(),
+            values.join(", ")
+        )),
+        _ => {
+            for value in values {
+                output.push(format!("{} {} {}", path, operator.symbol(), value));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a value of the field pointed by `path`.
+pub fn print_field_value<T: Serialize>(value: &T, path: &str) -> Result<String, ArcStr> {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| print_value(&v))
+        .ok_or_else(|| ArcStr::from(format!("Invalid value for '{}'", path)))
+}
+
+/// Writes a value as it would be read by `RuleValue`, quoting the texts when needed.
+fn print_value(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Bool(v) => Some(v.to_string()),
+        serde_json::Value::Number(v) => Some(v.to_string()),
+        serde_json::Value::String(v) => {
+            let word = VALUE_ALIASES
+                .iter()
+                .find(|(_, name)| name == v)
+                .map_or(v.as_str(), |(alias, _)| *alias);
+
+            // Only the words that would be read as the same text are written unquoted.
+            let is_word = !word.is_empty() && word.chars().all(is_word_char);
+            if is_word && RuleValue::from_word(word, 0).value == *value {
+                Some(word.to_string())
+            } else {
+                Some(format!(
+                    "\"{}\"",
+                    v.replace('\\', "\\\\").replace('"', "\\\"")
+                ))
+            }
+        }
+        _ => None,
+    }
+}
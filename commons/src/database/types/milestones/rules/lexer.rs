@@ -0,0 +1,145 @@
+use arcstr::ArcStr;
+
+use crate::database::types::rules::RuleError;
+
+/// A token of a rule and its byte offset in the rule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token<'a> {
+    pub kind: TokenKind<'a>,
+    pub position: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind<'a> {
+    /// A keyword, field path, number or unquoted value, e.g. `win`, `team.crowns`, `-5`
+    /// or `1v1`.
+    Word(&'a str),
+    /// A quoted value without the quotes and with the escapes resolved.
+    Text(String),
+    /// One of `=`, `!=`, `>`, `>=`, `<`, `<=`, `[`, `]` or `,`.
+    Symbol(&'static str),
+    /// The end of the rule.
+    End,
+}
+
+impl<'a> TokenKind<'a> {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Describes the token in error messages.
+    pub fn describe(&self) -> String {
+        match self {
+            TokenKind::Word(v) => format!("'{}'", v),
+            TokenKind::Text(v) => format!("\"{}\"", v),
+            TokenKind::Symbol(v) => format!("'{}'", v),
+            TokenKind::End => "the end of the rule".to_string(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+const SYMBOLS: [&str; 9] = [">=", "<=", "!=", "=", ">", "<", "[", "]", ","];
+
+/// Whether the char can be part of a word or not.
+pub fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '.'
+}
+
+/// Splits a rule into tokens. The last token is always `TokenKind::End`.
+pub fn tokenize(rule: &str) -> Result<Vec<Token<'_>>, RuleError> {
+    let mut tokens = Vec::new();
+    let mut chars = rule.char_indices().peekable();
+
+    while let Some(&(position, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        // Words, including negative numbers.
+        let is_negative_number = c == '-'
+            && matches!(rule[position + 1..].chars().next(), Some(v) if v.is_ascii_digit());
+
+        if is_word_char(c) || is_negative_number {
+            chars.next();
+
+            let mut end = position + c.len_utf8();
+            while let Some(&(i, c)) = chars.peek() {
+                if !is_word_char(c) {
+                    break;
+                }
+
+                chars.next();
+                end = i + c.len_utf8();
+            }
+
+            tokens.push(Token {
+                kind: TokenKind::Word(&rule[position..end]),
+                position,
+            });
+            continue;
+        }
+
+        // Quoted values.
+        if c == '"' {
+            chars.next();
+
+            let mut text = String::new();
+            let mut closed = false;
+            while let Some((_, c)) = chars.next() {
+                match c {
+                    '"' => {
+                        closed = true;
+                        break;
+                    }
+                    '\\' => match chars.next() {
+                        Some((_, c)) => text.push(c),
+                        None => break,
+                    },
+                    _ => text.push(c),
+                }
+            }
+
+            if !closed {
+                return Err(RuleError::new(
+                    position,
+                    arcstr::literal!("Unterminated quoted value"),
+                ));
+            }
+
+            tokens.push(Token {
+                kind: TokenKind::Text(text),
+                position,
+            });
+            continue;
+        }
+
+        match SYMBOLS.iter().find(|v| rule[position..].starts_with(*v)) {
+            Some(symbol) => {
+                for _ in 0..symbol.len() {
+                    chars.next();
+                }
+
+                tokens.push(Token {
+                    kind: TokenKind::Symbol(symbol),
+                    position,
+                });
+            }
+            None => {
+                return Err(RuleError::new(
+                    position,
+                    ArcStr::from(format!("Unexpected character '{}'", c)),
+                ));
+            }
+        }
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::End,
+        position: rule.len(),
+    });
+
+    Ok(tokens)
+}
@@ -0,0 +1,28 @@
+//! A text language to write milestones, e.g.:
+//!
+//! ```text
+//! win 3 matches in 1v1 where team.crowns >= 2 and opponent.trophies > 5000
+//! win any 2 matches where result = win then 1 match where team.crowns = 3 strict
+//! achieve where measure = increment and wins >= 10
+//! statistics where matchCount >= 10 and winRate >= 60
+//! ```
+//!
+//! The fields are named as in JSON, joined by '.' for the nested conditions, except
+//! `startingTrophies` that is also written as `trophies`. The conditions are written as
+//! `field = value`, `field != value`, `field > value`, `field >= value`, `field < value`,
+//! `field <= value`, `field in [value, ...]` or `field not in [value, ...]`, and joined
+//! with `and`. Values are numbers, booleans, names or quoted texts.
+//!
+//! The card conditions and the day time ranges cannot be written in a rule.
+
+pub use error::*;
+
+mod clash_royale;
+mod error;
+mod fields;
+mod lexer;
+mod parser;
+mod printer;
+
+pub(crate) use parser::parse_clash_royale_rule;
+pub(crate) use printer::print_clash_royale_rule;
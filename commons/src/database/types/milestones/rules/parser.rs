@@ -0,0 +1,338 @@
+use arcstr::ArcStr;
+
+use crate::database::types::game::{
+    ClashRoyaleMatchConditions, ClashRoyaleMilestone, ClashRoyaleStatisticsConditions,
+    ClashRoyaleUserConditions, ClashRoyaleWinMatches, NonQualifyingMatchPolicy, WinMatchesMode,
+    MAX_WIN_MATCHES,
+};
+use crate::database::types::rules::fields::{
+    apply_condition, RuleCondition, RuleConditions, RuleOperator, RuleValue,
+};
+use crate::database::types::rules::lexer::{tokenize, Token, TokenKind};
+use crate::database::types::rules::RuleError;
+
+/// Parses a rule into the milestone it describes.
+pub fn parse_clash_royale_rule(rule: &str) -> Result<ClashRoyaleMilestone, RuleError> {
+    let mut parser = RuleParser::new(rule)?;
+    let milestone = parser.parse_milestone()?;
+    parser.expect_end()?;
+
+    Ok(milestone)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+struct RuleParser<'a> {
+    tokens: Vec<Token<'a>>,
+    index: usize,
+}
+
+impl<'a> RuleParser<'a> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    fn new(rule: &'a str) -> Result<Self, RuleError> {
+        Ok(RuleParser {
+            tokens: tokenize(rule)?,
+            index: 0,
+        })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    fn peek(&self) -> &Token<'a> {
+        &self.tokens[self.index]
+    }
+
+    /// Whether the next token is the keyword or not.
+    fn is_next(&self, keyword: &str) -> bool {
+        matches!(&self.peek().kind, TokenKind::Word(v) if *v == keyword)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    fn next(&mut self) -> Token<'a> {
+        let token = self.tokens[self.index].clone();
+
+        // The last token is always the end.
+        if self.index + 1 < self.tokens.len() {
+            self.index += 1;
+        }
+
+        token
+    }
+
+    /// Consumes the next token if it is the keyword.
+    fn accept(&mut self, keyword: &str) -> bool {
+        if self.is_next(keyword) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, keywords: &[&str]) -> Result<&'a str, RuleError> {
+        let token = self.next();
+
+        match token.kind {
+            TokenKind::Word(v) if keywords.contains(&v) => Ok(v),
+            kind => Err(Self::unexpected(token.position, &kind, keywords)),
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), RuleError> {
+        let token = self.next();
+
+        match token.kind {
+            TokenKind::Symbol(v) if v == symbol => Ok(()),
+            kind => Err(Self::unexpected(token.position, &kind, &[symbol])),
+        }
+    }
+
+    fn expect_end(&mut self) -> Result<(), RuleError> {
+        let token = self.next();
+
+        match token.kind {
+            TokenKind::End => Ok(()),
+            kind => Err(RuleError::new(
+                token.position,
+                ArcStr::from(format!("Unexpected {}", kind.describe())),
+            )),
+        }
+    }
+
+    /// milestone := win_matches | "achieve" "where" conditions | "statistics" "where" conditions
+    fn parse_milestone(&mut self) -> Result<ClashRoyaleMilestone, RuleError> {
+        match self.expect(&["win", "achieve", "statistics"])? {
+            "win" => Ok(ClashRoyaleMilestone::WinMatches(self.parse_win_matches()?)),
+            "achieve" => {
+                self.expect(&["where"])?;
+
+                let mut conditions = ClashRoyaleUserConditions::default();
+                self.parse_conditions(&mut conditions)?;
+
+                Ok(ClashRoyaleMilestone::Achievement(Box::new(conditions)))
+            }
+            _ => {
+                self.expect(&["where"])?;
+
+                let mut conditions = ClashRoyaleStatisticsConditions::default();
+                self.parse_conditions(&mut conditions)?;
+
+                Ok(ClashRoyaleMilestone::Statistics(Box::new(conditions)))
+            }
+        }
+    }
+
+    /// win_matches := "win" [mode] group ("then" group)* ["strict" | "lenient"]
+    /// mode := "first" | "any" | "consecutive"
+    fn parse_win_matches(&mut self) -> Result<ClashRoyaleWinMatches, RuleError> {
+        let mode = if self.accept("first") {
+            Some(WinMatchesMode::FirstN)
+        } else if self.accept("any") {
+            Some(WinMatchesMode::AnyN)
+        } else if self.accept("consecutive") {
+            Some(WinMatchesMode::Consecutive)
+        } else {
+            None
+        };
+
+        let mut matches = Vec::new();
+        loop {
+            self.parse_group(&mut matches)?;
+
+            if !self.accept("then") {
+                break;
+            }
+        }
+
+        let non_qualifying = if self.accept("strict") {
+            Some(NonQualifyingMatchPolicy::Fail)
+        } else if self.accept("lenient") {
+            Some(NonQualifyingMatchPolicy::Ignore)
+        } else {
+            None
+        };
+
+        Ok(ClashRoyaleWinMatches {
+            mode,
+            non_qualifying,
+            matches,
+        })
+    }
+
+    /// group := count ("match" | "matches") ["in" value] ["where" conditions]
+    fn parse_group(
+        &mut self,
+        matches: &mut Vec<ClashRoyaleMatchConditions>,
+    ) -> Result<(), RuleError> {
+        let token = self.next();
+        let count = match token.kind {
+            TokenKind::Word(v) => v.parse::<usize>().ok().filter(|v| *v > 0),
+            _ => None,
+        };
+        let count = match count {
+            Some(v) => v,
+            None => {
+                return Err(RuleError::new(
+                    token.position,
+                    ArcStr::from(format!(
+                        "Expected a number of matches greater than 0 but found {}",
+                        token.kind.describe()
+                    )),
+                ));
+            }
+        };
+
+        // Checked before allocating the matches.
+        if count > MAX_WIN_MATCHES - matches.len() {
+            return Err(RuleError::new(
+                token.position,
+                ArcStr::from(format!(
+                    "The number of matches cannot be greater than {}",
+                    MAX_WIN_MATCHES
+                )),
+            ));
+        }
+
+        self.expect(&["match", "matches"])?;
+
+        let mut conditions = ClashRoyaleMatchConditions::default();
+
+        // `in <mode>` is a shortcut of `gameMode = <mode>`.
+        let position = self.peek().position;
+        if self.accept("in") {
+            let condition = RuleCondition {
+                path: "gameMode",
+                position,
+                operator: RuleOperator::Equal,
+                operator_position: position,
+                values: vec![self.parse_value()?],
+            };
+
+            apply_condition(&mut conditions, condition.path, &condition)?;
+        }
+
+        if self.accept("where") {
+            self.parse_conditions(&mut conditions)?;
+        }
+
+        matches.resize(matches.len() + count, conditions);
+        Ok(())
+    }
+
+    /// conditions := condition ("and" condition)*
+    fn parse_conditions<S: RuleConditions>(&mut self, conditions: &mut S) -> Result<(), RuleError> {
+        loop {
+            let condition = self.parse_condition()?;
+            apply_condition(conditions, condition.path, &condition)?;
+
+            if !self.accept("and") {
+                return Ok(());
+            }
+        }
+    }
+
+    /// condition := path operator value | path ("in" | "not" "in") "[" [value ("," value)*] "]"
+    fn parse_condition(&mut self) -> Result<RuleCondition<'a>, RuleError> {
+        let token = self.next();
+        let position = token.position;
+        let path = match token.kind {
+            TokenKind::Word(v) => v,
+            kind => {
+                return Err(RuleError::new(
+                    token.position,
+                    ArcStr::from(format!("Expected a field but found {}", kind.describe())),
+                ));
+            }
+        };
+
+        let token = self.next();
+        let operator = match &token.kind {
+            TokenKind::Symbol("=") => RuleOperator::Equal,
+            TokenKind::Symbol("!=") => RuleOperator::NotEqual,
+            TokenKind::Symbol(">") => RuleOperator::GreaterThan,
+            TokenKind::Symbol(">=") => RuleOperator::GreaterOrEqualThan,
+            TokenKind::Symbol("<") => RuleOperator::LowerThan,
+            TokenKind::Symbol("<=") => RuleOperator::LowerOrEqualThan,
+            TokenKind::Word("in") => RuleOperator::In,
+            TokenKind::Word("not") => {
+                self.expect(&["in"])?;
+                RuleOperator::NotIn
+            }
+            kind => {
+                return Err(RuleError::new(
+                    token.position,
+                    ArcStr::from(format!(
+                        "Expected an operator but found {}",
+                        kind.describe()
+                    )),
+                ));
+            }
+        };
+
+        let values = match operator {
+            RuleOperator::In | RuleOperator::NotIn => self.parse_list()?,
+            _ => vec![self.parse_value()?],
+        };
+
+        Ok(RuleCondition {
+            path,
+            position,
+            operator,
+            operator_position: token.position,
+            values,
+        })
+    }
+
+    fn parse_list(&mut self) -> Result<Vec<RuleValue>, RuleError> {
+        self.expect_symbol("[")?;
+
+        let mut values = Vec::new();
+        if matches!(self.peek().kind, TokenKind::Symbol("]")) {
+            self.next();
+            return Ok(values);
+        }
+
+        loop {
+            values.push(self.parse_value()?);
+
+            let token = self.next();
+            match token.kind {
+                TokenKind::Symbol(",") => {}
+                TokenKind::Symbol("]") => return Ok(values),
+                kind => return Err(Self::unexpected(token.position, &kind, &[",", "]"])),
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<RuleValue, RuleError> {
+        let token = self.next();
+
+        match token.kind {
+            TokenKind::Word(v) => Ok(RuleValue::from_word(v, token.position)),
+            TokenKind::Text(v) => Ok(RuleValue::from_text(v, token.position)),
+            kind => Err(RuleError::new(
+                token.position,
+                ArcStr::from(format!("Expected a value but found {}", kind.describe())),
+            )),
+        }
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    fn unexpected(position: usize, kind: &TokenKind, expected: &[&str]) -> RuleError {
+        let expected: Vec<_> = expected.iter().map(|v| format!("'{}'", v)).collect();
+
+        RuleError::new(
+            position,
+            ArcStr::from(format!(
+                "Expected {} but found {}",
+                expected.join(" or "),
+                kind.describe()
+            )),
+        )
+    }
+}
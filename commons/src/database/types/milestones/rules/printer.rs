@@ -0,0 +1,110 @@
+use arcstr::ArcStr;
+
+use crate::database::types::conditions::OptionCondition;
+use crate::database::types::game::{
+    ClashRoyaleMatchConditions, ClashRoyaleMilestone, ClashRoyaleWinMatches,
+    NonQualifyingMatchPolicy, WinMatchesMode,
+};
+use crate::database::types::rules::fields::{print_conditions, print_field_value, RuleConditions};
+
+/// Writes a milestone as a rule that can be parsed back.
+pub fn print_clash_royale_rule(milestone: &ClashRoyaleMilestone) -> Result<String, ArcStr> {
+    match milestone {
+        ClashRoyaleMilestone::WinMatches(params) => print_win_matches(params),
+        ClashRoyaleMilestone::Achievement(conditions) => Ok(format!(
+            "achieve where {}",
+            print_where(conditions.as_ref())?
+        )),
+        ClashRoyaleMilestone::Statistics(conditions) => Ok(format!(
+            "statistics where {}",
+            print_where(conditions.as_ref())?
+        )),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn print_win_matches(params: &ClashRoyaleWinMatches) -> Result<String, ArcStr> {
+    if params.matches.is_empty() {
+        return Err(arcstr::literal!(
+            "A 'win matches' milestone without conditions cannot be written in a rule"
+        ));
+    }
+
+    let mut parts = vec!["win".to_string()];
+
+    match params.mode {
+        Some(WinMatchesMode::FirstN) => parts.push("first".to_string()),
+        Some(WinMatchesMode::AnyN) => parts.push("any".to_string()),
+        Some(WinMatchesMode::Consecutive) => parts.push("consecutive".to_string()),
+        None => {}
+    }
+
+    // Consecutive matches with the same conditions are grouped.
+    let mut groups: Vec<(usize, String)> = Vec::new();
+    for conditions in &params.matches {
+        let group = print_group(conditions)?;
+
+        match groups.last_mut() {
+            Some((count, last_group)) if *last_group == group => *count += 1,
+            _ => groups.push((1, group)),
+        }
+    }
+
+    let groups: Vec<_> = groups
+        .into_iter()
+        .map(|(count, group)| {
+            let noun = if count == 1 { "match" } else { "matches" };
+            format!("{} {}{}", count, noun, group)
+        })
+        .collect();
+    parts.push(groups.join(" then "));
+
+    match params.non_qualifying {
+        Some(NonQualifyingMatchPolicy::Fail) => parts.push("strict".to_string()),
+        Some(NonQualifyingMatchPolicy::Ignore) => parts.push("lenient".to_string()),
+        None => {}
+    }
+
+    Ok(parts.join(" "))
+}
+
+/// Writes the conditions of a match after the number of matches, i.e. the optional
+/// `in <mode>` shortcut and `where` clause.
+fn print_group(conditions: &ClashRoyaleMatchConditions) -> Result<String, ArcStr> {
+    let mut conditions = conditions.clone();
+    let mut group = String::new();
+
+    if let Some(OptionCondition::AnyOf(game_modes)) = &conditions.game_mode {
+        if game_modes.len() == 1 {
+            group.push_str(" in ");
+            group.push_str(&print_field_value(&game_modes[0], "gameMode")?);
+            conditions.game_mode = None;
+        }
+    }
+
+    let mut output = Vec::new();
+    print_conditions(&conditions, "", &mut output)?;
+
+    if !output.is_empty() {
+        group.push_str(" where ");
+        group.push_str(&output.join(" and "));
+    }
+
+    Ok(group)
+}
+
+fn print_where<S: RuleConditions>(conditions: &S) -> Result<String, ArcStr> {
+    let mut output = Vec::new();
+    print_conditions(conditions, "", &mut output)?;
+
+    if output.is_empty() {
+        return Err(arcstr::literal!(
+            "A milestone without conditions cannot be written in a rule"
+        ));
+    }
+
+    Ok(output.join(" and "))
+}
@@ -0,0 +1,119 @@
+//! JSON Schema (draft-07) of the milestones and their conditions, so that clients can build
+//! and validate them against the same shapes and limits as the `validate` methods.
+//!
+//! The schemas are derived with `schemars` from the serde attributes of the types and the
+//! limits are declared next to each field with `#[schemars(...)]`. This module only contains
+//! the pieces that cannot be derived. Named types are written once in `definitions` and
+//! referenced with `$ref`. Unknown properties are allowed because serde ignores them.
+//!
+//! The rules that depend on several fields cannot be expressed in the schema and are only
+//! checked by the server, i.e. the 'at least' count of a composite milestone not exceeding
+//! its milestones and the day time ranges not being empty.
+
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::{
+    InstanceType, NumberValidation, ObjectValidation, Schema, SchemaObject, SubschemaValidation,
+};
+pub use schemars::JsonSchema;
+use serde_json::Value;
+
+use crate::database::types::game::{ClashRoyaleStatisticsConditions, DECK_SIZE, MAX_ELIXIR};
+use crate::database::types::DayTime;
+
+/// Builds the root schema of a type including the definitions of all named types.
+pub fn root_schema_for<T: JsonSchema>() -> Value {
+    // Missing optional fields are omitted instead of being null.
+    let generator = SchemaSettings::draft07()
+        .with(|settings| settings.option_add_null_type = false)
+        .into_generator();
+    let schema = generator.into_root_schema_for::<T>();
+
+    serde_json::to_value(schema).expect("Schemas are always serializable")
+}
+
+/// The statistics conditions with at least one condition besides the filters of the matches.
+pub(crate) fn statistics_conditions_schema(generator: &mut SchemaGenerator) -> Schema {
+    let conditions = ["matchCount", "wins", "winRate", "crowns", "distinctCards"];
+    let required = conditions
+        .iter()
+        .map(|name| {
+            SchemaObject {
+                object: Some(Box::new(ObjectValidation {
+                    required: std::iter::once(name.to_string()).collect(),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }
+            .into()
+        })
+        .collect();
+
+    SchemaObject {
+        subschemas: Some(Box::new(SubschemaValidation {
+            all_of: Some(vec![
+                generator.subschema_for::<ClashRoyaleStatisticsConditions>()
+            ]),
+            any_of: Some(required),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An integer between 0 and 100 used as the value of the conditions over percentages,
+/// e.g. `#[schemars(with = "Option<OrderedCondition<Percentage>>")]`.
+#[derive(PartialEq, PartialOrd)]
+pub struct Percentage;
+
+/// An integer between 0 and the size of a deck used as the value of the conditions over
+/// the number of cards of a deck.
+#[derive(PartialEq, PartialOrd)]
+pub struct DeckCardCount;
+
+/// A number between 0 and the maximum elixir cost of a card used as the value of the
+/// conditions over the elixir cost.
+#[derive(PartialEq, PartialOrd)]
+pub struct ElixirCost;
+
+/// Implements `JsonSchema` for types serialized as a number between a minimum and a maximum.
+macro_rules! impl_range_schema {
+    ($($ty:ident: $instance_type:ident($min:expr, $max:expr)),* $(,)?) => {
+        $(
+            impl JsonSchema for $ty {
+                fn is_referenceable() -> bool {
+                    false
+                }
+
+                fn schema_name() -> String {
+                    stringify!($ty).to_string()
+                }
+
+                fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+                    SchemaObject {
+                        instance_type: Some(InstanceType::$instance_type.into()),
+                        number: Some(Box::new(NumberValidation {
+                            minimum: Some($min as f64),
+                            maximum: Some($max as f64),
+                            ..Default::default()
+                        })),
+                        ..Default::default()
+                    }
+                    .into()
+                }
+            }
+        )*
+    };
+}
+
+impl_range_schema!(
+    // Seconds from midnight.
+    DayTime: Integer(0, 86_399),
+    Percentage: Integer(0, 100),
+    DeckCardCount: Integer(0, DECK_SIZE),
+    ElixirCost: Number(0, MAX_ELIXIR),
+);
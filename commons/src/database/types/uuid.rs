@@ -68,6 +68,16 @@ impl DBUuid {
         Ok(DBUuid(text.into()))
     }
 
+    /// Builds a deterministic DB key from a date and a code of exactly
+    /// `DBUuidType::DBKey.random_chars()` chars.
+    pub fn new_with_date_and_code(date: DateTime, code: &str) -> AppResult<DBUuid> {
+        let prefix = DBUuidType::DBKey.prefix_char_codes();
+        let result = Self::new_with_code_and_date(date, code.to_string(), &prefix);
+        check_nanoid(result.0.as_str(), Some(DBUuidType::DBKey))?;
+
+        Ok(result)
+    }
+
     pub fn parse_str(text: &str) -> AppResult<DBUuid> {
         check_nanoid(text, None)?;
         Ok(DBUuid(text.to_string().into()))
@@ -422,6 +432,21 @@ mod test {
         assert_eq!(id, id_ok);
     }
 
+    #[test]
+    fn test_date_and_code() {
+        let now = DateTime::new(Utc.ymd(2021, 8, 15).and_hms_milli(10, 16, 35, 468));
+
+        let id = DBUuid::new_with_date_and_code(now.clone(), "------2PP2PP2PP")
+            .expect("The id must succeed");
+        let id_ok = DBUuid("gidMh8J1aB------2PP2PP2PP".into());
+        assert_eq!(id, id_ok);
+        assert_eq!(id.date(), Some(now.clone()));
+
+        DBUuid::new_with_date_and_code(now.clone(), "2PP").expect_err("The id must fail by length");
+        DBUuid::new_with_date_and_code(now, "#-----2PP2PP2PP")
+            .expect_err("The id must fail by character");
+    }
+
     #[test]
     fn test_serialization() {
         let now = DateTime::new(Utc.ymd(2021, 8, 15).and_hms_milli(10, 16, 35, 468));
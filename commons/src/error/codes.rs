@@ -25,6 +25,10 @@ pub const AUTHORIZATION_TOO_MANY_REQUESTS_ERROR_CODE: ArcStr = arcstr::literal!(
 pub const AUTHORIZATION_TOO_MANY_RETRIES_ERROR_CODE: ArcStr = arcstr::literal!("too_many_retries");
 pub const AUTHORIZATION_BANNED_ERROR_CODE: ArcStr = arcstr::literal!("banned");
 
+// EXTERNAL -------------------------------------------------------------------
+pub const EXTERNAL_THROTTLED_ERROR_CODE: ArcStr = arcstr::literal!("upstream_throttled");
+pub const EXTERNAL_UNAVAILABLE_ERROR_CODE: ArcStr = arcstr::literal!("upstream_unavailable");
+
 // INPUT_VALIDATION -----------------------------------------------------------
 pub const INPUT_VALIDATION_CANNOT_MODIFY_VALUE_ERROR_CODE: ArcStr =
     arcstr::literal!("cannot_modify");
@@ -48,6 +52,12 @@ pub const INPUT_VALIDATION_UNDEFINED_AUTHENTICATION_ERROR_CODE: ArcStr =
     arcstr::literal!("undefined_authentication");
 pub const INPUT_VALIDATION_UNDEFINED_BET_ERROR_CODE: ArcStr = arcstr::literal!("undefined_bet");
 pub const INPUT_VALIDATION_UNDEFINED_EMAIL_ERROR_CODE: ArcStr = arcstr::literal!("undefined_email");
+pub const INPUT_VALIDATION_UNDEFINED_CLASH_ROYALE_PLAYER_ERROR_CODE: ArcStr =
+    arcstr::literal!("undefined_clash_royale_player");
+pub const INPUT_VALIDATION_UNDEFINED_CLASH_ROYALE_CLAN_ERROR_CODE: ArcStr =
+    arcstr::literal!("undefined_clash_royale_clan");
+pub const INPUT_VALIDATION_UNDEFINED_CLASH_ROYALE_TOURNAMENT_ERROR_CODE: ArcStr =
+    arcstr::literal!("undefined_clash_royale_tournament");
 pub const INPUT_VALIDATION_INCORRECT_FORMAT_ERROR_CODE: ArcStr =
     arcstr::literal!("incorrect_format");
 pub const INPUT_VALIDATION_NOT_ENOUGH_CHARS_ERROR_CODE: ArcStr =
@@ -69,3 +79,5 @@ pub const INPUT_VALIDATION_NOT_ENOUGH_CREDITS_ERROR_CODE: ArcStr =
 pub const INPUT_VALIDATION_LOCKED_ELEMENT_ERROR_CODE: ArcStr = arcstr::literal!("locked_element");
 pub const INPUT_VALIDATION_UNSUPPORTED_CODEC_ERROR_CODE: ArcStr =
     arcstr::literal!("unsupported_codec");
+pub const INPUT_VALIDATION_UNSUPPORTED_GAME_ERROR_CODE: ArcStr =
+    arcstr::literal!("unsupported_game");
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use arcstr::ArcStr;
+use async_trait::async_trait;
+
+use crate::clients::games::{BrawlStarsBattlelogResponse, BrawlStarsClient};
+use crate::database::collections::BattlelogCollection;
+use crate::database::documents::{BetDBDocument, ParticipantGamesData};
+use crate::database::types::game::{
+    BrawlStarsMilestone, BrawlStarsWinMatches, GameMilestone, NonQualifyingMatchPolicy,
+    WinMatchesMode,
+};
+use crate::database::types::{DateTime, GameType};
+use crate::database::NullableOption;
+use crate::error::AppResult;
+use crate::games::matches::verify_win_matches;
+use crate::games::{GameProgress, GameSnapshot, GameVerifier, MilestoneProgress, MilestoneStatus};
+
+pub struct BrawlStarsVerifier {
+    client: Arc<BrawlStarsClient>,
+}
+
+impl BrawlStarsVerifier {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(client: Arc<BrawlStarsClient>) -> Self {
+        BrawlStarsVerifier { client }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    async fn store_battlelogs(&self, tag: &str) -> AppResult<()> {
+        let battlelogs = self.client.get_player_battlelog(tag).await?;
+        BattlelogCollection::store_brawl_stars_battlelogs(tag, battlelogs).await
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    fn get_tag(games_data: &ParticipantGamesData) -> Option<&ArcStr> {
+        match &games_data.brawl_stars {
+            NullableOption::Value(v) => match &v.tag {
+                NullableOption::Value(v) => Some(v),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+#[async_trait]
+impl GameVerifier for BrawlStarsVerifier {
+    // GETTERS ----------------------------------------------------------------
+
+    fn game_type(&self) -> GameType {
+        GameType::BrawlStars
+    }
+
+    fn participant_tag<'a>(&self, games_data: &'a ParticipantGamesData) -> Option<&'a ArcStr> {
+        Self::get_tag(games_data)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    fn validate_milestone(&self, milestone: &GameMilestone) -> Option<ArcStr> {
+        match milestone {
+            GameMilestone::BrawlStars(v) => v.validate(),
+            _ => Some(arcstr::literal!("Not a Brawl Stars milestone")),
+        }
+    }
+
+    fn validate_participant(&self, games_data: &ParticipantGamesData) -> Option<ArcStr> {
+        if Self::get_tag(games_data).is_none() {
+            return Some(arcstr::literal!(
+                "The participant does not contain a BrawlStars tag, please add it first"
+            ));
+        }
+
+        None
+    }
+
+    async fn take_snapshot(
+        &self,
+        _games_data: &ParticipantGamesData,
+        _milestones: &[&GameMilestone],
+    ) -> AppResult<Option<GameSnapshot>> {
+        // Matches do not require any initial data.
+        Ok(None)
+    }
+
+    async fn ingest_matches(&self, games_data: &ParticipantGamesData) -> AppResult<()> {
+        match Self::get_tag(games_data) {
+            Some(tag) => self.store_battlelogs(tag).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn load_progress(
+        &self,
+        bet: &BetDBDocument,
+        bet_expiration: &DateTime,
+        _milestones: &[&GameMilestone],
+    ) -> AppResult<Box<dyn GameProgress>> {
+        let games_data = bet
+            .participant
+            .unwrap_as_ref()
+            .unwrap_document_as_ref()
+            .games_data
+            .unwrap_as_ref();
+        let tag = Self::get_tag(games_data).unwrap();
+        let bet_start_date = bet.created_at.unwrap_as_ref();
+
+        // Read the matches from the store, that keeps those already out of the API window,
+        // after adding the latest ones.
+        self.store_battlelogs(tag).await?;
+        let battlelogs =
+            BattlelogCollection::get_brawl_stars_by_player_since(tag, bet_start_date).await?;
+
+        Ok(Box::new(BrawlStarsProgress {
+            tag: tag.clone(),
+            battlelogs,
+            bet_expiration: bet_expiration.clone(),
+        }))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+pub struct BrawlStarsProgress {
+    tag: ArcStr,
+    /// The matches played after the bet was created in chronological order.
+    battlelogs: Vec<BrawlStarsBattlelogResponse>,
+    bet_expiration: DateTime,
+}
+
+impl BrawlStarsProgress {
+    // METHODS ----------------------------------------------------------------
+
+    fn verify_win_matches(
+        &self,
+        params: &BrawlStarsWinMatches,
+        after: Option<&DateTime>,
+    ) -> MilestoneProgress {
+        verify_win_matches(
+            &self.battlelogs,
+            &params.matches,
+            params.mode.unwrap_or(WinMatchesMode::FirstN),
+            params
+                .non_qualifying
+                .unwrap_or(NonQualifyingMatchPolicy::Ignore),
+            after,
+            &self.bet_expiration,
+            |battlelog| &battlelog.battle_time,
+            |condition, battlelog| condition.check_response(battlelog, &self.tag),
+        )
+    }
+}
+
+impl GameProgress for BrawlStarsProgress {
+    fn verify(&self, milestone: &GameMilestone, after: Option<&DateTime>) -> MilestoneProgress {
+        match milestone {
+            GameMilestone::BrawlStars(milestone) => match milestone {
+                BrawlStarsMilestone::WinMatches(params) => self.verify_win_matches(params, after),
+            },
+            // Other games' milestones can never be completed with these matches.
+            _ => MilestoneProgress::new(MilestoneStatus::Failed, 0, 1),
+        }
+    }
+}
@@ -0,0 +1,502 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arcstr::ArcStr;
+use async_trait::async_trait;
+
+use crate::clients::games::{
+    ClashRoyaleBattlelogPlayerResponse, ClashRoyaleBattlelogResponse, ClashRoyaleClient,
+    ClashRoyalePlayerInfoResponse,
+};
+use crate::database::collections::BattlelogCollection;
+use crate::database::documents::{
+    BattlelogDBDocument, BetDBDocument, FraudConfig, ParticipantGamesData,
+};
+use crate::database::types::conditions::ConditionCheck;
+use crate::database::types::game::{
+    ClashRoyaleMilestone, ClashRoyaleStatisticsConditions, ClashRoyaleUserConditions,
+    ClashRoyaleWinMatches, GameMilestone, NonQualifyingMatchPolicy, WinMatchesMode,
+};
+use crate::database::types::{DateTime, GameType};
+use crate::database::NullableOption;
+use crate::error::{AppError, AppResult, INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE};
+use crate::games::matches::verify_win_matches;
+use crate::games::{
+    ClashRoyaleMatchStatistics, FraudSignal, GameProgress, GameSnapshot, GameVerifier,
+    MatchProgress, MilestoneProgress, MilestoneStatus,
+};
+
+pub struct ClashRoyaleVerifier {
+    client: Arc<ClashRoyaleClient>,
+}
+
+impl ClashRoyaleVerifier {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(client: Arc<ClashRoyaleClient>) -> Self {
+        ClashRoyaleVerifier { client }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    async fn store_battlelogs(&self, tag: &str) -> AppResult<()> {
+        let battlelogs = self.client.get_player_battlelog(tag).await?;
+        BattlelogCollection::store_clash_royale_battlelogs(tag, battlelogs).await
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    fn get_tag(games_data: &ParticipantGamesData) -> Option<&ArcStr> {
+        match &games_data.clash_royale {
+            NullableOption::Value(v) => match &v.tag {
+                NullableOption::Value(v) => Some(v),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+#[async_trait]
+impl GameVerifier for ClashRoyaleVerifier {
+    // GETTERS ----------------------------------------------------------------
+
+    fn game_type(&self) -> GameType {
+        GameType::ClashRoyale
+    }
+
+    fn participant_tag<'a>(&self, games_data: &'a ParticipantGamesData) -> Option<&'a ArcStr> {
+        Self::get_tag(games_data)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    fn validate_milestone(&self, milestone: &GameMilestone) -> Option<ArcStr> {
+        match milestone {
+            GameMilestone::ClashRoyale(v) => v.validate(),
+            _ => Some(arcstr::literal!("Not a Clash Royale milestone")),
+        }
+    }
+
+    fn validate_participant(&self, games_data: &ParticipantGamesData) -> Option<ArcStr> {
+        if Self::get_tag(games_data).is_none() {
+            return Some(arcstr::literal!(
+                "The participant does not contain a ClashRoyale tag, please add it first"
+            ));
+        }
+
+        if !games_data.clash_royale.unwrap_as_ref().is_verified() {
+            return Some(arcstr::literal!(
+                "The participant has not verified the ownership of the ClashRoyale tag yet"
+            ));
+        }
+
+        None
+    }
+
+    async fn take_snapshot(
+        &self,
+        games_data: &ParticipantGamesData,
+        milestones: &[&GameMilestone],
+    ) -> AppResult<Option<GameSnapshot>> {
+        let has_achievements = milestones.iter().any(|milestone| {
+            matches!(
+                milestone,
+                GameMilestone::ClashRoyale(ClashRoyaleMilestone::Achievement(_))
+            )
+        });
+
+        if !has_achievements {
+            return Ok(None);
+        }
+
+        // Snapshot the player info to verify the achievements. A cached response could
+        // predate the bet and count the progress made before it.
+        let tag = Self::get_tag(games_data).unwrap();
+        let player_info = self.client.get_fresh_player_info(tag).await?;
+
+        // Achievements measured in absolute values must not be met yet.
+        for milestone in milestones {
+            if let GameMilestone::ClashRoyale(ClashRoyaleMilestone::Achievement(conditions)) =
+                milestone
+            {
+                if conditions.is_already_met(&player_info) {
+                    return Err(AppError::new_with_status(
+                        warp::http::StatusCode::BAD_REQUEST,
+                        INPUT_VALIDATION_INCORRECT_STATE_ERROR_CODE,
+                    )
+                    .message(arcstr::literal!(
+                        "The participant already meets the achievements of the challenge"
+                    ))
+                    .param(arcstr::literal!("participant")));
+                }
+            }
+        }
+
+        Ok(Some(GameSnapshot::ClashRoyale(player_info)))
+    }
+
+    async fn ingest_matches(&self, games_data: &ParticipantGamesData) -> AppResult<()> {
+        match Self::get_tag(games_data) {
+            Some(tag) => self.store_battlelogs(tag).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn load_progress(
+        &self,
+        bet: &BetDBDocument,
+        bet_expiration: &DateTime,
+        milestones: &[&GameMilestone],
+    ) -> AppResult<Box<dyn GameProgress>> {
+        let games_data = bet
+            .participant
+            .unwrap_as_ref()
+            .unwrap_document_as_ref()
+            .games_data
+            .unwrap_as_ref();
+        let tag = Self::get_tag(games_data).unwrap();
+        let bet_start_date = bet.created_at.unwrap_as_ref();
+
+        let mut battlelogs = None;
+        let mut player_info = None;
+
+        for milestone in milestones {
+            match milestone {
+                GameMilestone::ClashRoyale(ClashRoyaleMilestone::WinMatches(_))
+                | GameMilestone::ClashRoyale(ClashRoyaleMilestone::Statistics(_)) => {
+                    // Read the matches from the store, that keeps those already out of
+                    // the API window, after adding the latest ones.
+                    if battlelogs.is_none() {
+                        self.store_battlelogs(tag).await?;
+                        battlelogs = Some(
+                            BattlelogCollection::get_clash_royale_by_player_since(
+                                tag,
+                                bet_start_date,
+                            )
+                            .await?,
+                        );
+                    }
+                }
+                GameMilestone::ClashRoyale(ClashRoyaleMilestone::Achievement(_)) => {
+                    if player_info.is_none() {
+                        player_info = Some(self.client.get_player_info(tag).await?);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let battlelogs = battlelogs.unwrap_or_default();
+
+        let snapshot = match &bet.snapshots {
+            NullableOption::Value(v) => v.iter().find_map(|v| v.clash_royale()).cloned(),
+            _ => None,
+        };
+
+        Ok(Box::new(ClashRoyaleProgress::new(
+            battlelogs,
+            player_info,
+            snapshot,
+            bet_expiration.clone(),
+        )))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+pub struct ClashRoyaleProgress {
+    /// The matches played after the bet was created in chronological order.
+    battlelogs: Vec<ClashRoyaleBattlelogResponse>,
+    player_info: Option<ClashRoyalePlayerInfoResponse>,
+    snapshot: Option<ClashRoyalePlayerInfoResponse>,
+    bet_expiration: DateTime,
+}
+
+impl ClashRoyaleProgress {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds the progress from the matches played in chronological order and the
+    /// player info, required to verify the achievements.
+    pub fn new(
+        battlelogs: Vec<ClashRoyaleBattlelogResponse>,
+        player_info: Option<ClashRoyalePlayerInfoResponse>,
+        snapshot: Option<ClashRoyalePlayerInfoResponse>,
+        bet_expiration: DateTime,
+    ) -> Self {
+        ClashRoyaleProgress {
+            battlelogs,
+            player_info,
+            snapshot,
+            bet_expiration,
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The matches played before the bet expired until `until`, in chronological order.
+    fn matches_until<'a>(
+        &'a self,
+        until: &'a DateTime,
+    ) -> impl Iterator<Item = &'a ClashRoyaleBattlelogResponse> + 'a {
+        self.battlelogs.iter().take_while(move |v| {
+            v.battle_time.0 <= until.0 && v.battle_time.0 < self.bet_expiration.0
+        })
+    }
+
+    /// The matches played at any of `battle_times`, in chronological order.
+    fn matches_at<'a>(
+        &'a self,
+        battle_times: &'a [DateTime],
+    ) -> impl Iterator<Item = &'a ClashRoyaleBattlelogResponse> + 'a {
+        self.battlelogs
+            .iter()
+            .filter(move |v| battle_times.contains(&v.battle_time))
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    fn verify_win_matches(
+        &self,
+        params: &ClashRoyaleWinMatches,
+        after: Option<&DateTime>,
+    ) -> MilestoneProgress {
+        verify_win_matches(
+            &self.battlelogs,
+            &params.matches,
+            params.mode.unwrap_or(WinMatchesMode::FirstN),
+            params
+                .non_qualifying
+                .unwrap_or(NonQualifyingMatchPolicy::Ignore),
+            after,
+            &self.bet_expiration,
+            |battlelog| &battlelog.battle_time,
+            |condition, battlelog| {
+                let previous_count = self
+                    .battlelogs
+                    .partition_point(|v| v.battle_time.0 < battlelog.battle_time.0);
+                condition.check_response(battlelog, &self.battlelogs[..previous_count])
+            },
+        )
+    }
+
+    /// Aggregates the qualifying matches of the whole bet window. The statistics can change
+    /// with every match until the bet expires, so the milestone is only completed or failed
+    /// then, at the last aggregated match, unless `min_matches` allows completing it earlier.
+    /// Every aggregated match is listed, the last one with the checks of the statistics.
+    fn verify_statistics(
+        &self,
+        conditions: &ClashRoyaleStatisticsConditions,
+        after: Option<&DateTime>,
+    ) -> MilestoneProgress {
+        let mut statistics = ClashRoyaleMatchStatistics::default();
+        let mut matches: Vec<MatchProgress> = Vec::new();
+
+        for battlelog in self.matches_until(&self.bet_expiration) {
+            if let Some(after) = after {
+                if battlelog.battle_time.0 <= after.0 {
+                    continue;
+                }
+            }
+
+            if !conditions.qualifies(battlelog) {
+                continue;
+            }
+
+            statistics.add(battlelog);
+            matches.push(MatchProgress::new(
+                battlelog.battle_time.clone(),
+                Vec::new(),
+            ));
+
+            // Completed at the first match that fulfills the conditions.
+            if let Some(min_matches) = conditions.min_matches {
+                if statistics.match_count >= u64::from(min_matches) {
+                    let checks = conditions.check_statistics(&statistics);
+
+                    if ConditionCheck::all_passed(&checks) {
+                        let battle_time = battlelog.battle_time.clone();
+                        matches.last_mut().unwrap().conditions = checks;
+
+                        let mut progress = MilestoneProgress::new(
+                            MilestoneStatus::Completed(Some(battle_time)),
+                            1,
+                            1,
+                        );
+                        progress.matches = matches;
+                        return progress;
+                    }
+                }
+            }
+        }
+
+        let checks = conditions.check_statistics(&statistics);
+        let passed = ConditionCheck::all_passed(&checks);
+        let is_empty = matches.is_empty();
+
+        match matches.last_mut() {
+            Some(last_match) => last_match.conditions = checks,
+            None => {
+                let battle_time = after.unwrap_or(&self.bet_expiration).clone();
+                matches.push(MatchProgress::new(battle_time, checks));
+            }
+        }
+
+        if !self.bet_expiration.is_expired() {
+            if is_empty {
+                return MilestoneProgress::new(MilestoneStatus::NotInitiated, 0, 1);
+            }
+
+            let mut progress = MilestoneProgress::new(MilestoneStatus::Initiated, 0, 1);
+            progress.matches = matches;
+            return progress;
+        }
+
+        let mut progress = if passed {
+            let battle_time = matches.last().unwrap().battle_time.clone();
+            MilestoneProgress::new(MilestoneStatus::Completed(Some(battle_time)), 1, 1)
+        } else {
+            MilestoneProgress::new(MilestoneStatus::Failed, 0, 1)
+        };
+        progress.matches = matches;
+        progress
+    }
+
+    /// Verifies the achievement against the current player info. The moment it was reached
+    /// is unknown, so it is considered completed at `after`, the earliest possible moment,
+    /// in order not to discard the matches of the next milestones in a sequence.
+    fn verify_achievement(
+        &self,
+        conditions: &ClashRoyaleUserConditions,
+        after: Option<&DateTime>,
+    ) -> MilestoneProgress {
+        let player_info = match &self.player_info {
+            Some(v) => v,
+            None => return MilestoneProgress::new(MilestoneStatus::Failed, 0, 1),
+        };
+
+        let checks = conditions.check_response(player_info, self.snapshot.as_ref(), "");
+
+        // The achievement can still be reached before the bet expires.
+        let mut progress = if ConditionCheck::all_passed(&checks) {
+            MilestoneProgress::new(MilestoneStatus::Completed(after.cloned()), 1, 1)
+        } else {
+            MilestoneProgress::new(MilestoneStatus::Initiated, 0, 1)
+        };
+        progress.conditions = checks;
+
+        progress
+    }
+}
+
+impl GameProgress for ClashRoyaleProgress {
+    fn verify(&self, milestone: &GameMilestone, after: Option<&DateTime>) -> MilestoneProgress {
+        match milestone {
+            GameMilestone::ClashRoyale(milestone) => match milestone {
+                ClashRoyaleMilestone::WinMatches(params) => self.verify_win_matches(params, after),
+                ClashRoyaleMilestone::Achievement(conditions) => {
+                    self.verify_achievement(conditions, after)
+                }
+                ClashRoyaleMilestone::Statistics(conditions) => {
+                    self.verify_statistics(conditions, after)
+                }
+            },
+            // Other games' milestones can never be completed with these matches.
+            _ => MilestoneProgress::new(MilestoneStatus::Failed, 0, 1),
+        }
+    }
+
+    fn fraud_signals(&self, config: &FraudConfig, battle_times: &[DateTime]) -> Vec<FraudSignal> {
+        let mut signals = Vec::new();
+        let mut opponent_matches: BTreeMap<&str, u32> = BTreeMap::new();
+        let mut previous_battle_time: Option<&DateTime> = None;
+
+        for battlelog in self.matches_at(battle_times) {
+            for tag in opponent_tags(battlelog) {
+                *opponent_matches.entry(tag).or_default() += 1;
+            }
+
+            if let Some(max_trophy_gap) = config.max_trophy_gap {
+                let team_trophies = total_trophies(battlelog.team.as_deref());
+                let opponent_trophies = total_trophies(battlelog.opponent.as_deref());
+
+                if let (Some(team_trophies), Some(opponent_trophies)) =
+                    (team_trophies, opponent_trophies)
+                {
+                    let gap = if team_trophies > opponent_trophies {
+                        team_trophies - opponent_trophies
+                    } else {
+                        opponent_trophies - team_trophies
+                    };
+
+                    if gap > max_trophy_gap {
+                        signals.push(FraudSignal::TrophyGap {
+                            battle_time: battlelog.battle_time.clone(),
+                            gap,
+                        });
+                    }
+                }
+            }
+
+            if let (Some(min_seconds), Some(previous_battle_time)) =
+                (config.min_seconds_between_matches, previous_battle_time)
+            {
+                let seconds = (battlelog.battle_time.0 - previous_battle_time.0)
+                    .num_seconds()
+                    .max(0) as u64;
+
+                if seconds < min_seconds {
+                    signals.push(FraudSignal::ClusteredMatches {
+                        battle_time: battlelog.battle_time.clone(),
+                        seconds,
+                    });
+                }
+            }
+
+            previous_battle_time = Some(&battlelog.battle_time);
+        }
+
+        if let Some(max_matches) = config.max_matches_per_opponent {
+            for (tag, matches) in opponent_matches {
+                if matches > max_matches {
+                    signals.push(FraudSignal::RepeatedOpponent {
+                        tag: ArcStr::from(tag),
+                        matches,
+                    });
+                }
+            }
+        }
+
+        signals
+    }
+
+    fn opponent_matches(&self, battle_times: &[DateTime]) -> Vec<(ArcStr, DateTime)> {
+        self.matches_at(battle_times)
+            .flat_map(|battlelog| {
+                opponent_tags(battlelog)
+                    .map(move |tag| (ArcStr::from(tag), battlelog.battle_time.clone()))
+            })
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The tags of the opponents of a match without the leading '#'.
+fn opponent_tags(battlelog: &ClashRoyaleBattlelogResponse) -> impl Iterator<Item = &str> {
+    battlelog
+        .opponent
+        .iter()
+        .flatten()
+        .filter_map(|v| v.tag.as_ref())
+        .map(|v| BattlelogDBDocument::normalize_tag(v))
+}
+
+/// The sum of the starting trophies of all the players, missing if any of them is unknown.
+fn total_trophies(players: Option<&[ClashRoyaleBattlelogPlayerResponse]>) -> Option<u64> {
+    players.and_then(ClashRoyaleBattlelogPlayerResponse::total_starting_trophies)
+}
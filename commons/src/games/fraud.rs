@@ -0,0 +1,34 @@
+use arcstr::ArcStr;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::database::traits::{DBNormalize, DBNormalizeResult};
+use crate::database::types::DateTime;
+
+/// A sign of collusion between the participant and its opponents found in the matches
+/// used to complete a bet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum FraudSignal {
+    /// The same opponent was faced in more matches than allowed.
+    #[serde(rename_all = "camelCase")]
+    RepeatedOpponent { tag: ArcStr, matches: u32 },
+
+    /// An opponent has an active bet too.
+    #[serde(rename_all = "camelCase")]
+    OpponentWithActiveBet { tag: ArcStr },
+
+    /// The difference of starting trophies between the team and the opponent is too big.
+    #[serde(rename_all = "camelCase")]
+    TrophyGap { battle_time: DateTime, gap: u64 },
+
+    /// The match started too soon after the previous one.
+    #[serde(rename_all = "camelCase")]
+    ClusteredMatches { battle_time: DateTime, seconds: u64 },
+}
+
+impl DBNormalize for FraudSignal {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
@@ -0,0 +1,128 @@
+use crate::database::types::conditions::ConditionCheck;
+use crate::database::types::game::{NonQualifyingMatchPolicy, WinMatchesMode};
+use crate::database::types::DateTime;
+use crate::games::{MatchProgress, MilestoneProgress, MilestoneStatus};
+
+/// Verifies that the matches played after `after`, or all of them if missing, fulfill
+/// the conditions in order following `mode`. The battlelogs must be sorted chronologically.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn verify_win_matches<B, C>(
+    battlelogs: &[B],
+    conditions: &[C],
+    mode: WinMatchesMode,
+    non_qualifying: NonQualifyingMatchPolicy,
+    after: Option<&DateTime>,
+    bet_expiration: &DateTime,
+    battle_time: impl Fn(&B) -> &DateTime,
+    check: impl Fn(&C, &B) -> Vec<ConditionCheck>,
+) -> MilestoneProgress {
+    let required = conditions.len() as u32;
+    let first_match = match after {
+        Some(after) => battlelogs
+            .iter()
+            .position(|v| battle_time(v).0 > after.0)
+            .unwrap_or(battlelogs.len()),
+        None => 0,
+    };
+    let battlelogs = &battlelogs[first_match..];
+
+    // Initial checks.
+    if battlelogs.is_empty() {
+        return MilestoneProgress::new(MilestoneStatus::NotInitiated, 0, required);
+    }
+
+    let check_match = |condition: &C, battlelog: &B| {
+        let match_date = battle_time(battlelog);
+        let mut checks = check(condition, battlelog);
+
+        // When any of the matches are after the final date, the user has lost.
+        if match_date.0 >= bet_expiration.0 {
+            checks.push(ConditionCheck::new("expiration", false));
+        }
+
+        MatchProgress::new(match_date.clone(), checks)
+    };
+
+    if mode == WinMatchesMode::FirstN {
+        // Evaluate the matches in order, only the necessary ones.
+        let matches: Vec<_> = battlelogs
+            .iter()
+            .zip(conditions.iter())
+            .map(|(battlelog, condition)| check_match(condition, battlelog))
+            .collect();
+
+        let matched = matches.iter().filter(|v| v.passed()).count() as u32;
+        let status = if matches.len() < conditions.len() {
+            MilestoneStatus::Initiated
+        } else if matched < required {
+            MilestoneStatus::Failed
+        } else {
+            let last_match = matches.last().unwrap();
+            MilestoneStatus::Completed(Some(last_match.battle_time.clone()))
+        };
+
+        return progress_with_status(status, matches, required);
+    }
+
+    // Look for the qualifying matches, keeping only those of the current attempt.
+    let mut matches: Vec<MatchProgress> = Vec::new();
+
+    for battlelog in battlelogs {
+        // No match can be counted after the final date.
+        let match_date = battle_time(battlelog);
+        if match_date.0 >= bet_expiration.0 {
+            let checks = vec![ConditionCheck::new("expiration", false)];
+            matches.push(MatchProgress::new(match_date.clone(), checks));
+            return progress_with_status(MilestoneStatus::Failed, matches, required);
+        }
+
+        let mut progress = check_match(&conditions[matches.len()], battlelog);
+
+        if !progress.passed() && !matches.is_empty() {
+            match non_qualifying {
+                NonQualifyingMatchPolicy::Fail => {
+                    matches.push(progress);
+                    return progress_with_status(MilestoneStatus::Failed, matches, required);
+                }
+                NonQualifyingMatchPolicy::Ignore => {
+                    // Restart the streak, the match can be the first of the new one.
+                    if mode == WinMatchesMode::Consecutive {
+                        matches.clear();
+                        progress = check_match(&conditions[0], battlelog);
+                    }
+                }
+            }
+        }
+
+        if progress.passed() {
+            matches.push(progress);
+
+            if matches.len() == conditions.len() {
+                let last_match = matches.last().unwrap();
+                let status = MilestoneStatus::Completed(Some(last_match.battle_time.clone()));
+                return progress_with_status(status, matches, required);
+            }
+        }
+    }
+
+    progress_with_status(MilestoneStatus::Initiated, matches, required)
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn progress_with_status(
+    status: MilestoneStatus,
+    matches: Vec<MatchProgress>,
+    required: u32,
+) -> MilestoneProgress {
+    MilestoneProgress {
+        status,
+        matched: matches.iter().filter(|v| v.passed()).count() as u32,
+        required,
+        matches,
+        conditions: Vec::new(),
+        milestones: Vec::new(),
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arcstr::ArcStr;
+use async_trait::async_trait;
+
+pub use brawl_stars::*;
+pub use clash_royale::*;
+pub use fraud::*;
+pub use progress::*;
+pub use snapshot::*;
+pub use statistics::*;
+pub use status::*;
+pub use verifier::*;
+
+use crate::clients::games::GameClients;
+use crate::database::documents::{BetDBDocument, FraudConfig, ParticipantGamesData};
+use crate::database::types::game::GameMilestone;
+use crate::database::types::{DateTime, GameType};
+use crate::error::{AppError, AppResult, INPUT_VALIDATION_UNSUPPORTED_GAME_ERROR_CODE};
+
+mod brawl_stars;
+mod clash_royale;
+mod fraud;
+pub(crate) mod matches;
+mod progress;
+mod snapshot;
+mod statistics;
+mod status;
+mod verifier;
+
+/// Verifies the milestones of a specific game.
+#[async_trait]
+pub trait GameVerifier: Send + Sync {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The game this verifier is for.
+    fn game_type(&self) -> GameType;
+
+    /// The tag of the participant's game account, if linked.
+    fn participant_tag<'a>(&self, games_data: &'a ParticipantGamesData) -> Option<&'a ArcStr>;
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Validates a milestone of the game.
+    fn validate_milestone(&self, milestone: &GameMilestone) -> Option<ArcStr>;
+
+    /// Checks the participant has linked its game account.
+    fn validate_participant(&self, games_data: &ParticipantGamesData) -> Option<ArcStr>;
+
+    /// Gets the participant data required to verify the milestones later, if any, to store
+    /// it in the bet.
+    async fn take_snapshot(
+        &self,
+        games_data: &ParticipantGamesData,
+        milestones: &[&GameMilestone],
+    ) -> AppResult<Option<GameSnapshot>>;
+
+    /// Stores the latest matches of the participant so they are not lost when the game
+    /// API only returns a limited window of them.
+    async fn ingest_matches(&self, _games_data: &ParticipantGamesData) -> AppResult<()> {
+        Ok(())
+    }
+
+    /// Loads the current progress of the participant to verify the milestones.
+    async fn load_progress(
+        &self,
+        bet: &BetDBDocument,
+        bet_expiration: &DateTime,
+        milestones: &[&GameMilestone],
+    ) -> AppResult<Box<dyn GameProgress>>;
+}
+
+/// The progress of a participant in a game.
+pub trait GameProgress: Send + Sync {
+    /// Verifies a milestone considering only the progress made after `after`, or since
+    /// the bet was created if missing.
+    fn verify(&self, milestone: &GameMilestone, after: Option<&DateTime>) -> MilestoneProgress;
+
+    /// Looks for signs of collusion in the matches played at `battle_times`, i.e. those used
+    /// to complete the milestones.
+    fn fraud_signals(&self, _config: &FraudConfig, _battle_times: &[DateTime]) -> Vec<FraudSignal> {
+        Vec::new()
+    }
+
+    /// The opponents faced in the matches played at `battle_times`, as their tags without
+    /// the leading '#' along with the time of the match.
+    fn opponent_matches(&self, _battle_times: &[DateTime]) -> Vec<(ArcStr, DateTime)> {
+        Vec::new()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The registry of all supported games.
+pub struct GameVerifiers {
+    verifiers: HashMap<GameType, Arc<dyn GameVerifier>>,
+}
+
+impl GameVerifiers {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(clients: &GameClients) -> Self {
+        let verifiers: Vec<Arc<dyn GameVerifier>> = vec![
+            Arc::new(ClashRoyaleVerifier::new(clients.clash_royale.clone())),
+            Arc::new(BrawlStarsVerifier::new(clients.brawl_stars.clone())),
+        ];
+
+        GameVerifiers {
+            verifiers: verifiers
+                .into_iter()
+                .map(|verifier| (verifier.game_type(), verifier))
+                .collect(),
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn all(&self) -> impl Iterator<Item = &Arc<dyn GameVerifier>> {
+        self.verifiers.values()
+    }
+
+    pub fn get(&self, game_type: GameType) -> Option<&Arc<dyn GameVerifier>> {
+        self.verifiers.get(&game_type)
+    }
+
+    /// Gets the verifier of a milestone's game, failing if the game is not supported.
+    pub fn get_for(&self, milestone: &GameMilestone) -> AppResult<&Arc<dyn GameVerifier>> {
+        match self.get(milestone.game_type()) {
+            Some(v) => Ok(v),
+            None => Err(AppError::new_with_status(
+                warp::http::StatusCode::BAD_REQUEST,
+                INPUT_VALIDATION_UNSUPPORTED_GAME_ERROR_CODE,
+            )
+            .message(arcstr::literal!("Unsupported game"))
+            .param(arcstr::literal!("milestones"))),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::database::traits::{DBNormalize, DBNormalizeResult};
+use crate::database::types::conditions::ConditionCheck;
+use crate::database::types::DateTime;
+use crate::games::MilestoneStatus;
+
+/// The progress of a participant in a milestone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestoneProgress {
+    pub status: MilestoneStatus,
+
+    /// The number of matches or nested milestones already fulfilled.
+    pub matched: u32,
+
+    /// The number of matches or nested milestones required to complete the milestone.
+    pub required: u32,
+
+    /// The matches evaluated to verify the milestone.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub matches: Vec<MatchProgress>,
+
+    /// The conditions evaluated against the player, e.g. for achievements.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<ConditionCheck>,
+
+    /// The progress of the nested milestones.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub milestones: Vec<MilestoneProgress>,
+}
+
+impl MilestoneProgress {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(status: MilestoneStatus, matched: u32, required: u32) -> Self {
+        MilestoneProgress {
+            status,
+            matched,
+            required,
+            matches: Vec::new(),
+            conditions: Vec::new(),
+            milestones: Vec::new(),
+        }
+    }
+}
+
+impl DBNormalize for MilestoneProgress {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The evaluation of a single match against its conditions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchProgress {
+    pub battle_time: DateTime,
+    pub conditions: Vec<ConditionCheck>,
+}
+
+impl MatchProgress {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(battle_time: DateTime, conditions: Vec<ConditionCheck>) -> Self {
+        MatchProgress {
+            battle_time,
+            conditions,
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn passed(&self) -> bool {
+        ConditionCheck::all_passed(&self.conditions)
+    }
+}
@@ -0,0 +1,38 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::clients::games::ClashRoyalePlayerInfoResponse;
+use crate::database::traits::{DBNormalize, DBNormalizeResult};
+use crate::database::types::GameType;
+
+/// The data of a participant in a game when the bet was created, required to verify
+/// some milestones later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "T", content = "V")]
+#[serde(rename_all = "camelCase")]
+pub enum GameSnapshot {
+    /// The player info, to verify the achievements.
+    ClashRoyale(ClashRoyalePlayerInfoResponse),
+}
+
+impl GameSnapshot {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn game_type(&self) -> GameType {
+        match self {
+            GameSnapshot::ClashRoyale(_) => GameType::ClashRoyale,
+        }
+    }
+
+    pub fn clash_royale(&self) -> Option<&ClashRoyalePlayerInfoResponse> {
+        match self {
+            GameSnapshot::ClashRoyale(v) => Some(v),
+        }
+    }
+}
+
+impl DBNormalize for GameSnapshot {
+    fn normalize(&mut self) -> DBNormalizeResult {
+        DBNormalizeResult::NotModified
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+use crate::clients::games::ClashRoyaleBattlelogResponse;
+
+/// The statistics aggregated over a set of matches.
+#[derive(Default, Debug, Clone)]
+pub struct ClashRoyaleMatchStatistics {
+    pub match_count: u64,
+    pub wins: u64,
+    pub crowns: u64,
+    pub cards: HashSet<u64>,
+}
+
+impl ClashRoyaleMatchStatistics {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The percentage of matches won, rounded down.
+    pub fn win_rate(&self) -> u8 {
+        if self.match_count == 0 {
+            return 0;
+        }
+
+        (self.wins * 100 / self.match_count) as u8
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Adds a match to the statistics. Matches without teams are ignored.
+    pub fn add(&mut self, battlelog: &ClashRoyaleBattlelogResponse) {
+        let (team, opponent) = match (&battlelog.team, &battlelog.opponent) {
+            (Some(team), Some(opponent)) if !team.is_empty() && !opponent.is_empty() => {
+                (team, opponent)
+            }
+            _ => return,
+        };
+
+        let player = &team[0];
+        let crowns = player.crowns.unwrap_or(0);
+
+        self.match_count += 1;
+        self.crowns += u64::from(crowns);
+
+        if crowns > opponent[0].crowns.unwrap_or(0) {
+            self.wins += 1;
+        }
+
+        if let Some(cards) = &player.cards {
+            self.cards.extend(cards.iter().map(|v| v.id));
+        }
+    }
+}
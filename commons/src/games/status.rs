@@ -0,0 +1,39 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::database::types::DateTime;
+
+/// The verification status of a milestone.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "completedAt")]
+#[serde(rename_all = "camelCase")]
+pub enum MilestoneStatus {
+    /// The participant has not yet made progress in the milestone.
+    NotInitiated,
+
+    /// The participant has made progress in the milestone but it is not yet completed.
+    Initiated,
+
+    /// The milestone is completed. Contains the moment it was completed or missing if it was
+    /// completed before the bet was created.
+    Completed(Option<DateTime>),
+
+    /// The milestone cannot be completed anymore.
+    Failed,
+}
+
+impl MilestoneStatus {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn is_completed(&self) -> bool {
+        matches!(self, MilestoneStatus::Completed(_))
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self, MilestoneStatus::Failed)
+    }
+
+    pub fn is_not_initiated(&self) -> bool {
+        matches!(self, MilestoneStatus::NotInitiated)
+    }
+}
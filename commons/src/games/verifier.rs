@@ -0,0 +1,255 @@
+use std::collections::{HashMap, HashSet};
+
+use arcstr::ArcStr;
+
+use crate::database::documents::{ChallengeMilestone, FraudConfig};
+use crate::database::types::{CompositeMilestoneKind, DBUuid, DateTime, GameType};
+use crate::games::{FraudSignal, GameProgress, MilestoneProgress, MilestoneStatus};
+
+/// Verifies the milestones of a challenge with all the required data already loaded.
+pub struct MilestoneVerifier {
+    progresses: HashMap<GameType, Box<dyn GameProgress>>,
+    completed_challenges: HashSet<DBUuid>,
+}
+
+impl MilestoneVerifier {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds a verifier with the progress of every game of the milestones and the
+    /// prerequisite challenges already completed.
+    pub fn new(
+        progresses: HashMap<GameType, Box<dyn GameProgress>>,
+        completed_challenges: HashSet<DBUuid>,
+    ) -> Self {
+        MilestoneVerifier {
+            progresses,
+            completed_challenges,
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Verifies a milestone considering only the progress made after `after`, or since
+    /// the bet was created if missing.
+    pub fn verify(
+        &self,
+        milestone: &ChallengeMilestone,
+        after: Option<&DateTime>,
+    ) -> MilestoneProgress {
+        match milestone {
+            ChallengeMilestone::GameMilestone(milestone) => {
+                match self.progresses.get(&milestone.milestone.game_type()) {
+                    Some(progress) => progress.verify(&milestone.milestone, after),
+                    // The progress of the game cannot be loaded, e.g. the participant
+                    // has not linked the game account.
+                    None => MilestoneProgress::new(MilestoneStatus::Failed, 0, 1),
+                }
+            }
+            ChallengeMilestone::OtherChallenge(milestone) => {
+                if !self
+                    .completed_challenges
+                    .contains(&milestone.challenge.key())
+                {
+                    return MilestoneProgress::new(MilestoneStatus::Initiated, 0, 1);
+                }
+
+                // Prerequisites are completed before playing any match.
+                MilestoneProgress::new(MilestoneStatus::Completed(after.cloned()), 1, 1)
+            }
+            ChallengeMilestone::Composite(milestone) => match milestone.kind {
+                CompositeMilestoneKind::AllOf => self.verify_all_of(&milestone.milestones, after),
+                CompositeMilestoneKind::AnyOf => {
+                    self.verify_at_least(&milestone.milestones, 1, after)
+                }
+                CompositeMilestoneKind::AtLeast(count) => {
+                    self.verify_at_least(&milestone.milestones, count as usize, after)
+                }
+                CompositeMilestoneKind::Sequence => {
+                    self.verify_sequence(&milestone.milestones, after)
+                }
+            },
+        }
+    }
+
+    /// Looks for signs of collusion in the matches of every game used to complete `progress`,
+    /// the result of verifying `milestones` with `verify_all_of`.
+    pub fn fraud_signals(
+        &self,
+        config: &FraudConfig,
+        milestones: &[ChallengeMilestone],
+        progress: &MilestoneProgress,
+    ) -> Vec<FraudSignal> {
+        let used_matches = used_matches(milestones, progress);
+
+        self.progresses
+            .iter()
+            .filter_map(|(game_type, v)| {
+                used_matches
+                    .get(game_type)
+                    .map(|battle_times| v.fraud_signals(config, battle_times))
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// The opponents of every game faced in the matches used to complete `progress`, the
+    /// result of verifying `milestones` with `verify_all_of`.
+    pub fn opponent_matches(
+        &self,
+        milestones: &[ChallengeMilestone],
+        progress: &MilestoneProgress,
+    ) -> Vec<(ArcStr, DateTime)> {
+        let used_matches = used_matches(milestones, progress);
+
+        self.progresses
+            .iter()
+            .filter_map(|(game_type, v)| {
+                used_matches
+                    .get(game_type)
+                    .map(|battle_times| v.opponent_matches(battle_times))
+            })
+            .flatten()
+            .collect()
+    }
+
+    pub fn verify_all_of(
+        &self,
+        milestones: &[ChallengeMilestone],
+        after: Option<&DateTime>,
+    ) -> MilestoneProgress {
+        self.verify_at_least(milestones, milestones.len(), after)
+    }
+
+    pub fn verify_at_least(
+        &self,
+        milestones: &[ChallengeMilestone],
+        count: usize,
+        after: Option<&DateTime>,
+    ) -> MilestoneProgress {
+        let progresses: Vec<_> = milestones.iter().map(|v| self.verify(v, after)).collect();
+
+        let mut completed: Vec<_> = progresses
+            .iter()
+            .filter_map(|v| match &v.status {
+                MilestoneStatus::Completed(v) => Some(v),
+                _ => None,
+            })
+            .collect();
+        let failed = progresses.iter().filter(|v| v.status.is_failed()).count();
+
+        let status = if count == 0 {
+            MilestoneStatus::Completed(after.cloned())
+        } else if completed.len() >= count {
+            // The milestone is completed when the n-th milestone is.
+            completed.sort_by_key(|v| v.as_ref().map(|v| v.0));
+            MilestoneStatus::Completed(completed[count - 1].clone())
+        } else if failed > milestones.len().saturating_sub(count) {
+            MilestoneStatus::Failed
+        } else if progresses.iter().all(|v| v.status.is_not_initiated()) {
+            MilestoneStatus::NotInitiated
+        } else {
+            MilestoneStatus::Initiated
+        };
+
+        let mut progress = MilestoneProgress::new(status, completed.len() as u32, count as u32);
+        progress.milestones = progresses;
+        progress
+    }
+
+    pub fn verify_sequence(
+        &self,
+        milestones: &[ChallengeMilestone],
+        after: Option<&DateTime>,
+    ) -> MilestoneProgress {
+        let mut after = after.cloned();
+        let mut progresses = Vec::with_capacity(milestones.len());
+        let mut status = None;
+
+        for (i, milestone) in milestones.iter().enumerate() {
+            let progress = self.verify(milestone, after.as_ref());
+
+            match &progress.status {
+                MilestoneStatus::Completed(completed_at) => {
+                    if completed_at.is_some() {
+                        after = completed_at.clone();
+                    }
+                }
+                MilestoneStatus::NotInitiated if i == 0 => {
+                    status = Some(MilestoneStatus::NotInitiated)
+                }
+                MilestoneStatus::NotInitiated | MilestoneStatus::Initiated => {
+                    status = Some(MilestoneStatus::Initiated)
+                }
+                MilestoneStatus::Failed => status = Some(MilestoneStatus::Failed),
+            }
+
+            progresses.push(progress);
+
+            if status.is_some() {
+                break;
+            }
+        }
+
+        let matched = progresses
+            .iter()
+            .filter(|v| v.status.is_completed())
+            .count();
+        let status = status.unwrap_or(MilestoneStatus::Completed(after));
+
+        let mut progress = MilestoneProgress::new(status, matched as u32, milestones.len() as u32);
+        progress.milestones = progresses;
+        progress
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The time of the matches of each game used to complete `progress`, i.e. the passed matches
+/// of its completed game milestones, in chronological order.
+fn used_matches(
+    milestones: &[ChallengeMilestone],
+    progress: &MilestoneProgress,
+) -> HashMap<GameType, Vec<DateTime>> {
+    let mut result: HashMap<GameType, Vec<DateTime>> = HashMap::new();
+    collect_used_matches(milestones, &progress.milestones, &mut result);
+
+    for battle_times in result.values_mut() {
+        battle_times.sort_by_key(|v| v.0);
+        battle_times.dedup();
+    }
+
+    result
+}
+
+fn collect_used_matches(
+    milestones: &[ChallengeMilestone],
+    progresses: &[MilestoneProgress],
+    result: &mut HashMap<GameType, Vec<DateTime>>,
+) {
+    for (milestone, progress) in milestones.iter().zip(progresses) {
+        if !progress.status.is_completed() {
+            continue;
+        }
+
+        match milestone {
+            ChallengeMilestone::GameMilestone(milestone) => {
+                result
+                    .entry(milestone.milestone.game_type())
+                    .or_default()
+                    .extend(
+                        progress
+                            .matches
+                            .iter()
+                            .filter(|v| v.passed())
+                            .map(|v| v.battle_time.clone()),
+                    );
+            }
+            ChallengeMilestone::OtherChallenge(_) => {}
+            ChallengeMilestone::Composite(milestone) => {
+                collect_used_matches(&milestone.milestones, &progress.milestones, result)
+            }
+        }
+    }
+}
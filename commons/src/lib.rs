@@ -19,6 +19,7 @@ pub mod constants;
 pub mod data;
 pub mod database;
 pub mod error;
+pub mod games;
 pub mod programs;
 pub mod server;
 #[cfg(feature = "test")]
@@ -0,0 +1,47 @@
+use std::net::SocketAddr;
+
+use warp::http::StatusCode;
+use warp::{Filter, Reply};
+
+use crate::config::GameAPIConfig;
+
+/// The tag of the player served by the mock.
+pub const BRAWL_STARS_FIXTURE_TAG: &str = "8Q9RJ2LC";
+pub const BRAWL_STARS_PLAYER_FIXTURE: &str = include_str!("fixtures/brawl_stars/player.json");
+pub const BRAWL_STARS_BATTLELOG_FIXTURE: &str = include_str!("fixtures/brawl_stars/battlelog.json");
+
+/// Starts a local mock of the Brawl Stars API that serves the fixtures for
+/// `BRAWL_STARS_FIXTURE_TAG` and a 404 for any other player.
+/// Returns the config to point the client at it.
+pub fn start_brawl_stars_mock() -> GameAPIConfig {
+    let player = warp::path!("v1" / "players" / String)
+        .and(warp::get())
+        .map(|tag: String| reply_fixture(&tag, BRAWL_STARS_PLAYER_FIXTURE));
+    let battlelog = warp::path!("v1" / "players" / String / "battlelog")
+        .and(warp::get())
+        .map(|tag: String| reply_fixture(&tag, BRAWL_STARS_BATTLELOG_FIXTURE));
+
+    let address: SocketAddr = ([127, 0, 0, 1], 0).into();
+    let (address, server) = warp::serve(player.or(battlelog)).bind_ephemeral(address);
+    tokio::spawn(server);
+
+    GameAPIConfig {
+        token: arcstr::literal!("test"),
+        url: format!("http://{}/v1", address).into(),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn reply_fixture(tag: &str, fixture: &'static str) -> warp::reply::Response {
+    let tag = tag.trim_start_matches("%23");
+
+    if tag != BRAWL_STARS_FIXTURE_TAG {
+        let reply = warp::reply::with_status(r#"{"reason":"notFound"}"#, StatusCode::NOT_FOUND);
+        return warp::reply::with_header(reply, "content-type", "application/json").into_response();
+    }
+
+    warp::reply::with_header(fixture, "content-type", "application/json").into_response()
+}
@@ -0,0 +1,349 @@
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+use warp::http::StatusCode;
+use warp::{Filter, Reply};
+
+use crate::config::GameAPIConfig;
+use crate::data::games::ClashRoyaleCard;
+
+/// The tag of the player served by default by the mock.
+pub const CLASH_ROYALE_FIXTURE_TAG: &str = "82L0C9YG";
+pub const CLASH_ROYALE_PLAYER_FIXTURE: &str = include_str!("fixtures/clash_royale/player.json");
+pub const CLASH_ROYALE_BATTLELOG_FIXTURE: &str =
+    include_str!("fixtures/clash_royale/battlelog.json");
+
+/// The key under which the requests and errors of the card list are recorded.
+pub const CLASH_ROYALE_CARDS_MOCK_KEY: &str = "cards";
+
+/// A local mock of the Clash Royale API that serves the players, clans and tournaments
+/// added to it and a 404 for any other tag. The card list is served from `ClashRoyaleCard`
+/// unless replaced.
+///
+/// The documents are served from JSON values that tests can modify, and the next
+/// responses of a tag can be scripted to fail, e.g. to simulate throttling.
+/// Clones share the same state.
+#[derive(Clone)]
+pub struct ClashRoyaleMock {
+    config: GameAPIConfig,
+    state: Arc<Mutex<ClashRoyaleMockState>>,
+}
+
+struct ClashRoyaleMockState {
+    players: HashMap<String, ClashRoyaleMockPlayer>,
+    clans: HashMap<String, ClashRoyaleMockClan>,
+    tournaments: HashMap<String, Value>,
+    cards: Vec<Value>,
+    errors: HashMap<String, VecDeque<ClashRoyaleMockError>>,
+    requests: HashMap<String, usize>,
+}
+
+struct ClashRoyaleMockPlayer {
+    info: Value,
+    battlelog: Vec<Value>,
+}
+
+struct ClashRoyaleMockClan {
+    info: Value,
+    members: Vec<Value>,
+    river_race_log: Vec<Value>,
+}
+
+impl ClashRoyaleMock {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Starts the mock serving the fixtures for `CLASH_ROYALE_FIXTURE_TAG`.
+    pub fn start() -> Self {
+        let cards = ClashRoyaleCard::enum_list()
+            .iter()
+            .map(|v| serde_json::json!({ "id": *v as u64, "name": format!("{:?}", v), "maxLevel": 14 }))
+            .collect();
+        let state = Arc::new(Mutex::new(ClashRoyaleMockState {
+            players: HashMap::new(),
+            clans: HashMap::new(),
+            tournaments: HashMap::new(),
+            cards,
+            errors: HashMap::new(),
+            requests: HashMap::new(),
+        }));
+
+        let player_state = state.clone();
+        let player =
+            warp::path!("v1" / "players" / String)
+                .and(warp::get())
+                .map(move |tag: String| {
+                    reply(&player_state, &tag, |state, tag| {
+                        state.players.get(tag).map(|v| v.info.clone())
+                    })
+                });
+        let battlelog_state = state.clone();
+        let battlelog = warp::path!("v1" / "players" / String / "battlelog")
+            .and(warp::get())
+            .map(move |tag: String| {
+                reply(&battlelog_state, &tag, |state, tag| {
+                    state
+                        .players
+                        .get(tag)
+                        .map(|v| Value::from(v.battlelog.clone()))
+                })
+            });
+        let clan_state = state.clone();
+        let clan = warp::path!("v1" / "clans" / String)
+            .and(warp::get())
+            .map(move |tag: String| {
+                reply(&clan_state, &tag, |state, tag| {
+                    state.clans.get(tag).map(|v| v.info.clone())
+                })
+            });
+        let members_state = state.clone();
+        let members = warp::path!("v1" / "clans" / String / "members")
+            .and(warp::get())
+            .map(move |tag: String| {
+                reply(&members_state, &tag, |state, tag| {
+                    state
+                        .clans
+                        .get(tag)
+                        .map(|v| serde_json::json!({ "items": v.members }))
+                })
+            });
+        let river_race_log_state = state.clone();
+        let river_race_log = warp::path!("v1" / "clans" / String / "riverracelog")
+            .and(warp::get())
+            .map(move |tag: String| {
+                reply(&river_race_log_state, &tag, |state, tag| {
+                    state
+                        .clans
+                        .get(tag)
+                        .map(|v| serde_json::json!({ "items": v.river_race_log }))
+                })
+            });
+        let tournament_state = state.clone();
+        let tournament = warp::path!("v1" / "tournaments" / String)
+            .and(warp::get())
+            .map(move |tag: String| {
+                reply(&tournament_state, &tag, |state, tag| {
+                    state.tournaments.get(tag).cloned()
+                })
+            });
+        let cards_state = state.clone();
+        let cards = warp::path!("v1" / "cards").and(warp::get()).map(move || {
+            reply(&cards_state, CLASH_ROYALE_CARDS_MOCK_KEY, |state, _| {
+                Some(serde_json::json!({ "items": state.cards }))
+            })
+        });
+
+        let routes = player
+            .or(battlelog)
+            .or(clan)
+            .or(members)
+            .or(river_race_log)
+            .or(tournament)
+            .or(cards);
+        let address: SocketAddr = ([127, 0, 0, 1], 0).into();
+        let (address, server) = warp::serve(routes).bind_ephemeral(address);
+        tokio::spawn(server);
+
+        let mock = ClashRoyaleMock {
+            config: GameAPIConfig {
+                token: arcstr::literal!("test"),
+                url: format!("http://{}/v1", address).into(),
+            },
+            state,
+        };
+        mock.add_fixture_player(CLASH_ROYALE_FIXTURE_TAG);
+        mock
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The config to point the client at the mock.
+    pub fn config(&self) -> GameAPIConfig {
+        self.config.clone()
+    }
+
+    /// The number of requests received for a tag, including the failed ones.
+    pub fn request_count(&self, tag: &str) -> usize {
+        let state = self.state.lock().unwrap();
+        state.requests.get(tag).copied().unwrap_or(0)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Adds or replaces a player. The tag of `info` is overwritten with `tag`.
+    pub fn add_player(&self, tag: &str, mut info: Value, battlelog: Vec<Value>) {
+        info["tag"] = Value::String(format!("#{}", tag));
+
+        let mut state = self.state.lock().unwrap();
+        state
+            .players
+            .insert(tag.to_string(), ClashRoyaleMockPlayer { info, battlelog });
+    }
+
+    /// Adds or replaces a player with the content of the fixtures, so that tests
+    /// running in parallel can use different players.
+    pub fn add_fixture_player(&self, tag: &str) {
+        let info = serde_json::from_str(CLASH_ROYALE_PLAYER_FIXTURE).unwrap();
+        let mut battlelog: Vec<Value> =
+            serde_json::from_str(CLASH_ROYALE_BATTLELOG_FIXTURE).unwrap();
+
+        for battle in &mut battlelog {
+            battle["team"][0]["tag"] = Value::String(format!("#{}", tag));
+        }
+
+        self.add_player(tag, info, battlelog);
+    }
+
+    pub fn remove_player(&self, tag: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.players.remove(tag);
+    }
+
+    /// Modifies the info of a player, e.g. to change its current deck.
+    pub fn update_player<F: FnOnce(&mut Value)>(&self, tag: &str, update: F) {
+        let mut state = self.state.lock().unwrap();
+        let player = state.players.get_mut(tag).expect("Undefined mock player");
+        update(&mut player.info);
+    }
+
+    /// Adds a battle to the battlelog of a player as the most recent one.
+    pub fn push_battle(&self, tag: &str, battle: Value) {
+        let mut state = self.state.lock().unwrap();
+        let player = state.players.get_mut(tag).expect("Undefined mock player");
+        player.battlelog.insert(0, battle);
+    }
+
+    /// Adds or replaces a clan. The tag of `info` is overwritten with `tag`.
+    pub fn add_clan(
+        &self,
+        tag: &str,
+        mut info: Value,
+        members: Vec<Value>,
+        river_race_log: Vec<Value>,
+    ) {
+        info["tag"] = Value::String(format!("#{}", tag));
+
+        let mut state = self.state.lock().unwrap();
+        state.clans.insert(
+            tag.to_string(),
+            ClashRoyaleMockClan {
+                info,
+                members,
+                river_race_log,
+            },
+        );
+    }
+
+    /// Adds or replaces a tournament. The tag of `info` is overwritten with `tag`.
+    pub fn add_tournament(&self, tag: &str, mut info: Value) {
+        info["tag"] = Value::String(format!("#{}", tag));
+
+        let mut state = self.state.lock().unwrap();
+        state.tournaments.insert(tag.to_string(), info);
+    }
+
+    /// Replaces the card list, e.g. to simulate a new card.
+    pub fn set_cards(&self, cards: Vec<Value>) {
+        let mut state = self.state.lock().unwrap();
+        state.cards = cards;
+    }
+
+    /// Makes the next request of a tag fail with `error`. Successive calls
+    /// fail the successive requests.
+    pub fn push_error(&self, tag: &str, error: ClashRoyaleMockError) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .errors
+            .entry(tag.to_string())
+            .or_default()
+            .push_back(error);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An error answered by the mock.
+#[derive(Debug, Clone)]
+pub struct ClashRoyaleMockError {
+    pub status: StatusCode,
+
+    /// The `Retry-After` header in seconds.
+    pub retry_after: Option<u64>,
+}
+
+impl ClashRoyaleMockError {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(status: StatusCode) -> Self {
+        ClashRoyaleMockError {
+            status,
+            retry_after: None,
+        }
+    }
+
+    pub fn not_found() -> Self {
+        Self::new(StatusCode::NOT_FOUND)
+    }
+
+    pub fn throttled(retry_after: Option<u64>) -> Self {
+        ClashRoyaleMockError {
+            status: StatusCode::TOO_MANY_REQUESTS,
+            retry_after,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Auxiliary methods ----------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Replies with the document that `document` finds for `tag`, or with the next scripted
+/// error of `tag`.
+fn reply<F>(
+    state: &Arc<Mutex<ClashRoyaleMockState>>,
+    tag: &str,
+    document: F,
+) -> warp::reply::Response
+where
+    F: FnOnce(&ClashRoyaleMockState, &str) -> Option<Value>,
+{
+    let tag = tag.trim_start_matches("%23");
+    let mut state = state.lock().unwrap();
+
+    *state.requests.entry(tag.to_string()).or_default() += 1;
+
+    let error = state.errors.get_mut(tag).and_then(|v| v.pop_front());
+    let body = match (error, document(&state, tag)) {
+        (None, Some(document)) => serde_json::to_string(&document).unwrap(),
+        (None, None) => return reply_error(&ClashRoyaleMockError::not_found()),
+        (Some(error), _) => return reply_error(&error),
+    };
+
+    warp::reply::with_header(body, "content-type", "application/json").into_response()
+}
+
+fn reply_error(error: &ClashRoyaleMockError) -> warp::reply::Response {
+    let reason = if error.status == StatusCode::NOT_FOUND {
+        "notFound"
+    } else if error.status == StatusCode::TOO_MANY_REQUESTS {
+        "requestThrottled"
+    } else {
+        "unknownException"
+    };
+
+    let body = format!(r#"{{"reason":"{}"}}"#, reason);
+    let reply = warp::reply::with_status(body, error.status);
+    let mut response =
+        warp::reply::with_header(reply, "content-type", "application/json").into_response();
+
+    if let Some(retry_after) = error.retry_after {
+        response
+            .headers_mut()
+            .insert("retry-after", retry_after.to_string().parse().unwrap());
+    }
+
+    response
+}
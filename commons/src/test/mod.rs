@@ -12,6 +12,8 @@ use crate::database::types::DateTime;
 use crate::database::{APIDocument, APIReference, DBDocument, DBReference, NullableOption};
 use crate::error::AppError;
 
+pub mod brawl_stars;
+pub mod clash_royale;
 pub mod commons;
 
 pub fn assert_http_response_status(
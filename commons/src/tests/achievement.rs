@@ -0,0 +1,111 @@
+use crate::clients::games::ClashRoyalePlayerInfoResponse;
+use crate::database::types::game::ClashRoyaleUserConditions;
+
+fn player(value: serde_json::Value) -> ClashRoyalePlayerInfoResponse {
+    serde_json::from_value(value).unwrap()
+}
+
+fn conditions(value: serde_json::Value) -> ClashRoyaleUserConditions {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn test_achievement_increment() {
+    let conditions = conditions(serde_json::json!({
+        "measure": "increment",
+        "wins": { "condition": ">=", "value": [10] },
+    }));
+    let snapshot = player(serde_json::json!({ "tag": "#A", "wins": 1000 }));
+
+    assert!(!conditions.verify_response(
+        &player(serde_json::json!({ "tag": "#A", "wins": 1009 })),
+        Some(&snapshot)
+    ));
+    assert!(conditions.verify_response(
+        &player(serde_json::json!({ "tag": "#A", "wins": 1010 })),
+        Some(&snapshot)
+    ));
+
+    // Increments cannot be measured without a snapshot.
+    assert!(!conditions.verify_response(
+        &player(serde_json::json!({ "tag": "#A", "wins": 1010 })),
+        None
+    ));
+}
+
+#[test]
+fn test_achievement_increment_without_snapshot_value() {
+    let increment = conditions(serde_json::json!({
+        "measure": "increment",
+        "wins": { "condition": ">=", "value": [10] },
+    }));
+
+    // The lifetime total is not taken as the increment.
+    let snapshot = player(serde_json::json!({ "tag": "#A" }));
+    assert!(!increment.verify_response(
+        &player(serde_json::json!({ "tag": "#A", "wins": 1010 })),
+        Some(&snapshot)
+    ));
+
+    // Absolute values do not need the snapshot.
+    let absolute = conditions(serde_json::json!({
+        "wins": { "condition": ">=", "value": [10] },
+    }));
+    assert!(absolute.verify_response(
+        &player(serde_json::json!({ "tag": "#A", "wins": 1010 })),
+        Some(&snapshot)
+    ));
+}
+
+#[test]
+fn test_achievement_already_met() {
+    let player = player(serde_json::json!({ "tag": "#A", "wins": 1010 }));
+
+    // Absolute values already reached when the bet is created.
+    let absolute = conditions(serde_json::json!({
+        "wins": { "condition": ">=", "value": [10] },
+    }));
+    assert!(absolute.is_already_met(&player));
+
+    let absolute = conditions(serde_json::json!({
+        "measure": "absolute",
+        "wins": { "condition": ">=", "value": [2000] },
+    }));
+    assert!(!absolute.is_already_met(&player));
+
+    // Increments always start from zero.
+    let increment = conditions(serde_json::json!({
+        "measure": "increment",
+        "wins": { "condition": ">=", "value": [0] },
+    }));
+    assert!(!increment.is_already_met(&player));
+}
+
+#[test]
+fn test_achievement_checks() {
+    let conditions = conditions(serde_json::json!({
+        "wins": { "condition": ">=", "value": [10] },
+        "losses": { "condition": "<=", "value": [5] },
+        "allowedCards": { "conditions": [{ "cardId": 26000000 }] },
+    }));
+    let player = player(serde_json::json!({
+        "tag": "#A",
+        "wins": 20,
+        "losses": 6,
+        "cards": [{ "id": 26000000, "level": 9 }],
+    }));
+
+    let checks = conditions.check_response(&player, None, "");
+    let names: Vec<&str> = checks.iter().map(|v| v.condition.as_str()).collect();
+    assert_eq!(names, vec!["wins", "losses", "allowedCards[0]"]);
+
+    let passed: Vec<bool> = checks.iter().map(|v| v.passed).collect();
+    assert_eq!(passed, vec![true, false, true]);
+    assert_eq!(checks[1].actual, Some(serde_json::json!(6)));
+    assert_eq!(checks[2].actual, Some(serde_json::json!(26000000)));
+    assert!(!conditions.verify_response(&player, None));
+
+    // Nested paths are joined with the parent.
+    let checks = conditions.check_response(&player, None, "user");
+    assert_eq!(checks[0].condition.as_str(), "user.wins");
+}
@@ -0,0 +1,35 @@
+use crate::database::types::game::ClashRoyaleBattleKind;
+
+#[test]
+fn test_parse_battle_kind() {
+    let kinds = [
+        ("PvP", Some("Ladder"), ClashRoyaleBattleKind::Ladder),
+        ("PvP", None, ClashRoyaleBattleKind::Ladder),
+        ("PvP", Some("2v2_Ladder"), ClashRoyaleBattleKind::TwoVsTwo),
+        ("PvP", Some("TeamVsTeam"), ClashRoyaleBattleKind::TwoVsTwo),
+        ("pathOfLegend", None, ClashRoyaleBattleKind::PathOfLegends),
+        ("challenge", None, ClashRoyaleBattleKind::Challenge),
+        ("tournament", None, ClashRoyaleBattleKind::Tournament),
+        ("boatBattle", None, ClashRoyaleBattleKind::BoatBattle),
+        ("friendly", None, ClashRoyaleBattleKind::Friendly),
+        ("friendly2v2", None, ClashRoyaleBattleKind::Friendly),
+        ("clanMate", None, ClashRoyaleBattleKind::Friendly),
+        ("clanMate2v2", None, ClashRoyaleBattleKind::Friendly),
+        ("casual1v1", None, ClashRoyaleBattleKind::Casual),
+        ("casual2v2", None, ClashRoyaleBattleKind::Casual),
+        ("clanWarWarDay", None, ClashRoyaleBattleKind::ClanWar),
+        ("riverRacePvP", None, ClashRoyaleBattleKind::ClanWar),
+        ("riverRaceDuel", None, ClashRoyaleBattleKind::ClanWar),
+        ("trail", None, ClashRoyaleBattleKind::Unknown),
+    ];
+
+    for (kind, game_mode, expected) in kinds.iter() {
+        assert_eq!(
+            ClashRoyaleBattleKind::parse(kind, *game_mode),
+            *expected,
+            "{} {:?}",
+            kind,
+            game_mode
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::clients::games::{
+    BrawlStarsBattlelogListResponse, BrawlStarsPlayerInfoResponse, ClashRoyaleBattlelogResponse,
+};
+use crate::data::games::{BrawlStarsBrawler, BrawlStarsGameMode};
+use crate::database::documents::BattlelogDBDocument;
+use crate::database::types::conditions::{OptionCondition, OrderedCondition};
+use crate::database::types::game::{
+    BrawlStarsGameResult, BrawlStarsMatchConditions, BrawlStarsMilestone, NonQualifyingMatchPolicy,
+    WinMatchesMode,
+};
+
+const TAG: &str = "8Q9RJ2LC";
+
+fn battlelogs() -> BrawlStarsBattlelogListResponse {
+    serde_json::from_str(include_str!("../test/fixtures/brawl_stars/battlelog.json")).unwrap()
+}
+
+#[test]
+fn test_fixtures_deserialization() {
+    let player: BrawlStarsPlayerInfoResponse =
+        serde_json::from_str(include_str!("../test/fixtures/brawl_stars/player.json")).unwrap();
+    assert_eq!(player.three_vs_three_victories, Some(1843));
+    assert_eq!(player.brawlers.unwrap().len(), 2);
+
+    let battlelogs = battlelogs().items;
+    assert_eq!(battlelogs.len(), 3);
+    assert!(battlelogs[0].battle_time.0 > battlelogs[1].battle_time.0);
+}
+
+#[test]
+fn test_match_conditions() {
+    let battlelogs = battlelogs().items;
+    let (defeat, showdown, victory) = (&battlelogs[0], &battlelogs[1], &battlelogs[2]);
+
+    let conditions = BrawlStarsMatchConditions {
+        result: Some(OptionCondition::AnyOf(vec![BrawlStarsGameResult::Victory])),
+        ..Default::default()
+    };
+    assert!(!conditions.verify_response(defeat, TAG));
+    assert!(conditions.verify_response(showdown, TAG));
+    assert!(conditions.verify_response(victory, TAG));
+    assert!(!conditions.verify_response(victory, "2YQ0V8L9"));
+
+    let conditions = BrawlStarsMatchConditions {
+        game_mode: Some(OptionCondition::AnyOf(vec![BrawlStarsGameMode::GemGrab])),
+        brawler: Some(OptionCondition::AnyOf(vec![BrawlStarsBrawler::Shelly])),
+        trophies: Some(OrderedCondition::LowerThan(vec![450])),
+        ..Default::default()
+    };
+    assert!(!conditions.verify_response(defeat, TAG));
+    assert!(!conditions.verify_response(showdown, TAG));
+    assert!(conditions.verify_response(victory, TAG));
+}
+
+#[test]
+fn test_win_matches_formats() {
+    let legacy: BrawlStarsMilestone = serde_json::from_value(serde_json::json!({
+        "type": "winMatches",
+        "params": [{}, {}],
+    }))
+    .unwrap();
+    let BrawlStarsMilestone::WinMatches(params) = &legacy;
+    assert_eq!(params.mode, None);
+    assert_eq!(params.matches.len(), 2);
+
+    let current: BrawlStarsMilestone = serde_json::from_value(serde_json::json!({
+        "type": "winMatches",
+        "params": {
+            "mode": "consecutive",
+            "nonQualifying": "fail",
+            "matches": [{}],
+        },
+    }))
+    .unwrap();
+    let BrawlStarsMilestone::WinMatches(params) = &current;
+    assert_eq!(params.mode, Some(WinMatchesMode::Consecutive));
+    assert_eq!(params.non_qualifying, Some(NonQualifyingMatchPolicy::Fail));
+    assert!(current.validate().is_none());
+
+    let empty: BrawlStarsMilestone = serde_json::from_value(serde_json::json!({
+        "type": "winMatches",
+        "params": { "matches": [] },
+    }))
+    .unwrap();
+    assert!(empty.validate().is_some());
+}
+
+#[test]
+fn test_battlelog_keys() {
+    let battlelog = battlelogs().items.remove(0);
+    let document = BattlelogDBDocument::new_brawl_stars(TAG, battlelog.clone()).unwrap();
+    let same_document =
+        BattlelogDBDocument::new_brawl_stars(&format!("#{}", TAG), battlelog.clone()).unwrap();
+    assert_eq!(document.db_key, same_document.db_key);
+
+    // A Clash Royale match of the same tag and time is stored apart.
+    let clash_royale_battlelog: ClashRoyaleBattlelogResponse =
+        serde_json::from_value(serde_json::json!({
+            "type": "PvP",
+            "battleTime": battlelog.battle_time,
+        }))
+        .unwrap();
+    let clash_royale_document =
+        BattlelogDBDocument::new_clash_royale(TAG, clash_royale_battlelog).unwrap();
+    assert_ne!(document.db_key, clash_royale_document.db_key);
+}
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+use crate::data::games::{
+    ClashRoyaleArena, ClashRoyaleCard, ClashRoyaleCardRarity, ClashRoyaleCardType,
+    ClashRoyaleCatalog,
+};
+
+#[test]
+fn test_catalog_contains_all() {
+    let catalog = ClashRoyaleCatalog::instance();
+
+    for card in ClashRoyaleCard::enum_list() {
+        assert!(
+            catalog.card(*card as u64).is_some(),
+            "Missing card: {:?}",
+            card
+        );
+    }
+
+    for arena in ClashRoyaleArena::enum_list() {
+        assert!(
+            catalog.arena(*arena as u64).is_some(),
+            "Missing arena: {:?}",
+            arena
+        );
+    }
+
+    let keys: HashSet<_> = catalog.cards().iter().map(|v| v.key.as_str()).collect();
+    assert_eq!(keys.len(), catalog.cards().len());
+
+    assert_eq!(ClashRoyaleCard::PEKKA.name(), "P.E.K.K.A");
+    assert_eq!(ClashRoyaleCard::PEKKA.rarity(), ClashRoyaleCardRarity::Epic);
+    assert_eq!(ClashRoyaleCard::PEKKA.elixir(), Some(7));
+    assert_eq!(ClashRoyaleCard::TheLog.kind(), ClashRoyaleCardType::Spell);
+    assert_eq!(ClashRoyaleCard::Mirror.elixir(), None);
+}
+
+#[test]
+fn test_catalog_ids_round_trip() {
+    let catalog = ClashRoyaleCatalog::instance();
+
+    for card in catalog.cards() {
+        let value = ClashRoyaleCard::try_from(card.id).unwrap();
+        assert_eq!(value as u64, card.id);
+    }
+
+    for arena in catalog.arenas() {
+        let value = ClashRoyaleArena::try_from(arena.id).unwrap();
+        assert_eq!(value as u64, arena.id);
+    }
+
+    for card in ClashRoyaleCard::enum_list() {
+        assert_eq!(ClashRoyaleCard::try_from(*card as u64), Ok(*card));
+    }
+
+    for arena in ClashRoyaleArena::enum_list() {
+        assert_eq!(ClashRoyaleArena::try_from(*arena as u64), Ok(*arena));
+    }
+
+    assert!(ClashRoyaleCard::try_from(26999999).is_err());
+    assert!(ClashRoyaleArena::try_from(54999999).is_err());
+}
+
+#[test]
+fn test_arena_trophy_ranges() {
+    let catalog = ClashRoyaleCatalog::instance();
+    let arenas = catalog.arenas();
+
+    // Contiguous ranges.
+    for pair in arenas.windows(2) {
+        assert!(pair[0].order < pair[1].order);
+        assert_eq!(pair[0].max_trophies, Some(pair[1].min_trophies - 1));
+    }
+
+    assert_eq!(arenas.last().unwrap().max_trophies, None);
+    assert!(ClashRoyaleArena::Arena2 < ClashRoyaleArena::Arena10);
+    assert_eq!(ClashRoyaleArena::Arena10.min_trophies(), 3000);
+}
+
+#[test]
+fn test_resolve_unknown_arena() {
+    assert_eq!(
+        ClashRoyaleArena::resolve(54000010, None),
+        Some(ClashRoyaleArena::Arena9)
+    );
+    assert_eq!(
+        ClashRoyaleArena::resolve(54000010, Some(0)),
+        Some(ClashRoyaleArena::Arena9)
+    );
+
+    // Unknown arenas are resolved by trophies.
+    assert_eq!(ClashRoyaleArena::resolve(54999999, None), None);
+    assert_eq!(
+        ClashRoyaleArena::resolve(54999999, Some(3050)),
+        Some(ClashRoyaleArena::Arena10)
+    );
+    assert_eq!(
+        ClashRoyaleArena::resolve(54999999, Some(20000)),
+        Some(ClashRoyaleArena::UltimateChampion)
+    );
+}
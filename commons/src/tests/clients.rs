@@ -0,0 +1,89 @@
+use crate::clients::games::{
+    ClashRoyaleCardListResponse, ClashRoyaleRiverRaceLogListResponse, ClashRoyaleTournamentResponse,
+};
+use crate::data::games::ClashRoyaleCard;
+
+#[test]
+fn test_card_catalog_cross_check() {
+    let mut items: Vec<_> = ClashRoyaleCard::enum_list()
+        .iter()
+        .filter(|v| **v != ClashRoyaleCard::Knight)
+        .map(|v| serde_json::json!({ "id": *v as u64, "name": "Card", "maxLevel": 14 }))
+        .collect();
+    items.push(serde_json::json!({ "id": 26999999, "name": "New card", "maxLevel": 14 }));
+
+    let cards: ClashRoyaleCardListResponse =
+        serde_json::from_value(serde_json::json!({ "items": items })).unwrap();
+
+    let unknown: Vec<_> = cards.unknown_cards().iter().map(|v| v.id).collect();
+    assert_eq!(unknown, vec![26999999]);
+    assert_eq!(cards.removed_cards(), vec![ClashRoyaleCard::Knight]);
+}
+
+#[test]
+fn test_river_race_log() {
+    let log: ClashRoyaleRiverRaceLogListResponse = serde_json::from_value(serde_json::json!({
+        "items": [{
+            "seasonId": 80,
+            "sectionIndex": 3,
+            "createdDate": "20211115T094047.000Z",
+            "standings": [{
+                "rank": 1,
+                "trophyChange": 20,
+                "clan": {
+                    "tag": "#9VQJ2Y0R",
+                    "fame": 10000,
+                    "finishTime": "20211113T102154.000Z",
+                    "participants": [{ "tag": "#82L0C9YG", "fame": 1800, "decksUsed": 16 }]
+                }
+            }, {
+                "rank": 2,
+                "trophyChange": 10,
+                "clan": { "tag": "#2PYQ8GC0", "fame": 9000, "participants": [] }
+            }, {
+                "rank": 3,
+                "clan": { "tag": "#YL8VQ2R0", "fame": 8000, "finishTime": null }
+            }]
+        }]
+    }))
+    .unwrap();
+
+    let race = &log.items[0];
+    assert_eq!(race.clan_standing("#2PYQ8GC0").unwrap().rank, 2);
+    assert!(race
+        .clan_standing("#2PYQ8GC0")
+        .unwrap()
+        .clan
+        .finish_time
+        .is_none());
+    assert!(race
+        .clan_standing("#YL8VQ2R0")
+        .unwrap()
+        .clan
+        .finish_time
+        .is_none());
+    assert!(race
+        .clan_standing("#9VQJ2Y0R")
+        .unwrap()
+        .clan
+        .finish_time
+        .is_some());
+    assert_eq!(race.participant("#82L0C9YG").unwrap().decks_used, Some(16));
+    assert!(race.participant("#9JL0PQ2C").is_none());
+}
+
+#[test]
+fn test_tournament() {
+    let tournament: ClashRoyaleTournamentResponse = serde_json::from_value(serde_json::json!({
+        "tag": "#2PYQ8GC0",
+        "status": "ended",
+        "createdTime": "20211115T094047.000Z",
+        "startedTime": "20211115T100047.000Z",
+        "membersList": [{ "tag": "#82L0C9YG", "score": 12, "rank": 1 }]
+    }))
+    .unwrap();
+
+    assert!(tournament.has_ended());
+    assert!(tournament.ended_time.is_none());
+    assert_eq!(tournament.member("#82L0C9YG").unwrap().rank, Some(1));
+}
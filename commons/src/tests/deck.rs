@@ -0,0 +1,136 @@
+use crate::clients::games::ClashRoyaleBattlelogResponse;
+use crate::data::games::ClashRoyaleCard;
+use crate::database::types::conditions::ConditionCheck;
+use crate::database::types::game::{
+    ClashRoyaleDeckConditions, ClashRoyaleMilestone, ClashRoyaleTeamConditions,
+};
+
+/// The 2.6 Hog Cycle deck.
+const HOG_CYCLE: [ClashRoyaleCard; 8] = [
+    ClashRoyaleCard::HogRider,
+    ClashRoyaleCard::Musketeer,
+    ClashRoyaleCard::Fireball,
+    ClashRoyaleCard::TheLog,
+    ClashRoyaleCard::IceSpirit,
+    ClashRoyaleCard::Skeletons,
+    ClashRoyaleCard::Cannon,
+    ClashRoyaleCard::IceGolem,
+];
+
+fn battlelog(cards: &[u64]) -> ClashRoyaleBattlelogResponse {
+    let cards: Vec<_> = cards
+        .iter()
+        .map(|id| serde_json::json!({ "id": id, "level": 11 }))
+        .collect();
+
+    serde_json::from_value(serde_json::json!({
+        "type": "PvP",
+        "battleTime": "20220101T101010.000Z",
+        "team": [{ "tag": "#A", "crowns": 1, "cards": cards }],
+        "opponent": [{ "tag": "#B", "crowns": 0 }],
+    }))
+    .unwrap()
+}
+
+fn check(conditions: serde_json::Value, cards: &[u64]) -> Vec<ConditionCheck> {
+    let conditions: ClashRoyaleDeckConditions = serde_json::from_value(conditions).unwrap();
+    assert!(conditions.validate().is_none());
+
+    let battlelog = battlelog(cards);
+    let player = &battlelog.team.as_ref().unwrap()[0];
+    conditions.check_response(&battlelog, player, "team.deck")
+}
+
+#[test]
+fn test_deck_composition() {
+    let deck: Vec<_> = HOG_CYCLE.iter().map(|v| *v as u64).collect();
+    let checks = check(
+        serde_json::json!({
+            "averageElixir": { "condition": "<=", "value": [3.0] },
+            "rareCards": { "condition": "anyOf", "value": [4] },
+            "spellCards": { "condition": ">=", "value": [2] },
+            "buildingCards": { "condition": "anyOf", "value": [1] },
+            "winCondition": true,
+        }),
+        &deck,
+    );
+
+    assert_eq!(checks.len(), 5);
+    assert!(ConditionCheck::all_passed(&checks));
+    assert_eq!(checks[0].actual, Some(serde_json::json!(2.6)));
+
+    // No legendary cards.
+    let checks = check(
+        serde_json::json!({ "legendaryCards": { "condition": "anyOf", "value": [0] } }),
+        &deck,
+    );
+    assert!(!ConditionCheck::all_passed(&checks));
+    assert_eq!(checks[0].condition.as_str(), "team.deck.legendaryCards");
+
+    // Without a win condition.
+    let checks = check(serde_json::json!({ "winCondition": true }), &deck[1..]);
+    assert!(!ConditionCheck::all_passed(&checks));
+
+    // Unknown cards cannot be evaluated.
+    let mut unknown_deck = deck.clone();
+    unknown_deck[0] = 26999999;
+    let checks = check(serde_json::json!({ "winCondition": true }), &unknown_deck);
+    assert_eq!(checks.len(), 1);
+    assert!(!checks[0].passed);
+    assert_eq!(checks[0].actual, Some(serde_json::json!([26999999])));
+}
+
+#[test]
+fn test_team_deck() {
+    let cards = |cards: &[ClashRoyaleCard]| -> Vec<_> {
+        cards
+            .iter()
+            .map(|v| serde_json::json!({ "id": *v as u64, "level": 11 }))
+            .collect()
+    };
+    let battlelog: ClashRoyaleBattlelogResponse = serde_json::from_value(serde_json::json!({
+        "type": "clanWarDuel",
+        "battleTime": "20220101T101010.000Z",
+        "team": [
+            { "tag": "#A", "crowns": 1, "cards": cards(&HOG_CYCLE) },
+            { "tag": "#C", "crowns": 1, "cards": cards(&HOG_CYCLE[..6]) },
+        ],
+        "opponent": [{ "tag": "#B", "crowns": 0 }],
+    }))
+    .unwrap();
+    let team = battlelog.team.as_ref().unwrap();
+
+    let conditions: ClashRoyaleTeamConditions = serde_json::from_value(serde_json::json!({
+        "deck": { "buildingCards": { "condition": "anyOf", "value": [1] } },
+    }))
+    .unwrap();
+
+    // Each deck is checked on its own.
+    let checks = conditions.check_response(&battlelog, team, "team");
+    assert_eq!(checks.len(), 2);
+    assert!(checks[0].passed);
+    assert_eq!(checks[0].condition.as_str(), "team.deck[0].buildingCards");
+    assert!(!checks[1].passed);
+    assert_eq!(checks[1].condition.as_str(), "team.deck[1].buildingCards");
+    assert_eq!(checks[1].actual, Some(serde_json::json!(0)));
+}
+
+#[test]
+fn test_deck_validation() {
+    let conditions: ClashRoyaleDeckConditions = serde_json::from_value(serde_json::json!({
+        "troopCards": { "condition": ">", "value": [9] },
+    }))
+    .unwrap();
+    assert!(conditions.validate().is_some());
+
+    let conditions: ClashRoyaleDeckConditions = serde_json::from_value(serde_json::json!({
+        "averageElixir": { "condition": "<", "value": [12.5] },
+    }))
+    .unwrap();
+    assert!(conditions.validate().is_some());
+
+    let rule = "win 1 match where team.deck.averageElixir <= 3.5 and team.deck.legendaryCards = 0 and team.firstMember.deck.winCondition = true";
+    let milestone = ClashRoyaleMilestone::from_rule(rule).unwrap();
+    assert!(milestone.validate().is_none());
+    assert_eq!(milestone.to_rule().unwrap(), rule);
+}
@@ -0,0 +1,222 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::clients::games::ClashRoyaleBattlelogResponse;
+use crate::database::documents::{ChallengeMilestone, FraudConfig, GameChallengeMilestone};
+use crate::database::types::game::GameMilestone;
+use crate::database::types::{DateTime, GameType};
+use crate::games::{ClashRoyaleProgress, FraudSignal, GameProgress, MilestoneVerifier};
+
+fn battlelog(
+    minute: u32,
+    opponent: &str,
+    team_trophies: u64,
+    opponent_trophies: u64,
+) -> ClashRoyaleBattlelogResponse {
+    serde_json::from_value(serde_json::json!({
+        "type": "PvP",
+        "battleTime": format!("20220101T10{:02}00.000Z", minute),
+        "team": [{ "tag": "#A", "startingTrophies": team_trophies }],
+        "opponent": [{ "tag": opponent, "startingTrophies": opponent_trophies }],
+    }))
+    .unwrap()
+}
+
+/// The default configuration enabled and with a single match allowed per opponent.
+fn config() -> FraudConfig {
+    FraudConfig {
+        enabled: true,
+        max_matches_per_opponent: Some(1),
+        ..FraudConfig::default()
+    }
+}
+
+fn fraud_signals(
+    battlelogs: Vec<ClashRoyaleBattlelogResponse>,
+    config: &FraudConfig,
+) -> Vec<FraudSignal> {
+    let battle_times: Vec<_> = battlelogs.iter().map(|v| v.battle_time.clone()).collect();
+    let progress = ClashRoyaleProgress::new(battlelogs, None, None, DateTime::max_datetime());
+    progress.fraud_signals(config, &battle_times)
+}
+
+#[test]
+fn test_fraud_config_default() {
+    // The stored configurations without the signals must behave as the default one.
+    let config: FraudConfig = serde_json::from_str("{}").unwrap();
+    let default = FraudConfig::default();
+    assert!(!config.enabled);
+    assert_eq!(
+        config.max_matches_per_opponent,
+        default.max_matches_per_opponent
+    );
+    assert_eq!(config.check_opponent_bets, default.check_opponent_bets);
+    assert_eq!(config.max_trophy_gap, default.max_trophy_gap);
+    assert_eq!(
+        config.min_seconds_between_matches,
+        default.min_seconds_between_matches
+    );
+
+    // The disabled signals are kept.
+    let config: FraudConfig = serde_json::from_value(
+        serde_json::to_value(FraudConfig {
+            max_trophy_gap: None,
+            ..FraudConfig::default()
+        })
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(config.max_trophy_gap, None);
+}
+
+#[test]
+fn test_fraud_signals_without_signs() {
+    let battlelogs = vec![
+        battlelog(1, "#B", 5000, 5100),
+        battlelog(5, "#C", 5000, 4900),
+        battlelog(9, "#D", 5000, 5000),
+    ];
+
+    assert!(fraud_signals(battlelogs, &config()).is_empty());
+}
+
+#[test]
+fn test_fraud_signals_repeated_opponent() {
+    let battlelogs = vec![
+        battlelog(1, "#B", 5000, 5000),
+        battlelog(5, "#C", 5000, 5000),
+        battlelog(9, "#B", 5000, 5000),
+    ];
+
+    assert_eq!(
+        fraud_signals(battlelogs.clone(), &config()),
+        vec![FraudSignal::RepeatedOpponent {
+            tag: arcstr::literal!("B"),
+            matches: 2,
+        }]
+    );
+
+    let config = FraudConfig {
+        max_matches_per_opponent: Some(2),
+        ..config()
+    };
+    assert!(fraud_signals(battlelogs, &config).is_empty());
+}
+
+#[test]
+fn test_fraud_signals_trophy_gap() {
+    let battlelogs = vec![
+        battlelog(1, "#B", 5000, 3000),
+        battlelog(5, "#C", 5000, 6600),
+    ];
+
+    assert_eq!(
+        fraud_signals(battlelogs.clone(), &config()),
+        vec![
+            FraudSignal::TrophyGap {
+                battle_time: battlelogs[0].battle_time.clone(),
+                gap: 2000,
+            },
+            FraudSignal::TrophyGap {
+                battle_time: battlelogs[1].battle_time.clone(),
+                gap: 1600,
+            },
+        ]
+    );
+
+    let config = FraudConfig {
+        max_trophy_gap: None,
+        ..config()
+    };
+    assert!(fraud_signals(battlelogs, &config).is_empty());
+}
+
+#[test]
+fn test_fraud_signals_clustered_matches() {
+    let battlelogs = vec![
+        battlelog(1, "#B", 5000, 5000),
+        battlelog(1, "#C", 5000, 5000),
+        battlelog(3, "#D", 5000, 5000),
+    ];
+
+    assert_eq!(
+        fraud_signals(battlelogs.clone(), &config()),
+        vec![FraudSignal::ClusteredMatches {
+            battle_time: battlelogs[1].battle_time.clone(),
+            seconds: 0,
+        }]
+    );
+
+    let config = FraudConfig {
+        min_seconds_between_matches: None,
+        ..config()
+    };
+    assert!(fraud_signals(battlelogs, &config).is_empty());
+}
+
+#[test]
+fn test_fraud_signals_used_matches() {
+    let battlelogs = vec![
+        battlelog(1, "#B", 5000, 5000),
+        battlelog(5, "#C", 5000, 5000),
+        battlelog(9, "#B", 5000, 5000),
+    ];
+    let progress =
+        ClashRoyaleProgress::new(battlelogs.clone(), None, None, DateTime::max_datetime());
+
+    // The matches not used by the milestones are ignored.
+    let battle_times = vec![
+        battlelogs[0].battle_time.clone(),
+        battlelogs[1].battle_time.clone(),
+    ];
+    assert!(progress.fraud_signals(&config(), &battle_times).is_empty());
+    assert_eq!(
+        progress.opponent_matches(&battle_times),
+        vec![
+            (arcstr::literal!("B"), battlelogs[0].battle_time.clone()),
+            (arcstr::literal!("C"), battlelogs[1].battle_time.clone()),
+        ]
+    );
+}
+
+#[test]
+fn test_fraud_signals_of_verified_milestones() {
+    let battlelog = |kind: &str, minute: u32, opponent: &str| -> ClashRoyaleBattlelogResponse {
+        serde_json::from_value(serde_json::json!({
+            "type": kind,
+            "battleTime": format!("20220101T10{:02}00.000Z", minute),
+            "team": [{ "tag": "#A", "crowns": 1 }],
+            "opponent": [{ "tag": opponent, "crowns": 0 }],
+        }))
+        .unwrap()
+    };
+    let battlelogs = vec![
+        battlelog("PvP", 1, "#B"),
+        battlelog("friendly", 3, "#B"),
+        battlelog("PvP", 5, "#C"),
+    ];
+    let progress =
+        ClashRoyaleProgress::new(battlelogs.clone(), None, None, DateTime::max_datetime());
+
+    let mut progresses: HashMap<GameType, Box<dyn GameProgress>> = HashMap::new();
+    progresses.insert(GameType::ClashRoyale, Box::new(progress));
+    let verifier = MilestoneVerifier::new(progresses, HashSet::new());
+
+    let milestones = vec![ChallengeMilestone::GameMilestone(GameChallengeMilestone {
+        milestone: GameMilestone::from_rule("win any 2 matches where battleKind in [ladder]")
+            .unwrap(),
+    })];
+    let progress = verifier.verify_all_of(&milestones, None);
+    assert!(progress.status.is_completed());
+
+    // The friendly match against the same opponent does not count.
+    assert!(verifier
+        .fraud_signals(&config(), &milestones, &progress)
+        .is_empty());
+    assert_eq!(
+        verifier.opponent_matches(&milestones, &progress),
+        vec![
+            (arcstr::literal!("B"), battlelogs[0].battle_time.clone()),
+            (arcstr::literal!("C"), battlelogs[2].battle_time.clone()),
+        ]
+    );
+}
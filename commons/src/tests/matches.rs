@@ -0,0 +1,105 @@
+use crate::database::types::conditions::ConditionCheck;
+use crate::database::types::game::{NonQualifyingMatchPolicy, WinMatchesMode};
+use crate::database::types::DateTime;
+use crate::games::matches::verify_win_matches;
+use crate::games::MilestoneStatus;
+
+/// Verifies two 'win' conditions against the results, one match per minute.
+fn verify(
+    results: &[bool],
+    mode: WinMatchesMode,
+    non_qualifying: NonQualifyingMatchPolicy,
+) -> (MilestoneStatus, u32) {
+    let start = DateTime::now();
+    let battlelogs: Vec<_> = results
+        .iter()
+        .enumerate()
+        .map(|(i, win)| (start.after_seconds(60 * i as u64), *win))
+        .collect();
+
+    let progress = verify_win_matches(
+        &battlelogs,
+        &[(), ()],
+        mode,
+        non_qualifying,
+        None,
+        &DateTime::max_datetime(),
+        |battlelog| &battlelog.0,
+        |_, battlelog| vec![ConditionCheck::new("result", battlelog.1)],
+    );
+
+    (progress.status, progress.matched)
+}
+
+#[test]
+fn test_win_matches_modes() {
+    let results = [false, true, false, true];
+
+    let (status, matched) = verify(
+        &results,
+        WinMatchesMode::FirstN,
+        NonQualifyingMatchPolicy::Ignore,
+    );
+    assert!(status.is_failed());
+    assert_eq!(matched, 1);
+
+    let (status, matched) = verify(
+        &results,
+        WinMatchesMode::AnyN,
+        NonQualifyingMatchPolicy::Ignore,
+    );
+    assert!(status.is_completed());
+    assert_eq!(matched, 2);
+
+    let (status, matched) = verify(
+        &results,
+        WinMatchesMode::AnyN,
+        NonQualifyingMatchPolicy::Fail,
+    );
+    assert!(status.is_failed());
+    assert_eq!(matched, 1);
+
+    let (status, matched) = verify(
+        &results,
+        WinMatchesMode::Consecutive,
+        NonQualifyingMatchPolicy::Ignore,
+    );
+    assert_eq!(status, MilestoneStatus::Initiated);
+    assert_eq!(matched, 1);
+
+    let (status, matched) = verify(
+        &[false, true, false, true, true],
+        WinMatchesMode::Consecutive,
+        NonQualifyingMatchPolicy::Ignore,
+    );
+    assert!(status.is_completed());
+    assert_eq!(matched, 2);
+}
+
+#[test]
+fn test_win_matches_after_expiration() {
+    let start = DateTime::now();
+    let battlelogs = [start.clone(), start.after_seconds(60)];
+
+    for mode in [WinMatchesMode::FirstN, WinMatchesMode::AnyN] {
+        let progress = verify_win_matches(
+            &battlelogs,
+            &[(), ()],
+            mode,
+            NonQualifyingMatchPolicy::Ignore,
+            None,
+            &start.after_seconds(30),
+            |battlelog| battlelog,
+            |_, _| vec![ConditionCheck::new("result", true)],
+        );
+
+        assert!(progress.status.is_failed());
+        assert_eq!(progress.matched, 1);
+
+        let last_match = progress.matches.last().unwrap();
+        assert!(last_match
+            .conditions
+            .iter()
+            .any(|v| v.condition == "expiration" && !v.passed));
+    }
+}
@@ -1,2 +1,19 @@
+mod achievement;
 mod aql;
+mod battle_kind;
+mod brawl_stars;
+mod catalog;
+mod clients;
+mod deck;
+mod fraud;
+mod matches;
+mod participant;
+mod relative;
+mod rules;
+mod schema;
+mod statistics;
+mod throttling;
+mod time_window;
+mod towers;
 mod uuid;
+mod verifier;
@@ -0,0 +1,79 @@
+use crate::clients::games::ClashRoyalePlayerInfoResponse;
+use crate::database::documents::{ClashRoyaleTagVerification, ClashRoyaleTagVerificationKind};
+use crate::database::types::DateTime;
+
+fn player(deck: &[u64], favourite_card: u64) -> ClashRoyalePlayerInfoResponse {
+    let cards: Vec<_> = (26000000..26000010)
+        .map(|id| serde_json::json!({ "id": id, "level": 9 }))
+        .collect();
+    let deck: Vec<_> = deck
+        .iter()
+        .map(|id| serde_json::json!({ "id": id, "level": 9 }))
+        .collect();
+
+    serde_json::from_value(serde_json::json!({
+        "tag": "#ABC",
+        "cards": cards,
+        "currentDeck": deck,
+        "currentFavouriteCard": { "id": favourite_card, "level": 9 },
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_tag_verification_favourite_card() {
+    let initial_player = player(&[], 26000000);
+    let verification = ClashRoyaleTagVerification::new_random(
+        arcstr::literal!("#ABC"),
+        ClashRoyaleTagVerificationKind::FavouriteCard,
+        &initial_player,
+        DateTime::now().after_seconds(60),
+    )
+    .unwrap();
+
+    let cards = verification.cards.unwrap_as_ref();
+    assert_eq!(cards.len(), 1);
+    assert_ne!(cards[0] as u64, 26000000);
+    assert!(!verification.is_expired());
+    assert!(!verification.verify(&initial_player));
+
+    let verified_player = player(&[], cards[0] as u64);
+    assert!(verification.verify(&verified_player));
+}
+
+#[test]
+fn test_tag_verification_deck() {
+    let initial_player = player(&[26000000, 26000001], 26000000);
+    let verification = ClashRoyaleTagVerification::new_random(
+        arcstr::literal!("#ABC"),
+        ClashRoyaleTagVerificationKind::Deck,
+        &initial_player,
+        DateTime::now().after_seconds(60),
+    )
+    .unwrap();
+
+    let mut deck: Vec<_> = verification
+        .cards
+        .unwrap_as_ref()
+        .iter()
+        .map(|v| *v as u64)
+        .collect();
+    assert_eq!(deck.len(), 8);
+    assert!(!verification.verify(&initial_player));
+
+    // Any order is accepted but not a partial deck.
+    deck.reverse();
+    assert!(verification.verify(&player(&deck, 26000000)));
+    assert!(!verification.verify(&player(&deck[1..], 26000000)));
+
+    // Not enough cards.
+    let mut poor_player = player(&[], 26000000);
+    poor_player.cards.as_mut().unwrap().truncate(7);
+    assert!(ClashRoyaleTagVerification::new_random(
+        arcstr::literal!("#ABC"),
+        ClashRoyaleTagVerificationKind::Deck,
+        &poor_player,
+        DateTime::now().after_seconds(60),
+    )
+    .is_none());
+}
@@ -0,0 +1,144 @@
+use crate::clients::games::ClashRoyaleBattlelogResponse;
+use crate::database::types::conditions::ConditionCheck;
+use crate::database::types::game::{ClashRoyaleRelativeConditions, ClashRoyaleTeamConditions};
+
+fn battlelog(team: serde_json::Value, opponent: serde_json::Value) -> ClashRoyaleBattlelogResponse {
+    serde_json::from_value(serde_json::json!({
+        "type": "PvP",
+        "battleTime": "20220101T101010.000Z",
+        "team": team,
+        "opponent": opponent,
+    }))
+    .unwrap()
+}
+
+fn battlelog_with_unknown_opponent() -> ClashRoyaleBattlelogResponse {
+    battlelog(
+        serde_json::json!([{ "tag": "#A", "startingTrophies": 5000 }]),
+        serde_json::json!([{ "tag": "#B" }]),
+    )
+}
+
+fn check_trophy_difference(battlelog: &ClashRoyaleBattlelogResponse) -> ConditionCheck {
+    let conditions: ClashRoyaleRelativeConditions = serde_json::from_value(serde_json::json!({
+        "trophyDifference": { "condition": ">=", "value": [-200] },
+    }))
+    .unwrap();
+
+    let mut checks = conditions.check_response(
+        battlelog.team.as_ref().unwrap(),
+        battlelog.opponent.as_ref().unwrap(),
+        &[],
+        "relative",
+    );
+    assert_eq!(checks.len(), 1);
+    checks.remove(0)
+}
+
+#[test]
+fn test_trophy_difference() {
+    let battlelog = battlelog(
+        serde_json::json!([
+            { "tag": "#A", "startingTrophies": 5000 },
+            { "tag": "#C", "startingTrophies": 5100 },
+        ]),
+        serde_json::json!([
+            { "tag": "#B", "startingTrophies": 4950 },
+            { "tag": "#D", "startingTrophies": 5000 },
+        ]),
+    );
+
+    let check = check_trophy_difference(&battlelog);
+    assert!(check.passed);
+    assert_eq!(check.actual, Some(serde_json::json!(-150)));
+}
+
+#[test]
+fn test_trophy_difference_with_unknown_trophies() {
+    // The missing trophies must not count as 0, which would pass the condition.
+    let battlelog = battlelog(
+        serde_json::json!([
+            { "tag": "#A", "startingTrophies": 5000 },
+            { "tag": "#C" },
+        ]),
+        serde_json::json!([
+            { "tag": "#B", "startingTrophies": 4950 },
+            { "tag": "#D", "startingTrophies": 5000 },
+        ]),
+    );
+
+    let check = check_trophy_difference(&battlelog);
+    assert!(!check.passed);
+    assert_eq!(check.actual, None);
+
+    let battlelog = battlelog_with_unknown_opponent();
+    let check = check_trophy_difference(&battlelog);
+    assert!(!check.passed);
+    assert_eq!(check.actual, None);
+}
+
+#[test]
+fn test_team_starting_trophies_with_unknown_trophies() {
+    let conditions: ClashRoyaleTeamConditions = serde_json::from_value(serde_json::json!({
+        "startingTrophies": { "condition": "<", "value": [6000] },
+    }))
+    .unwrap();
+
+    let battlelog = battlelog_with_unknown_opponent();
+    let checks = conditions.check_response(&battlelog, battlelog.team.as_ref().unwrap(), "team");
+    assert!(ConditionCheck::all_passed(&checks));
+
+    let checks =
+        conditions.check_response(&battlelog, battlelog.opponent.as_ref().unwrap(), "opponent");
+    assert!(!ConditionCheck::all_passed(&checks));
+    assert_eq!(checks[0].actual, None);
+}
+
+#[test]
+fn test_card_level_difference_with_mixed_rarities() {
+    let conditions: ClashRoyaleRelativeConditions = serde_json::from_value(serde_json::json!({
+        "cardLevelDifference": { "condition": "<=", "value": [0.0] },
+    }))
+    .unwrap();
+
+    // Knight (common) 11 and Ice Wizard (legendary) 3, i.e. 11 and 11 as commons, against
+    // P.E.K.K.A (epic) 6 and Princess (legendary) 4, i.e. 11 and 12 as commons.
+    let mixed = battlelog(
+        serde_json::json!([{
+            "tag": "#A",
+            "cards": [{ "id": 26000000, "level": 11 }, { "id": 26000023, "level": 3 }],
+        }]),
+        serde_json::json!([{
+            "tag": "#B",
+            "cards": [{ "id": 26000004, "level": 6 }, { "id": 26000026, "level": 4 }],
+        }]),
+    );
+
+    let checks = conditions.check_response(
+        mixed.team.as_ref().unwrap(),
+        mixed.opponent.as_ref().unwrap(),
+        &[],
+        "relative",
+    );
+    assert_eq!(checks[0].condition.as_str(), "relative.cardLevelDifference");
+    assert_eq!(checks[0].actual, Some(serde_json::json!(0.5)));
+    assert!(!checks[0].passed);
+
+    // Unknown cards are ignored.
+    let unknown = battlelog(
+        serde_json::json!([{ "tag": "#A", "cards": [{ "id": 26000000, "level": 13 }] }]),
+        serde_json::json!([{
+            "tag": "#B",
+            "cards": [{ "id": 26000023, "level": 5 }, { "id": 1, "level": 1 }],
+        }]),
+    );
+
+    let checks = conditions.check_response(
+        unknown.team.as_ref().unwrap(),
+        unknown.opponent.as_ref().unwrap(),
+        &[],
+        "relative",
+    );
+    assert_eq!(checks[0].actual, Some(serde_json::json!(0.0)));
+    assert!(checks[0].passed);
+}
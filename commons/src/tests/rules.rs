@@ -0,0 +1,102 @@
+use crate::database::types::conditions::{OptionCondition, OrderedCondition};
+use crate::database::types::game::{ClashRoyaleGameMode, ClashRoyaleMilestone, WinMatchesMode};
+
+#[test]
+fn test_parse_win_matches_rule() {
+    let rule = "win 3 matches in 1v1 where team.crowns >= 2 and opponent.trophies > 5000";
+    let milestone = ClashRoyaleMilestone::from_rule(rule).unwrap();
+
+    let params = match &milestone {
+        ClashRoyaleMilestone::WinMatches(v) => v,
+        _ => panic!("Not a 'win matches' milestone"),
+    };
+    assert_eq!(params.mode, None);
+    assert_eq!(params.matches.len(), 3);
+
+    let conditions = &params.matches[2];
+    assert!(matches!(
+        conditions.game_mode.as_ref().unwrap(),
+        OptionCondition::AnyOf(v) if v == &[ClashRoyaleGameMode::OneVsOne]
+    ));
+    assert!(matches!(
+        conditions.team.as_ref().unwrap().crowns.as_ref().unwrap(),
+        OrderedCondition::GreaterOrEqualThan(v) if v == &[2]
+    ));
+    assert!(matches!(
+        conditions.opponent.as_ref().unwrap().starting_trophies.as_ref().unwrap(),
+        OrderedCondition::GreaterThan(v) if v == &[5000]
+    ));
+
+    assert_eq!(milestone.to_rule().unwrap(), rule);
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_print_rules() {
+    let rules = [
+        "win consecutive 2 matches where result = win and battleKind in [ladder, pathOfLegends] then 1 match where relative.trophyDifference >= -200 strict",
+        "win 1 match where timeWindow.utcOffset = 60 and timeWindow.weekdays not in [saturday, sunday] and team.firstMember.kingTowerHitPoints = 0",
+        "achieve where measure = increment and wins >= 10 and losses < 20",
+        "statistics where matchCount >= 10 and winRate >= 60",
+    ];
+
+    for rule in rules.iter() {
+        let milestone = ClashRoyaleMilestone::from_rule(rule).unwrap();
+        assert_eq!(&milestone.to_rule().unwrap(), rule);
+    }
+
+    let milestone = ClashRoyaleMilestone::from_rule("win any 1 match strict").unwrap();
+    match &milestone {
+        ClashRoyaleMilestone::WinMatches(v) => assert_eq!(v.mode, Some(WinMatchesMode::AnyN)),
+        _ => panic!("Not a 'win matches' milestone"),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_rule_errors() {
+    let errors = [
+        ("win 0 matches", 4),
+        ("win 3 games", 6),
+        ("win 3 matches where team.color = red", 20),
+        ("win 3 matches where team.crowns >= many", 35),
+        ("win 3 matches where gameMode > 1v1", 29),
+        (
+            "win 3 matches where team.crowns = 1 and team.crowns > 1",
+            40,
+        ),
+        (
+            "win 3 matches where team.crowns = 1 and team.crowns = 2",
+            40,
+        ),
+        (
+            "win 3 matches where gameMode = 1v1 and gameMode in [2v2]",
+            39,
+        ),
+        ("win 1 match in 1v1 where gameMode = 2v2", 25),
+        ("win 101 matches", 4),
+        ("win 60 matches then 41 matches", 20),
+        ("win 3 matches where result = \"win", 29),
+        ("win 3 matches where team.allowedCards = 1", 20),
+        ("statistics where winRate >= 60 or wins > 1", 31),
+    ];
+
+    for (rule, position) in errors.iter() {
+        let error = ClashRoyaleMilestone::from_rule(rule).unwrap_err();
+        assert_eq!(error.position, *position, "{}: {}", rule, error);
+    }
+
+    // Repeated exclusions are merged.
+    let rule = "win 1 match where gameMode != 1v1 and gameMode != 2v2";
+    let milestone = ClashRoyaleMilestone::from_rule(rule).unwrap();
+    assert_eq!(
+        milestone.to_rule().unwrap(),
+        "win 1 match where gameMode not in [1v1, 2v2]"
+    );
+}
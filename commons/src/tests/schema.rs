@@ -0,0 +1,408 @@
+use std::collections::BTreeSet;
+
+use chrono::NaiveTime;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::data::games::{
+    BrawlStarsBrawler, BrawlStarsGameMode, ClashRoyaleArena, ClashRoyaleCard,
+};
+use crate::database::types::conditions::{
+    DayTimeRange, OptionCondition, OrderedCondition, TimeWindowCondition, Weekday,
+};
+use crate::database::types::game::{
+    BrawlStarsGameResult, BrawlStarsMatchConditions, BrawlStarsWinMatches, ClashRoyaleBattleKind,
+    ClashRoyaleCardCondition, ClashRoyaleCardConditions, ClashRoyaleDeckConditions,
+    ClashRoyaleGameMode, ClashRoyaleGameResult, ClashRoyaleMatchConditions,
+    ClashRoyaleRelativeConditions, ClashRoyaleStatisticMeasure, ClashRoyaleStatisticsConditions,
+    ClashRoyaleTeamConditions, ClashRoyaleTeamMemberConditions, ClashRoyaleUserConditions,
+    ClashRoyaleWinMatches, GameMilestone, NonQualifyingMatchPolicy, WinMatchesMode,
+};
+use crate::database::types::schema::root_schema_for;
+use crate::database::types::DayTime;
+
+#[test]
+fn test_milestone_schema() {
+    let schema = root_schema_for::<GameMilestone>();
+    let definitions = &schema["definitions"];
+
+    assert_eq!(
+        schema["$schema"],
+        json!("http://json-schema.org/draft-07/schema#")
+    );
+    assert_eq!(schema["title"], json!("GameMilestone"));
+
+    // Every reference must point to a definition.
+    let mut references = Vec::new();
+    collect_references(&schema, &mut references);
+    assert!(!references.is_empty());
+
+    for reference in references {
+        let name = reference.trim_start_matches("#/definitions/");
+        assert!(
+            definitions.get(name).is_some(),
+            "Missing definition: {}",
+            name
+        );
+    }
+
+    // Enums are serialized as in JSON.
+    assert!(enum_values(&definitions["ClashRoyaleGameMode"]).contains(&json!("oneVsOne")));
+    assert!(enum_values(&definitions["ClashRoyaleArena"]).contains(&json!(54000001)));
+
+    // Limits of validate.
+    let statistics = find_variant(
+        &definitions["ClashRoyaleMilestone"],
+        "type",
+        &json!("statistics"),
+    );
+    let statistics = &statistics["properties"]["params"];
+    assert_eq!(
+        statistics["anyOf"][0],
+        json!({ "required": ["matchCount"] })
+    );
+    assert_eq!(statistics["anyOf"].as_array().unwrap().len(), 5);
+
+    let win_rate = resolve(
+        definitions,
+        &definitions["ClashRoyaleStatisticsConditions"]["properties"]["winRate"],
+    );
+    for variant in win_rate["oneOf"].as_array().unwrap() {
+        let items = &variant["properties"]["value"]["items"];
+        assert_eq!(items["minimum"], json!(0.0));
+        assert_eq!(items["maximum"], json!(100.0));
+    }
+
+    let time_window = &definitions["TimeWindowCondition"];
+    assert_eq!(
+        time_window["properties"]["utcOffset"]["minimum"],
+        json!(-840.0)
+    );
+    assert_eq!(time_window["properties"]["dayTimes"]["minItems"], json!(1));
+}
+
+#[test]
+fn test_milestone_schema_tags() {
+    let schema = root_schema_for::<GameMilestone>();
+    let definitions = &schema["definitions"];
+
+    let milestone =
+        GameMilestone::from_rule("win 2 matches in 1v1 where team.crowns >= 2").unwrap();
+    let milestone = serde_json::to_value(&milestone).unwrap();
+
+    let game_variant = find_variant(&schema, "game", &milestone["game"]);
+    assert_eq!(
+        game_variant["properties"]["challenge"]["$ref"],
+        json!("#/definitions/ClashRoyaleMilestone")
+    );
+
+    let milestone = &milestone["challenge"];
+    let variant = find_variant(
+        &definitions["ClashRoyaleMilestone"],
+        "type",
+        &milestone["type"],
+    );
+    assert_eq!(
+        variant["properties"]["params"]["$ref"],
+        json!("#/definitions/ClashRoyaleWinMatches")
+    );
+
+    // All the fields written are described.
+    let conditions = &milestone["params"]["matches"][0];
+    let properties = &definitions["ClashRoyaleMatchConditions"]["properties"];
+    for name in conditions.as_object().unwrap().keys() {
+        assert!(!properties[name].is_null(), "Missing property: {}", name);
+    }
+
+    let condition = &conditions["team"]["crowns"]["condition"];
+    let ordered = resolve(
+        definitions,
+        &definitions["ClashRoyaleTeamConditions"]["properties"]["crowns"],
+    );
+    find_variant(ordered, "condition", condition);
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_milestone_schema_properties() {
+    let schema = root_schema_for::<GameMilestone>();
+    let definitions = &schema["definitions"];
+
+    // The values are built without defaults so that new fields must be added here.
+    let card_condition = ClashRoyaleCardCondition {
+        card_id: Some(ClashRoyaleCard::Knight),
+        level: ordered(14),
+        count: ordered(1),
+    };
+    let cards = ClashRoyaleCardConditions {
+        conditions: vec![card_condition.clone()],
+    };
+    let deck = ClashRoyaleDeckConditions {
+        average_elixir: ordered(3.5),
+        common_cards: ordered(1),
+        rare_cards: ordered(1),
+        epic_cards: ordered(1),
+        legendary_cards: ordered(1),
+        champion_cards: ordered(1),
+        troop_cards: ordered(1),
+        building_cards: ordered(1),
+        spell_cards: ordered(1),
+        win_condition: Some(true),
+    };
+    let member = ClashRoyaleTeamMemberConditions {
+        starting_trophies: ordered(5000),
+        king_tower_hit_points: ordered(0),
+        princess_tower_1_hit_points: ordered(0),
+        princess_tower_2_hit_points: ordered(0),
+        allowed_cards: Some(cards.clone()),
+        forbidden_cards: Some(cards.clone()),
+        deck: Some(deck.clone()),
+    };
+    let team = ClashRoyaleTeamConditions {
+        crowns: ordered(3),
+        starting_trophies: ordered(5000),
+        king_tower_hit_points: ordered(0),
+        princess_tower_1_hit_points: ordered(0),
+        princess_tower_2_hit_points: ordered(0),
+        allowed_cards: Some(cards.clone()),
+        forbidden_cards: Some(cards.clone()),
+        deck: Some(deck.clone()),
+        first_member: Some(member.clone()),
+        second_member: Some(member.clone()),
+    };
+    let relative = ClashRoyaleRelativeConditions {
+        trophy_difference: ordered(-200),
+        card_level_difference: ordered(1.0),
+        allow_clan_mates: Some(false),
+        allow_repeated_opponents: Some(false),
+    };
+    let day_time = DayTime(NaiveTime::from_hms(10, 0, 0));
+    let day_time_range = DayTimeRange {
+        from: day_time.clone(),
+        to: day_time,
+    };
+    let time_window = TimeWindowCondition {
+        utc_offset: Some(60),
+        day_times: Some(vec![day_time_range.clone()]),
+        weekdays: option(Weekday::Monday),
+    };
+    let match_conditions = ClashRoyaleMatchConditions {
+        result: option(ClashRoyaleGameResult::Win),
+        allow_friends: Some(false),
+        battle_kind: option(ClashRoyaleBattleKind::Ladder),
+        arena: ordered(ClashRoyaleArena::Arena1),
+        game_mode: option(ClashRoyaleGameMode::OneVsOne),
+        time_window: Some(time_window.clone()),
+        team: Some(team.clone()),
+        opponent: Some(team.clone()),
+        relative: Some(relative.clone()),
+    };
+    let win_matches = ClashRoyaleWinMatches {
+        mode: Some(WinMatchesMode::AnyN),
+        non_qualifying: Some(NonQualifyingMatchPolicy::Fail),
+        matches: vec![match_conditions.clone()],
+    };
+    let user = ClashRoyaleUserConditions {
+        measure: Some(ClashRoyaleStatisticMeasure::Absolute),
+        exp_level: ordered(10),
+        trophies: ordered(1),
+        best_trophies: ordered(1),
+        wins: ordered(1),
+        three_crowns_wins: ordered(1),
+        losses: ordered(1),
+        battle_count: ordered(1),
+        challenge_cards_won: ordered(1),
+        challenge_max_wins: ordered(1),
+        tournament_cards_won: ordered(1),
+        tournament_battle_count: ordered(1),
+        donations: ordered(1),
+        donations_received: ordered(1),
+        total_donations: ordered(1),
+        war_day_wins: ordered(1),
+        clan_cards_collected: ordered(1),
+        current_arena: ordered(ClashRoyaleArena::Arena1),
+        allowed_cards: Some(cards.clone()),
+        forbidden_cards: Some(cards.clone()),
+    };
+    let statistics = ClashRoyaleStatisticsConditions {
+        battle_kind: option(ClashRoyaleBattleKind::Ladder),
+        game_mode: option(ClashRoyaleGameMode::OneVsOne),
+        match_count: ordered(1),
+        wins: ordered(1),
+        win_rate: ordered(50),
+        crowns: ordered(1),
+        distinct_cards: ordered(1),
+        min_matches: Some(1),
+    };
+    let brawl_stars_match_conditions = BrawlStarsMatchConditions {
+        result: option(BrawlStarsGameResult::Victory),
+        game_mode: option(BrawlStarsGameMode::GemGrab),
+        brawler: option(BrawlStarsBrawler::Shelly),
+        trophies: ordered(500),
+    };
+    let brawl_stars_win_matches = BrawlStarsWinMatches {
+        mode: Some(WinMatchesMode::AnyN),
+        non_qualifying: Some(NonQualifyingMatchPolicy::Fail),
+        matches: vec![brawl_stars_match_conditions.clone()],
+    };
+
+    let values = vec![
+        ("ClashRoyaleCardCondition", to_value(&card_condition)),
+        ("ClashRoyaleCardConditions", to_value(&cards)),
+        ("ClashRoyaleDeckConditions", to_value(&deck)),
+        ("ClashRoyaleTeamMemberConditions", to_value(&member)),
+        ("ClashRoyaleTeamConditions", to_value(&team)),
+        ("ClashRoyaleRelativeConditions", to_value(&relative)),
+        ("DayTimeRange", to_value(&day_time_range)),
+        ("TimeWindowCondition", to_value(&time_window)),
+        ("ClashRoyaleMatchConditions", to_value(&match_conditions)),
+        ("ClashRoyaleWinMatches", to_value(&win_matches)),
+        ("ClashRoyaleUserConditions", to_value(&user)),
+        ("ClashRoyaleStatisticsConditions", to_value(&statistics)),
+        (
+            "BrawlStarsMatchConditions",
+            to_value(&brawl_stars_match_conditions),
+        ),
+        ("BrawlStarsWinMatches", to_value(&brawl_stars_win_matches)),
+    ];
+
+    for (name, value) in &values {
+        assert_properties(name, &definitions[name], value);
+    }
+
+    // Every object of the schema is checked.
+    let checked: BTreeSet<_> = values.iter().map(|(name, _)| name.to_string()).collect();
+    let objects: BTreeSet<_> = definitions
+        .as_object()
+        .unwrap()
+        .iter()
+        .filter(|(_, schema)| object_schema(schema).is_some())
+        .map(|(name, _)| name.clone())
+        .collect();
+    assert_eq!(checked, objects);
+}
+
+#[test]
+fn test_milestone_schema_legacy_win_matches() {
+    let schema = root_schema_for::<GameMilestone>();
+    let definitions = &schema["definitions"];
+
+    for name in &["ClashRoyaleWinMatches", "BrawlStarsWinMatches"] {
+        let legacy = definitions[name]["anyOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|v| v["type"] == json!("array"))
+            .unwrap_or_else(|| panic!("Missing legacy format: {}", name));
+        assert_eq!(legacy["minItems"], json!(1));
+    }
+
+    // The legacy format is still accepted.
+    let milestone: ClashRoyaleWinMatches = serde_json::from_value(
+        json!([{ "gameMode": { "condition": "anyOf", "value": ["oneVsOne"] } }]),
+    )
+    .unwrap();
+    assert_eq!(milestone.matches.len(), 1);
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn collect_references<'a>(schema: &'a Value, references: &mut Vec<&'a str>) {
+    match schema {
+        Value::Object(map) => {
+            for (key, value) in map {
+                match (key.as_str(), value) {
+                    ("$ref", Value::String(v)) => references.push(v),
+                    _ => collect_references(value, references),
+                }
+            }
+        }
+        Value::Array(list) => {
+            for value in list {
+                collect_references(value, references);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Gets the definition of a reference, also when it is wrapped to add a description.
+fn resolve<'a>(definitions: &'a Value, schema: &'a Value) -> &'a Value {
+    let reference = match &schema["allOf"][0]["$ref"] {
+        Value::Null => &schema["$ref"],
+        v => v,
+    };
+
+    match reference.as_str() {
+        Some(v) => &definitions[v.trim_start_matches("#/definitions/")],
+        None => schema,
+    }
+}
+
+fn enum_values(schema: &Value) -> Vec<Value> {
+    match schema["oneOf"].as_array() {
+        Some(variants) => variants.iter().flat_map(enum_values).collect(),
+        None => schema["enum"].as_array().unwrap().clone(),
+    }
+}
+
+fn find_variant<'a>(schema: &'a Value, tag: &str, name: &Value) -> &'a Value {
+    schema["oneOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|v| &v["properties"][tag]["enum"][0] == name)
+        .unwrap_or_else(|| panic!("Missing variant: {}", name))
+}
+
+/// The object schema of a struct, including the current format of those that also accept
+/// a legacy one.
+fn object_schema(schema: &Value) -> Option<&Value> {
+    match schema["anyOf"].as_array() {
+        Some(variants) => variants.iter().find(|v| v["type"] == json!("object")),
+        None if schema["type"] == json!("object") => Some(schema),
+        None => None,
+    }
+}
+
+/// Checks the properties of the object schema of a type are exactly the keys of `value`,
+/// i.e. the names of its fields as serialized.
+fn assert_properties(name: &str, schema: &Value, value: &Value) {
+    let schema = object_schema(schema).unwrap_or_else(|| panic!("Missing object: {}", name));
+
+    // Unknown properties are ignored by serde.
+    assert!(schema["additionalProperties"].is_null());
+
+    let properties = schema["properties"].as_object().unwrap();
+    let keys = value.as_object().unwrap();
+
+    for key in keys.keys() {
+        assert!(
+            properties.contains_key(key),
+            "Missing property: {}.{}",
+            name,
+            key
+        );
+    }
+
+    for key in properties.keys() {
+        assert!(keys.contains_key(key), "Unknown property: {}.{}", name, key);
+    }
+}
+
+fn to_value<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap()
+}
+
+fn ordered<T: PartialOrd>(value: T) -> Option<OrderedCondition<T>> {
+    Some(OrderedCondition::AnyOf(vec![value]))
+}
+
+fn option<T: PartialEq>(value: T) -> Option<OptionCondition<T>> {
+    Some(OptionCondition::AnyOf(vec![value]))
+}
@@ -0,0 +1,133 @@
+use crate::clients::games::ClashRoyaleBattlelogResponse;
+use crate::database::types::game::GameMilestone;
+use crate::database::types::DateTime;
+use crate::games::{ClashRoyaleProgress, GameProgress, MilestoneStatus};
+
+fn battlelog(kind: &str, minute: u32, won: bool) -> ClashRoyaleBattlelogResponse {
+    serde_json::from_value(serde_json::json!({
+        "type": kind,
+        "battleTime": format!("20220101T10{:02}00.000Z", minute),
+        "team": [{ "tag": "#A", "crowns": if won { 1 } else { 0 } }],
+        "opponent": [{ "tag": "#B", "crowns": if won { 0 } else { 1 } }],
+    }))
+    .unwrap()
+}
+
+fn verify(
+    battlelogs: Vec<ClashRoyaleBattlelogResponse>,
+    bet_expiration: DateTime,
+    rule: &str,
+) -> MilestoneStatus {
+    let progress = ClashRoyaleProgress::new(battlelogs, None, None, bet_expiration);
+    let milestone = GameMilestone::from_rule(rule).unwrap();
+
+    progress.verify(&milestone, None).status
+}
+
+/// An expiration after the matches but already past.
+fn expired() -> DateTime {
+    DateTime::new(battlelog("PvP", 59, true).battle_time.0)
+}
+
+#[test]
+fn test_statistics_over_the_whole_window() {
+    let battlelogs = vec![
+        battlelog("PvP", 1, true),
+        battlelog("PvP", 2, false),
+        battlelog("PvP", 3, false),
+    ];
+
+    // The first match alone fulfills the conditions but the whole window does not.
+    let rule = "statistics where winRate >= 60";
+    assert_eq!(
+        verify(battlelogs.clone(), expired(), rule),
+        MilestoneStatus::Failed
+    );
+
+    let rule = "statistics where wins >= 1 and matchCount >= 3";
+    assert_eq!(
+        verify(battlelogs.clone(), expired(), rule),
+        MilestoneStatus::Completed(Some(battlelogs[2].battle_time.clone()))
+    );
+
+    // Completed only when the bet expires.
+    assert_eq!(
+        verify(battlelogs.clone(), DateTime::max_datetime(), rule),
+        MilestoneStatus::Initiated
+    );
+    assert_eq!(
+        verify(Vec::new(), DateTime::max_datetime(), rule),
+        MilestoneStatus::NotInitiated
+    );
+
+    // The matches after the expiration are not aggregated.
+    let expiration = DateTime::new(battlelogs[2].battle_time.0);
+    assert_eq!(
+        verify(battlelogs, expiration, rule),
+        MilestoneStatus::Failed
+    );
+}
+
+#[test]
+fn test_statistics_qualifying_matches() {
+    let battlelogs = vec![
+        battlelog("PvP", 1, true),
+        battlelog("friendly", 2, false),
+        battlelog("pathOfLegend", 3, true),
+        battlelog("challenge", 4, false),
+    ];
+
+    // Friendly matches are never aggregated by default.
+    let rule = "statistics where matchCount = 3";
+    assert!(matches!(
+        verify(battlelogs.clone(), expired(), rule),
+        MilestoneStatus::Completed(_)
+    ));
+
+    let rule = "statistics where battleKind in [ladder, pathOfLegends] and winRate = 100";
+    assert!(matches!(
+        verify(battlelogs.clone(), expired(), rule),
+        MilestoneStatus::Completed(_)
+    ));
+
+    let rule = "statistics where battleKind in [friendly] and wins = 0";
+    assert!(matches!(
+        verify(battlelogs.clone(), expired(), rule),
+        MilestoneStatus::Completed(_)
+    ));
+
+    let rule = "statistics where gameMode in [2v2] and matchCount >= 1";
+    assert_eq!(verify(battlelogs, expired(), rule), MilestoneStatus::Failed);
+}
+
+#[test]
+fn test_statistics_min_matches() {
+    let battlelogs = vec![
+        battlelog("PvP", 1, true),
+        battlelog("PvP", 2, false),
+        battlelog("PvP", 3, true),
+        battlelog("PvP", 4, false),
+    ];
+
+    // Completed before the bet expires, at the first match that fulfills the conditions.
+    let rule = "statistics where wins >= 1 and winRate >= 60 and minMatches = 2";
+    assert_eq!(
+        verify(battlelogs.clone(), DateTime::max_datetime(), rule),
+        MilestoneStatus::Completed(Some(battlelogs[2].battle_time.clone()))
+    );
+
+    // Not enough matches yet.
+    let rule = "statistics where wins >= 1 and minMatches = 5";
+    assert_eq!(
+        verify(battlelogs.clone(), DateTime::max_datetime(), rule),
+        MilestoneStatus::Initiated
+    );
+    assert_eq!(
+        verify(battlelogs, expired(), rule),
+        MilestoneStatus::Completed(Some(battlelog("PvP", 4, false).battle_time))
+    );
+
+    let milestone =
+        GameMilestone::from_rule("statistics where wins >= 1 and minMatches = 0").unwrap();
+    assert!(milestone.validate().is_some());
+}
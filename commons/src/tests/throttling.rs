@@ -0,0 +1,51 @@
+use std::time::{Duration, Instant};
+
+use crate::clients::games::{retry_delay, ResponseCache, TokenBucket};
+
+#[test]
+fn test_token_bucket() {
+    let bucket = TokenBucket::new(2, 4);
+    let now = Instant::now();
+
+    assert!(bucket.try_acquire_at(now).is_ok());
+    assert!(bucket.try_acquire_at(now).is_ok());
+    assert_eq!(bucket.try_acquire_at(now), Err(Duration::from_millis(250)));
+
+    // Refilled after the delay.
+    let now = now + Duration::from_millis(250);
+    assert!(bucket.try_acquire_at(now).is_ok());
+
+    // A rate-limit response blocks the bucket even if it has been refilled.
+    bucket.block_until(now + Duration::from_secs(5));
+    let now = now + Duration::from_secs(2);
+    assert_eq!(bucket.try_acquire_at(now), Err(Duration::from_secs(3)));
+    assert!(bucket.try_acquire_at(now + Duration::from_secs(3)).is_ok());
+}
+
+#[test]
+fn test_response_cache() {
+    let cache = ResponseCache::new(Duration::from_secs(30));
+    let now = Instant::now();
+
+    cache.insert_at("ABC".to_string(), 1, now);
+    assert_eq!(cache.get_at("ABC", now + Duration::from_secs(29)), Some(1));
+    assert_eq!(cache.get_at("ABC", now + Duration::from_secs(30)), None);
+    assert_eq!(cache.get_at("DEF", now), None);
+}
+
+#[test]
+fn test_retry_delay() {
+    assert_eq!(retry_delay(0, None), Duration::from_millis(250));
+    assert_eq!(retry_delay(2, None), Duration::from_secs(1));
+    assert_eq!(retry_delay(20, None), Duration::from_secs(10));
+
+    // The delay requested by the API is honored but bounded.
+    assert_eq!(
+        retry_delay(0, Some(Duration::from_secs(3))),
+        Duration::from_secs(3)
+    );
+    assert_eq!(
+        retry_delay(0, Some(Duration::from_secs(60))),
+        Duration::from_secs(10)
+    );
+}
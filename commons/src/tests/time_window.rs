@@ -0,0 +1,67 @@
+use chrono::{TimeZone, Utc};
+
+use crate::database::types::conditions::TimeWindowCondition;
+use crate::database::types::DateTime;
+
+/// A moment of the first weekend of 2022, i.e. Friday the 7th to Sunday the 9th, in UTC.
+fn time(day: u32, hour: u32) -> DateTime {
+    DateTime::new(Utc.ymd(2022, 1, day).and_hms(hour, 0, 0))
+}
+
+fn time_window(condition: serde_json::Value) -> TimeWindowCondition {
+    let condition: TimeWindowCondition = serde_json::from_value(condition).unwrap();
+    assert!(condition.validate().is_none());
+    condition
+}
+
+#[test]
+fn test_time_window_weekdays() {
+    let condition = time_window(serde_json::json!({
+        "utcOffset": 60,
+        "weekdays": { "condition": "anyOf", "value": ["saturday", "sunday"] },
+    }));
+
+    assert!(!condition.verify(&time(7, 22)));
+    assert!(condition.verify(&time(7, 23)));
+    assert!(condition.verify(&time(9, 22)));
+    assert!(!condition.verify(&time(9, 23)));
+}
+
+#[test]
+fn test_time_window_wrapping_midnight() {
+    // Friday nights, from 22:00 to 02:00 in UTC+1.
+    let condition = time_window(serde_json::json!({
+        "utcOffset": 60,
+        "dayTimes": [{ "from": 22 * 3600, "to": 2 * 3600 }],
+        "weekdays": { "condition": "anyOf", "value": ["friday"] },
+    }));
+
+    // Friday at 23:00 and Saturday at 01:00.
+    assert!(condition.verify(&time(7, 22)));
+    assert!(condition.verify(&time(8, 0)));
+
+    // Friday at 01:00, i.e. Thursday night, and Saturday at 23:00.
+    assert!(!condition.verify(&time(7, 0)));
+    assert!(!condition.verify(&time(8, 22)));
+
+    // Out of the range.
+    assert!(!condition.verify(&time(7, 12)));
+    assert!(!condition.verify(&time(8, 1)));
+}
+
+#[test]
+fn test_time_window_several_ranges() {
+    // Saturday mornings or any night from 22:00 to 02:00, except Friday nights.
+    let condition = time_window(serde_json::json!({
+        "dayTimes": [
+            { "from": 8 * 3600, "to": 12 * 3600 },
+            { "from": 22 * 3600, "to": 2 * 3600 },
+        ],
+        "weekdays": { "condition": "noneOf", "value": ["friday"] },
+    }));
+
+    assert!(condition.verify(&time(8, 9)));
+    assert!(!condition.verify(&time(7, 9)));
+    assert!(!condition.verify(&time(8, 1)));
+    assert!(condition.verify(&time(9, 1)));
+}
@@ -0,0 +1,83 @@
+use crate::clients::games::ClashRoyaleBattlelogResponse;
+use crate::database::types::conditions::ConditionCheck;
+use crate::database::types::game::{ClashRoyaleTeamConditions, ClashRoyaleTeamMemberConditions};
+
+fn battlelog(team: serde_json::Value) -> ClashRoyaleBattlelogResponse {
+    serde_json::from_value(serde_json::json!({
+        "type": "clanWarDuel",
+        "battleTime": "20220101T101010.000Z",
+        "team": team,
+        "opponent": [{ "tag": "#B", "crowns": 0 }],
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_princess_tower_hit_points() {
+    let battlelog = battlelog(serde_json::json!([
+        { "tag": "#A", "kingTowerHitPoints": 4000, "princessTowersHitPoints": [1200, 2500] },
+        { "tag": "#C", "princessTowersHitPoints": [300] },
+        { "tag": "#D" },
+    ]));
+    let team = battlelog.team.as_ref().unwrap();
+
+    // Both towers standing.
+    assert_eq!(team[0].remaining_king_tower_hit_points(), 4000);
+    assert_eq!(team[0].max_princess_tower_hit_points(), 2500);
+    assert_eq!(team[0].min_princess_tower_hit_points(), 1200);
+
+    // One tower destroyed.
+    assert_eq!(team[1].remaining_king_tower_hit_points(), 0);
+    assert_eq!(team[1].max_princess_tower_hit_points(), 300);
+    assert_eq!(team[1].min_princess_tower_hit_points(), 0);
+
+    // All towers destroyed.
+    assert_eq!(team[2].max_princess_tower_hit_points(), 0);
+    assert_eq!(team[2].min_princess_tower_hit_points(), 0);
+}
+
+#[test]
+fn test_member_tower_conditions() {
+    let battlelog = battlelog(serde_json::json!([
+        { "tag": "#A", "kingTowerHitPoints": 4000, "princessTowersHitPoints": [2500] },
+    ]));
+    let team = battlelog.team.as_ref().unwrap();
+
+    // Win without losing a princess tower.
+    let conditions: ClashRoyaleTeamMemberConditions = serde_json::from_value(serde_json::json!({
+        "princessTower1HitPoints": { "condition": ">", "value": [0] },
+        "princessTower2HitPoints": { "condition": ">", "value": [0] },
+    }))
+    .unwrap();
+
+    let checks = conditions.check_response(&battlelog, &team[0], "team.firstMember");
+    assert_eq!(checks.len(), 2);
+    assert!(checks[0].passed);
+    assert!(!checks[1].passed);
+    assert_eq!(
+        checks[1].condition.as_str(),
+        "team.firstMember.princessTower2HitPoints"
+    );
+    assert_eq!(checks[1].actual, Some(serde_json::json!(0)));
+}
+
+#[test]
+fn test_team_tower_conditions() {
+    let battlelog = battlelog(serde_json::json!([
+        { "tag": "#A", "kingTowerHitPoints": 4000, "princessTowersHitPoints": [1200, 2500] },
+        { "tag": "#C", "kingTowerHitPoints": 3000, "princessTowersHitPoints": [300] },
+    ]));
+    let team = battlelog.team.as_ref().unwrap();
+
+    let conditions: ClashRoyaleTeamConditions = serde_json::from_value(serde_json::json!({
+        "kingTowerHitPoints": { "condition": "anyOf", "value": [7000] },
+        "princessTower1HitPoints": { "condition": "anyOf", "value": [2800] },
+        "princessTower2HitPoints": { "condition": "anyOf", "value": [1200] },
+    }))
+    .unwrap();
+
+    // The values of the members are added together.
+    let checks = conditions.check_response(&battlelog, team, "team");
+    assert_eq!(checks.len(), 3);
+    assert!(ConditionCheck::all_passed(&checks));
+}
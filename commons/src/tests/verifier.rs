@@ -0,0 +1,139 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::clients::games::{ClashRoyaleBattlelogResponse, ClashRoyalePlayerInfoResponse};
+use crate::database::documents::{
+    ChallengeMilestone, CompositeChallengeMilestone, GameChallengeMilestone,
+};
+use crate::database::types::game::GameMilestone;
+use crate::database::types::{CompositeMilestoneKind, DateTime, GameType};
+use crate::games::{ClashRoyaleProgress, GameProgress, MilestoneStatus, MilestoneVerifier};
+
+const ACHIEVEMENT_RULE: &str = "achieve where measure = increment and wins >= 10";
+
+fn battlelog(minute: u32) -> ClashRoyaleBattlelogResponse {
+    serde_json::from_value(serde_json::json!({
+        "type": "PvP",
+        "battleTime": format!("20220101T10{:02}00.000Z", minute),
+        "team": [{ "tag": "#A", "crowns": 1 }],
+        "opponent": [{ "tag": "#B", "crowns": 0 }],
+    }))
+    .unwrap()
+}
+
+fn player(wins: u64) -> ClashRoyalePlayerInfoResponse {
+    serde_json::from_value(serde_json::json!({ "tag": "#A", "wins": wins })).unwrap()
+}
+
+/// Builds a verifier with two won matches and the given wins since the snapshot.
+fn verifier(wins: u64) -> MilestoneVerifier {
+    let progress = ClashRoyaleProgress::new(
+        vec![battlelog(1), battlelog(2)],
+        Some(player(1000 + wins)),
+        Some(player(1000)),
+        DateTime::max_datetime(),
+    );
+
+    let mut progresses: HashMap<GameType, Box<dyn GameProgress>> = HashMap::new();
+    progresses.insert(GameType::ClashRoyale, Box::new(progress));
+
+    MilestoneVerifier::new(progresses, HashSet::new())
+}
+
+fn milestone(rule: &str) -> ChallengeMilestone {
+    ChallengeMilestone::GameMilestone(GameChallengeMilestone {
+        milestone: GameMilestone::from_rule(rule).unwrap(),
+    })
+}
+
+fn composite(kind: CompositeMilestoneKind, rules: &[&str]) -> ChallengeMilestone {
+    ChallengeMilestone::Composite(CompositeChallengeMilestone {
+        kind,
+        milestones: rules.iter().map(|v| milestone(v)).collect(),
+    })
+}
+
+#[test]
+fn test_achievement_in_sequence() {
+    let completed = verifier(10);
+
+    // The matches after the achievement are not discarded.
+    let sequence = composite(
+        CompositeMilestoneKind::Sequence,
+        &[ACHIEVEMENT_RULE, "win 2 matches"],
+    );
+    let progress = completed.verify(&sequence, None);
+    assert_eq!(
+        progress.status,
+        MilestoneStatus::Completed(Some(battlelog(2).battle_time))
+    );
+
+    // The achievement is completed after the previous milestone.
+    let sequence = composite(
+        CompositeMilestoneKind::Sequence,
+        &["win 1 match", ACHIEVEMENT_RULE, "win 1 match"],
+    );
+    let progress = completed.verify(&sequence, None);
+    assert_eq!(
+        progress.milestones[1].status,
+        MilestoneStatus::Completed(Some(battlelog(1).battle_time))
+    );
+    assert_eq!(
+        progress.status,
+        MilestoneStatus::Completed(Some(battlelog(2).battle_time))
+    );
+
+    // Not yet reached.
+    let progress = verifier(9).verify(&sequence, None);
+    assert_eq!(progress.status, MilestoneStatus::Initiated);
+    assert_eq!(progress.matched, 1);
+}
+
+#[test]
+fn test_achievement_in_all_of() {
+    let all_of = composite(
+        CompositeMilestoneKind::AllOf,
+        &[ACHIEVEMENT_RULE, "win 1 match"],
+    );
+
+    let progress = verifier(10).verify(&all_of, None);
+    assert_eq!(
+        progress.status,
+        MilestoneStatus::Completed(Some(battlelog(1).battle_time))
+    );
+
+    let progress = verifier(9).verify(&all_of, None);
+    assert_eq!(progress.status, MilestoneStatus::Initiated);
+    assert_eq!(progress.matched, 1);
+}
+
+#[test]
+fn test_achievement_in_any_of() {
+    let any_of = composite(
+        CompositeMilestoneKind::AnyOf,
+        &[ACHIEVEMENT_RULE, "win 3 matches"],
+    );
+
+    let progress = verifier(10).verify(&any_of, None);
+    assert_eq!(progress.status, MilestoneStatus::Completed(None));
+
+    let progress = verifier(9).verify(&any_of, None);
+    assert_eq!(progress.status, MilestoneStatus::Initiated);
+    assert_eq!(progress.matched, 0);
+}
+
+#[test]
+fn test_missing_progress() {
+    // The progress of the game is not loaded.
+    let verifier = MilestoneVerifier::new(HashMap::new(), HashSet::new());
+    let progress = verifier.verify(&milestone("win 1 match"), None);
+    assert_eq!(progress.status, MilestoneStatus::Failed);
+
+    // The player info is not loaded.
+    let progress =
+        ClashRoyaleProgress::new(vec![battlelog(1)], None, None, DateTime::max_datetime());
+    let milestone = GameMilestone::from_rule(ACHIEVEMENT_RULE).unwrap();
+    assert_eq!(
+        progress.verify(&milestone, None).status,
+        MilestoneStatus::Failed
+    );
+}
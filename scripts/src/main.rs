@@ -9,11 +9,7 @@ use commons::database::collections::CollectionKind;
 use commons::database::documents::ChallengeDBDocument;
 use commons::database::documents::ChallengeMilestone;
 use commons::database::documents::GameChallengeMilestone;
-use commons::database::types::conditions::{OptionCondition, OrderedCondition};
-use commons::database::types::game::ClashRoyaleGameMode;
-use commons::database::types::game::ClashRoyaleMatchConditions;
-use commons::database::types::game::ClashRoyaleTeamConditions;
-use commons::database::types::game::{ClashRoyaleMilestone, GameMilestone};
+use commons::database::types::game::GameMilestone;
 use commons::database::types::{Address, DateTime};
 use commons::database::{init_db_connection, DBDocument, NullableOption};
 use commons::solana_sdk::signer::Signer;
@@ -104,19 +100,10 @@ async fn insert_challenges_in_db() -> ServerResult<()> {
         keypair: NullableOption::Value(challenge_keypair.to_base58_string().into()),
         milestones: NullableOption::Value(vec![ChallengeMilestone::GameMilestone(
             GameChallengeMilestone {
-                milestone: GameMilestone::ClashRoyale(ClashRoyaleMilestone::WinMatches(vec![
-                    ClashRoyaleMatchConditions {
-                        allow_friends: Some(false),
-                        game_mode: Some(OptionCondition::AnyOf(vec![
-                            ClashRoyaleGameMode::OneVsOne,
-                        ])),
-                        team: Some(ClashRoyaleTeamConditions {
-                            crowns: Some(OrderedCondition::AnyOf(vec![1])),
-                            ..Default::default()
-                        }),
-                        ..Default::default()
-                    },
-                ])),
+                milestone: GameMilestone::from_rule(
+                    "win 1 match in 1v1 where allowFriends = false and team.crowns = 1",
+                )
+                .unwrap(),
             },
         )]),
         created_at: NullableOption::Value(DateTime::now()),